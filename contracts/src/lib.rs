@@ -27,6 +27,8 @@ pub use security::pausable;
 pub use token::psp22;
 #[cfg(feature = "psp34")]
 pub use token::psp34;
+#[cfg(feature = "psp37")]
+pub use token::psp37;
 
 #[cfg(feature = "set_code_hash")]
 pub use upgradeability::set_code_hash;
@@ -4,3 +4,5 @@
 pub mod psp22;
 #[cfg(feature = "psp34")]
 pub mod psp34;
+#[cfg(feature = "psp37")]
+pub mod psp37;
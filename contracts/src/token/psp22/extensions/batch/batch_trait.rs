@@ -0,0 +1,70 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use ink::{contract_ref, env::DefaultEnvironment, prelude::vec::Vec};
+
+pub type PSP22BatchRef = contract_ref!(PSP22Batch, DefaultEnvironment);
+
+/// trait extending PSP22 with transferring tokens to and approving many accounts in a single call.
+///
+/// Batches are atomic - if any transfer fails, none of them is applied.
+#[ink::trait_definition]
+pub trait PSP22Batch {
+    /// Transfers each `(to, value)` pair of `transfers` from the caller.
+    ///
+    /// On success a `Transfer` event is emitted for every transfer.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BatchTransferFailed` with the index and the error of the failing transfer.
+    #[ink(message)]
+    fn batch_transfer(
+        &mut self,
+        transfers: Vec<(AccountId, Balance)>,
+        data: Vec<u8>,
+    ) -> Result<(), PSP22Error>;
+
+    /// Transfers each `(to, value)` pair of `transfers` from `from` on behalf of the caller.
+    /// The sum of all values is deducted from the caller's allowance once.
+    /// Recipient contracts are called with empty `data`.
+    ///
+    /// On success a `Transfer` event is emitted for every transfer and an `Approval` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InsufficientAllowance` if the caller's allowance is lower than the sum of all values.
+    /// Returns `BatchTransferFailed` with the index and the error of the failing transfer.
+    #[ink(message)]
+    fn batch_transfer_from(
+        &mut self,
+        from: AccountId,
+        transfers: Vec<(AccountId, Balance)>,
+    ) -> Result<(), PSP22Error>;
+
+    /// Sets the allowance of each `(spender, value)` pair of `approvals` to spend the caller's tokens.
+    ///
+    /// On success an `Approval` event is emitted for every approval.
+    #[ink(message)]
+    fn batch_approve(
+        &mut self,
+        approvals: Vec<(AccountId, Balance)>,
+    ) -> Result<(), PSP22Error>;
+}
+
+/// trait that is derived by Pendzl PSP22Batch implementation macro
+///
+/// functions of this trait are recomended to use while writing ink::messages
+pub trait PSP22BatchInternal {
+    /// Transfers each `(to, value)` pair of `transfers` from `from`. Doesn't check the allowance.
+    ///
+    /// Transfers are applied in order, the caller is responsible for reverting the
+    /// already applied ones on error (returning the error from an ink::message does so).
+    ///
+    /// # Errors
+    /// Returns `BatchTransferFailed` with the index and the error of the failing transfer.
+    fn _batch_transfer(
+        &mut self,
+        from: &AccountId,
+        transfers: &Vec<(AccountId, Balance)>,
+        data: &Vec<u8>,
+    ) -> Result<(), PSP22Error>;
+}
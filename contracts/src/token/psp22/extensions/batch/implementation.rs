@@ -0,0 +1,65 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use ink::prelude::{format, vec::Vec};
+use ink::primitives::AccountId;
+use pendzl::math::errors::MathError;
+use pendzl::traits::DefaultEnv;
+
+use super::PSP22BatchInternal;
+use crate::token::psp22::{Balance, PSP22Error, PSP22Internal};
+
+pub trait PSP22BatchDefaultImpl:
+    PSP22Internal + PSP22BatchInternal + DefaultEnv
+{
+    fn batch_transfer_default_impl(
+        &mut self,
+        transfers: Vec<(AccountId, Balance)>,
+        data: Vec<u8>,
+    ) -> Result<(), PSP22Error> {
+        self._batch_transfer(&Self::env().caller(), &transfers, &data)
+    }
+
+    fn batch_transfer_from_default_impl(
+        &mut self,
+        from: AccountId,
+        transfers: Vec<(AccountId, Balance)>,
+    ) -> Result<(), PSP22Error> {
+        let caller = Self::env().caller();
+        let mut total: Balance = 0;
+        for (_, value) in transfers.iter() {
+            total = total.checked_add(*value).ok_or(MathError::Overflow)?;
+        }
+        self._decrease_allowance_from_to(&from, &caller, &total)?;
+        self._batch_transfer(&from, &transfers, &Vec::new())
+    }
+
+    fn batch_approve_default_impl(
+        &mut self,
+        approvals: Vec<(AccountId, Balance)>,
+    ) -> Result<(), PSP22Error> {
+        let owner = Self::env().caller();
+        for (spender, value) in approvals.iter() {
+            self._approve(&owner, spender, value)?;
+        }
+        Ok(())
+    }
+}
+
+pub trait PSP22BatchInternalDefaultImpl: PSP22Internal {
+    fn _batch_transfer_default_impl(
+        &mut self,
+        from: &AccountId,
+        transfers: &Vec<(AccountId, Balance)>,
+        data: &Vec<u8>,
+    ) -> Result<(), PSP22Error> {
+        for (index, (to, value)) in transfers.iter().enumerate() {
+            self._transfer(from, to, value, data).map_err(|e| {
+                PSP22Error::BatchTransferFailed {
+                    index: index as u32,
+                    reason: format!("{:?}", e),
+                }
+            })?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,26 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use ink::prelude::vec::Vec;
+
+/// Emitted when `amount` tokens bridged from `source_chain` with `nonce` are minted to `to`.
+#[ink::event]
+pub struct BridgeMint {
+    #[ink(topic)]
+    pub to: AccountId,
+    pub amount: Balance,
+    #[ink(topic)]
+    pub source_chain: u32,
+    pub nonce: u64,
+}
+
+/// Emitted when `amount` tokens are burned from `from` to be bridged to `recipient` on `dest_chain`.
+#[ink::event]
+pub struct BridgeBurn {
+    #[ink(topic)]
+    pub from: AccountId,
+    pub amount: Balance,
+    #[ink(topic)]
+    pub dest_chain: u32,
+    pub nonce: u64,
+    pub recipient: Vec<u8>,
+}
@@ -0,0 +1,109 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use ink::{contract_ref, env::DefaultEnvironment};
+
+pub type PSP22BridgeableRef =
+    contract_ref!(PSP22Bridgeable, DefaultEnvironment);
+
+/// trait extending PSP22 with minting and burning of tokens bridged from and to other chains.
+///
+/// Both messages are restricted to accounts with the `BRIDGE_RELAYER` role.
+/// Every `(source_chain, nonce)` pair can be minted only once.
+#[ink::trait_definition]
+pub trait PSP22Bridgeable {
+    /// Mints `amount` tokens bridged from `source_chain` to `to`.
+    ///
+    /// On success a `BridgeMint` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Custom("AC::MissingRole")` if the caller doesn't have the `BRIDGE_RELAYER` role.
+    /// Returns `BridgeNonceUsed` if `nonce` was already used for `source_chain`.
+    #[ink(message)]
+    fn bridge_mint(
+        &mut self,
+        to: AccountId,
+        amount: Balance,
+        source_chain: u32,
+        nonce: u64,
+    ) -> Result<(), PSP22Error>;
+
+    /// Burns `amount` tokens from `from` to be bridged to `recipient` on `dest_chain`.
+    /// If `from` is not the caller, `amount` is deducted from the caller's allowance.
+    ///
+    /// On success a `BridgeBurn` event is emitted and its nonce is returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Custom("AC::MissingRole")` if the caller doesn't have the `BRIDGE_RELAYER` role.
+    /// Returns `InsufficientAllowance` if `from` is not the caller and the caller's allowance is lower than `amount`.
+    #[ink(message)]
+    fn bridge_burn(
+        &mut self,
+        from: AccountId,
+        amount: Balance,
+        dest_chain: u32,
+        recipient: Vec<u8>,
+    ) -> Result<u64, PSP22Error>;
+
+    /// Returns true if `nonce` was already used for `source_chain`.
+    #[ink(message)]
+    fn is_nonce_used(&self, source_chain: u32, nonce: u64) -> bool;
+
+    /// Returns the nonce that will be assigned to the next `bridge_burn`.
+    #[ink(message)]
+    fn outbound_nonce(&self) -> u64;
+}
+
+/// trait that must be implemented by exactly one storage field of a contract storage
+/// so the Pendzl PSP22BridgeableInternal and PSP22Bridgeable implementation can be derived.
+pub trait PSP22BridgeableStorage {
+    /// Returns true if `nonce` was already used for `source_chain`.
+    fn is_nonce_used(&self, source_chain: u32, nonce: u64) -> bool;
+
+    /// Marks `nonce` as used for `source_chain`.
+    fn use_nonce(&mut self, source_chain: u32, nonce: u64);
+
+    /// Returns the nonce that will be assigned to the next outbound transfer.
+    fn outbound_nonce(&self) -> u64;
+
+    /// Returns the nonce for the next outbound transfer and increments it.
+    fn next_outbound_nonce(&mut self) -> Result<u64, PSP22Error>;
+}
+
+/// trait that is derived by Pendzl PSP22Bridgeable implementation macro assuming StorageFieldGetter<PSP22BridgeableStorage> is implemented
+///
+/// functions of this trait are recomended to use while writing ink::messages
+pub trait PSP22BridgeableInternal {
+    /// Mints `amount` tokens bridged from `source_chain` to `to`. Doesn't check the caller's role.
+    ///
+    /// On success a `BridgeMint` event is emitted.
+    ///
+    /// # Errors
+    /// Returns `BridgeNonceUsed` if `nonce` was already used for `source_chain`.
+    fn _bridge_mint(
+        &mut self,
+        to: &AccountId,
+        amount: &Balance,
+        source_chain: u32,
+        nonce: u64,
+    ) -> Result<(), PSP22Error>;
+
+    /// Burns `amount` tokens from `from` to be bridged to `recipient` on `dest_chain`.
+    /// Doesn't check the caller's role nor allowance.
+    ///
+    /// On success a `BridgeBurn` event is emitted and its nonce is returned.
+    fn _bridge_burn(
+        &mut self,
+        from: &AccountId,
+        amount: &Balance,
+        dest_chain: u32,
+        recipient: &Vec<u8>,
+    ) -> Result<u64, PSP22Error>;
+
+    /// Returns true if `nonce` was already used for `source_chain`.
+    fn _is_nonce_used(&self, source_chain: u32, nonce: u64) -> bool;
+
+    /// Returns the nonce that will be assigned to the next `bridge_burn`.
+    fn _outbound_nonce(&self) -> u64;
+}
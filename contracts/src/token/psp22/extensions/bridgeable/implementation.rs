@@ -0,0 +1,144 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use ink::{prelude::vec::Vec, primitives::AccountId, storage::Mapping};
+use pendzl::math::errors::MathError;
+use pendzl::traits::{DefaultEnv, StorageFieldGetter};
+
+use super::{
+    BridgeBurn, BridgeMint, PSP22BridgeableInternal, PSP22BridgeableStorage,
+};
+use crate::access::access_control::{AccessControlInternal, RoleType};
+use crate::token::psp22::{Balance, PSP22Error, PSP22Internal};
+
+pub const BRIDGE_RELAYER: RoleType = ink::selector_id!("BRIDGE_RELAYER");
+
+#[derive(Default, Debug)]
+#[pendzl::storage_item]
+pub struct PSP22BridgeableData {
+    pub used_nonces: Mapping<(u32, u64), ()>,
+    #[lazy]
+    pub outbound_nonce: u64,
+}
+
+impl PSP22BridgeableStorage for PSP22BridgeableData {
+    fn is_nonce_used(&self, source_chain: u32, nonce: u64) -> bool {
+        self.used_nonces.contains((source_chain, nonce))
+    }
+
+    fn use_nonce(&mut self, source_chain: u32, nonce: u64) {
+        self.used_nonces.insert((source_chain, nonce), &());
+    }
+
+    fn outbound_nonce(&self) -> u64 {
+        self.outbound_nonce.get_or_default()
+    }
+
+    fn next_outbound_nonce(&mut self) -> Result<u64, PSP22Error> {
+        let nonce = self.outbound_nonce();
+        self.outbound_nonce
+            .set(&(nonce.checked_add(1).ok_or(MathError::Overflow)?));
+        Ok(nonce)
+    }
+}
+
+pub trait PSP22BridgeableDefaultImpl:
+    AccessControlInternal + PSP22Internal + PSP22BridgeableInternal + DefaultEnv
+{
+    fn bridge_mint_default_impl(
+        &mut self,
+        to: AccountId,
+        amount: Balance,
+        source_chain: u32,
+        nonce: u64,
+    ) -> Result<(), PSP22Error> {
+        self._ensure_has_role(BRIDGE_RELAYER, Some(Self::env().caller()))?;
+        self._bridge_mint(&to, &amount, source_chain, nonce)
+    }
+
+    fn bridge_burn_default_impl(
+        &mut self,
+        from: AccountId,
+        amount: Balance,
+        dest_chain: u32,
+        recipient: Vec<u8>,
+    ) -> Result<u64, PSP22Error> {
+        let caller = Self::env().caller();
+        self._ensure_has_role(BRIDGE_RELAYER, Some(caller))?;
+        if caller != from {
+            self._decrease_allowance_from_to(&from, &caller, &amount)?;
+        }
+        self._bridge_burn(&from, &amount, dest_chain, &recipient)
+    }
+
+    fn is_nonce_used_default_impl(
+        &self,
+        source_chain: u32,
+        nonce: u64,
+    ) -> bool {
+        self._is_nonce_used(source_chain, nonce)
+    }
+
+    fn outbound_nonce_default_impl(&self) -> u64 {
+        self._outbound_nonce()
+    }
+}
+
+pub trait PSP22BridgeableInternalDefaultImpl:
+    StorageFieldGetter<PSP22BridgeableData> + PSP22Internal
+where
+    PSP22BridgeableData: PSP22BridgeableStorage,
+{
+    fn _bridge_mint_default_impl(
+        &mut self,
+        to: &AccountId,
+        amount: &Balance,
+        source_chain: u32,
+        nonce: u64,
+    ) -> Result<(), PSP22Error> {
+        if self.data().is_nonce_used(source_chain, nonce) {
+            return Err(PSP22Error::BridgeNonceUsed);
+        }
+        self.data().use_nonce(source_chain, nonce);
+        self._mint_to(to, amount)?;
+
+        Self::env().emit_event(BridgeMint {
+            to: *to,
+            amount: *amount,
+            source_chain,
+            nonce,
+        });
+        Ok(())
+    }
+
+    fn _bridge_burn_default_impl(
+        &mut self,
+        from: &AccountId,
+        amount: &Balance,
+        dest_chain: u32,
+        recipient: &Vec<u8>,
+    ) -> Result<u64, PSP22Error> {
+        self._burn_from(from, amount)?;
+        let nonce = self.data().next_outbound_nonce()?;
+
+        Self::env().emit_event(BridgeBurn {
+            from: *from,
+            amount: *amount,
+            dest_chain,
+            nonce,
+            recipient: recipient.clone(),
+        });
+        Ok(nonce)
+    }
+
+    fn _is_nonce_used_default_impl(
+        &self,
+        source_chain: u32,
+        nonce: u64,
+    ) -> bool {
+        self.data().is_nonce_used(source_chain, nonce)
+    }
+
+    fn _outbound_nonce_default_impl(&self) -> u64 {
+        self.data().outbound_nonce()
+    }
+}
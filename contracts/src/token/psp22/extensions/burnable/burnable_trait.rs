@@ -11,10 +11,17 @@ pub type PSP22BurnableRef = contract_ref!(PSP22Burnable, DefaultEnvironment);
 /// trait extending PSP22 with burn functionality
 #[ink::trait_definition]
 pub trait PSP22Burnable {
-    /// Destroys `amount` tokens from `account`, deducting from the caller's
+    /// Destroys `amount` tokens from `account`.
+    ///
+    /// If `account` is not the caller, `amount` is deducted from the caller's
     /// allowance.
     ///
-    /// See [`PSP22Internal::_burn_from`].
+    /// See [`PSP22Internal::_burn_from`] for burning without the allowance check.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InsufficientAllowance` if `account` is not the caller and the caller's allowance is lower than `amount`.
+    /// Returns `InsufficientBalance` if `account` holds less than `amount` tokens.
     #[ink(message)]
     fn burn(
         &mut self,
@@ -1,18 +1,23 @@
 // Copyright (c) 2024 C Forge. All Rights Reserved.
 // SPDX-License-Identifier: MIT
 use ink::primitives::AccountId;
+use pendzl::traits::DefaultEnv;
 
 use crate::token::psp22::PSP22Error;
 pub use crate::token::psp22::PSP22Internal;
 
 pub use pendzl::traits::Balance;
 
-pub trait PSP22BurnableDefaultImpl: PSP22Internal {
+pub trait PSP22BurnableDefaultImpl: PSP22Internal + DefaultEnv {
     fn burn_default_impl(
         &mut self,
         account: AccountId,
         amount: Balance,
     ) -> Result<(), PSP22Error> {
+        let caller = Self::env().caller();
+        if caller != account {
+            self._decrease_allowance_from_to(&account, &caller, &amount)?;
+        }
         self._burn_from(&account, &amount)
     }
 }
@@ -0,0 +1,56 @@
+// Copyright (c) 2012-2022 Supercolony. All Rights Reserved.
+// Copyright (c) 2023 Brushfam. All Rights Reserved.
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+
+use crate::token::psp22::PSP22Error;
+use ink::{contract_ref, env::DefaultEnvironment, primitives::AccountId};
+pub use pendzl::traits::Balance;
+
+pub type PSP22CappedRef = contract_ref!(PSP22Capped, DefaultEnvironment);
+
+/// trait extending PSP22 with a maximum total supply
+#[ink::trait_definition]
+pub trait PSP22Capped {
+    /// Returns the maximum total supply of the token.
+    #[ink(message)]
+    fn cap(&self) -> Balance;
+}
+
+/// trait that must be implemented by exactly one storage field of a contract storage
+/// so the Pendzl PSP22CappedInternal and PSP22Capped implementation can be derived.
+pub trait PSP22CappedStorage {
+    /// Retrieves the cap.
+    fn cap(&self) -> Balance;
+
+    /// Sets the cap to `cap`.
+    fn set_cap(&mut self, cap: &Balance);
+}
+
+/// trait that is derived by Pendzl PSP22Capped implementation macro assuming StorageFieldGetter<PSP22CappedStorage> is implemented
+///
+/// functions of this trait are recomended to use while writing ink::messages
+pub trait PSP22CappedInternal {
+    /// Retrieves the cap.
+    fn _cap(&self) -> Balance;
+
+    /// Sets the cap to `cap`. Should be called in the constructor.
+    ///
+    /// # Errors
+    /// Returns `CapExceeded` if `cap` is lower than the current total supply.
+    fn _set_cap(&mut self, cap: &Balance) -> Result<(), PSP22Error>;
+
+    /// Checks that moving `amount` tokens `from` `to` keeps the total supply within the cap.
+    /// Only mints (`from` is None) can exceed the cap.
+    ///
+    /// Called by the generated `PSP22Internal::_update` before the balances are updated.
+    ///
+    /// # Errors
+    /// Returns `CapExceeded` if the mint would make the total supply exceed the cap.
+    fn _check_cap(
+        &self,
+        from: Option<&AccountId>,
+        to: Option<&AccountId>,
+        amount: &Balance,
+    ) -> Result<(), PSP22Error>;
+}
@@ -0,0 +1,71 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use ink::primitives::AccountId;
+use pendzl::traits::StorageFieldGetter;
+
+use super::PSP22CappedStorage;
+use crate::token::psp22::{Balance, PSP22Error, PSP22Internal};
+
+#[derive(Default, Debug)]
+#[pendzl::storage_item]
+pub struct PSP22CappedData {
+    #[lazy]
+    pub cap: Balance,
+}
+
+impl PSP22CappedStorage for PSP22CappedData {
+    fn cap(&self) -> Balance {
+        self.cap.get_or_default()
+    }
+
+    fn set_cap(&mut self, cap: &Balance) {
+        self.cap.set(cap);
+    }
+}
+
+pub trait PSP22CappedDefaultImpl: StorageFieldGetter<PSP22CappedData>
+where
+    PSP22CappedData: PSP22CappedStorage,
+{
+    fn cap_default_impl(&self) -> Balance {
+        self.data().cap()
+    }
+}
+
+pub trait PSP22CappedInternalDefaultImpl:
+    StorageFieldGetter<PSP22CappedData> + PSP22Internal
+where
+    PSP22CappedData: PSP22CappedStorage,
+{
+    fn _cap_default_impl(&self) -> Balance {
+        self.data().cap()
+    }
+
+    fn _set_cap_default_impl(
+        &mut self,
+        cap: &Balance,
+    ) -> Result<(), PSP22Error> {
+        if *cap < self._total_supply() {
+            return Err(PSP22Error::CapExceeded);
+        }
+        self.data().set_cap(cap);
+        Ok(())
+    }
+
+    fn _check_cap_default_impl(
+        &self,
+        from: Option<&AccountId>,
+        _to: Option<&AccountId>,
+        amount: &Balance,
+    ) -> Result<(), PSP22Error> {
+        if from.is_some() {
+            return Ok(());
+        }
+        match self._total_supply().checked_add(*amount) {
+            Some(new_total_supply) if new_total_supply <= self.data().cap() => {
+                Ok(())
+            }
+            _ => Err(PSP22Error::CapExceeded),
+        }
+    }
+}
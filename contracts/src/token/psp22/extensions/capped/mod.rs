@@ -0,0 +1,9 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+include!("capped_trait.rs");
+
+#[cfg(feature = "psp22_capped_impl")]
+mod implementation;
+
+#[cfg(feature = "psp22_capped_impl")]
+pub use implementation::*;
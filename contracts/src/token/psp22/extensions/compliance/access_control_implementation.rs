@@ -0,0 +1,78 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use ink::primitives::AccountId;
+use pendzl::traits::DefaultEnv;
+
+use super::{ComplianceMode, PSP22ComplianceInternal};
+use crate::access::access_control::{AccessControlInternal, RoleType};
+use crate::token::psp22::PSP22Error;
+
+pub const COMPLIANCE_MANAGER: RoleType =
+    ink::selector_id!("COMPLIANCE_MANAGER");
+
+pub trait PSP22ComplianceAccessControlDefaultImpl:
+    AccessControlInternal + Sized + PSP22ComplianceInternal
+{
+    fn compliance_mode_default_impl(&self) -> ComplianceMode {
+        self._compliance_mode()
+    }
+
+    fn is_frozen_default_impl(&self, account: AccountId) -> bool {
+        self._is_frozen(&account)
+    }
+
+    fn is_allowed_default_impl(&self, account: AccountId) -> bool {
+        self._is_allowed(&account)
+    }
+
+    fn set_compliance_mode_default_impl(
+        &mut self,
+        mode: ComplianceMode,
+    ) -> Result<(), PSP22Error> {
+        AccessControlInternal::_ensure_has_role(
+            self,
+            COMPLIANCE_MANAGER,
+            Some(Self::env().caller()),
+        )?;
+        self._set_compliance_mode(&mode);
+        Ok(())
+    }
+
+    fn freeze_default_impl(
+        &mut self,
+        account: AccountId,
+    ) -> Result<(), PSP22Error> {
+        AccessControlInternal::_ensure_has_role(
+            self,
+            COMPLIANCE_MANAGER,
+            Some(Self::env().caller()),
+        )?;
+        self._freeze(&account)
+    }
+
+    fn unfreeze_default_impl(
+        &mut self,
+        account: AccountId,
+    ) -> Result<(), PSP22Error> {
+        AccessControlInternal::_ensure_has_role(
+            self,
+            COMPLIANCE_MANAGER,
+            Some(Self::env().caller()),
+        )?;
+        self._unfreeze(&account)
+    }
+
+    fn set_allowed_default_impl(
+        &mut self,
+        account: AccountId,
+        allowed: bool,
+    ) -> Result<(), PSP22Error> {
+        AccessControlInternal::_ensure_has_role(
+            self,
+            COMPLIANCE_MANAGER,
+            Some(Self::env().caller()),
+        )?;
+        self._set_allowed(&account, allowed);
+        Ok(())
+    }
+}
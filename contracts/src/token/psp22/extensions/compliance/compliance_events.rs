@@ -0,0 +1,29 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+/// Emitted when `account` is frozen.
+#[ink::event]
+pub struct Frozen {
+    #[ink(topic)]
+    pub account: AccountId,
+}
+
+/// Emitted when `account` is unfrozen.
+#[ink::event]
+pub struct Unfrozen {
+    #[ink(topic)]
+    pub account: AccountId,
+}
+
+/// Emitted when `account` is added to or removed from the allow list.
+#[ink::event]
+pub struct AllowListUpdated {
+    #[ink(topic)]
+    pub account: AccountId,
+    pub allowed: bool,
+}
+
+/// Emitted when the compliance mode is changed to `mode`.
+#[ink::event]
+pub struct ComplianceModeChanged {
+    pub mode: ComplianceMode,
+}
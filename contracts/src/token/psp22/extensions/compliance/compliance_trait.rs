@@ -0,0 +1,128 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use ink::{contract_ref, env::DefaultEnvironment};
+
+pub type PSP22ComplianceRef =
+    contract_ref!(PSP22Compliance, DefaultEnvironment);
+
+/// trait extending PSP22 with frozen accounts and an optional allow list.
+///
+/// Frozen accounts can neither send nor receive tokens.
+/// In `AllowList` mode additionally only allowed accounts can send and receive tokens.
+///
+/// Management messages are restricted to the `COMPLIANCE_MANAGER` role if the contract implements `AccessControl`
+/// (`PSP22ComplianceAccessControlDefaultImpl`), otherwise to the owner (`PSP22ComplianceOwnableDefaultImpl`).
+#[ink::trait_definition]
+pub trait PSP22Compliance {
+    /// Returns the current compliance mode.
+    #[ink(message)]
+    fn compliance_mode(&self) -> ComplianceMode;
+
+    /// Returns true if `account` is frozen.
+    #[ink(message)]
+    fn is_frozen(&self, account: AccountId) -> bool;
+
+    /// Returns true if `account` is on the allow list.
+    #[ink(message)]
+    fn is_allowed(&self, account: AccountId) -> bool;
+
+    /// Sets the compliance mode to `mode`.
+    ///
+    /// On success a `ComplianceModeChanged` event is emitted.
+    #[ink(message)]
+    fn set_compliance_mode(
+        &mut self,
+        mode: ComplianceMode,
+    ) -> Result<(), PSP22Error>;
+
+    /// Freezes `account`.
+    ///
+    /// On success a `Frozen` event is emitted.
+    #[ink(message)]
+    fn freeze(&mut self, account: AccountId) -> Result<(), PSP22Error>;
+
+    /// Unfreezes `account`.
+    ///
+    /// On success an `Unfrozen` event is emitted.
+    #[ink(message)]
+    fn unfreeze(&mut self, account: AccountId) -> Result<(), PSP22Error>;
+
+    /// Adds `account` to the allow list if `allowed` is true, removes it otherwise.
+    ///
+    /// On success an `AllowListUpdated` event is emitted.
+    #[ink(message)]
+    fn set_allowed(
+        &mut self,
+        account: AccountId,
+        allowed: bool,
+    ) -> Result<(), PSP22Error>;
+}
+
+/// trait that must be implemented by exactly one storage field of a contract storage
+/// so the Pendzl PSP22ComplianceInternal and PSP22Compliance implementation can be derived.
+pub trait PSP22ComplianceStorage {
+    fn compliance_mode(&self) -> ComplianceMode;
+
+    fn set_compliance_mode(&mut self, mode: &ComplianceMode);
+
+    fn is_frozen(&self, account: &AccountId) -> bool;
+
+    fn set_frozen(&mut self, account: &AccountId, frozen: bool);
+
+    fn is_allowed(&self, account: &AccountId) -> bool;
+
+    fn set_allowed(&mut self, account: &AccountId, allowed: bool);
+}
+
+/// trait that is derived by Pendzl PSP22Compliance implementation macro assuming StorageFieldGetter<PSP22ComplianceStorage> is implemented
+///
+/// functions of this trait are recomended to use while writing ink::messages
+pub trait PSP22ComplianceInternal {
+    /// Returns the current compliance mode.
+    fn _compliance_mode(&self) -> ComplianceMode;
+
+    /// Sets the compliance mode to `mode`.
+    ///
+    /// On success a `ComplianceModeChanged` event is emitted.
+    fn _set_compliance_mode(&mut self, mode: &ComplianceMode);
+
+    /// Returns true if `account` is frozen.
+    fn _is_frozen(&self, account: &AccountId) -> bool;
+
+    /// Freezes `account`.
+    ///
+    /// On success a `Frozen` event is emitted.
+    ///
+    /// # Errors
+    /// Returns `AccountFrozen` if `account` is already frozen.
+    fn _freeze(&mut self, account: &AccountId) -> Result<(), PSP22Error>;
+
+    /// Unfreezes `account`.
+    ///
+    /// On success an `Unfrozen` event is emitted.
+    ///
+    /// # Errors
+    /// Returns `AccountNotFrozen` if `account` is not frozen.
+    fn _unfreeze(&mut self, account: &AccountId) -> Result<(), PSP22Error>;
+
+    /// Returns true if `account` is on the allow list.
+    fn _is_allowed(&self, account: &AccountId) -> bool;
+
+    /// Adds `account` to the allow list if `allowed` is true, removes it otherwise.
+    ///
+    /// On success an `AllowListUpdated` event is emitted.
+    fn _set_allowed(&mut self, account: &AccountId, allowed: bool);
+
+    /// Checks that `from` and `to` are allowed to move tokens.
+    ///
+    /// Called by the generated `PSP22Internal::_update` before the balances are updated.
+    ///
+    /// # Errors
+    /// Returns `AccountFrozen` if `from` or `to` is frozen.
+    /// Returns `AccountNotAllowed` if the mode is `AllowList` and `from` or `to` is not on the allow list.
+    fn _check_compliance(
+        &self,
+        from: Option<&AccountId>,
+        to: Option<&AccountId>,
+    ) -> Result<(), PSP22Error>;
+}
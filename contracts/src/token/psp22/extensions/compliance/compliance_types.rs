@@ -0,0 +1,15 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+/// Defines which accounts are allowed to hold and move tokens.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub enum ComplianceMode {
+    /// Every account except the frozen ones.
+    #[default]
+    DenyList,
+    /// Only allowed accounts that are not frozen.
+    AllowList,
+}
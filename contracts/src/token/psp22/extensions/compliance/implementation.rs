@@ -0,0 +1,129 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use ink::{primitives::AccountId, storage::Mapping};
+use pendzl::traits::StorageFieldGetter;
+
+use super::{
+    AllowListUpdated, ComplianceMode, ComplianceModeChanged, Frozen,
+    PSP22ComplianceInternal, PSP22ComplianceStorage, Unfrozen,
+};
+use crate::token::psp22::PSP22Error;
+
+#[derive(Default, Debug)]
+#[pendzl::storage_item]
+pub struct PSP22ComplianceData {
+    #[lazy]
+    pub mode: ComplianceMode,
+    pub frozen: Mapping<AccountId, ()>,
+    pub allowed: Mapping<AccountId, ()>,
+}
+
+impl PSP22ComplianceStorage for PSP22ComplianceData {
+    fn compliance_mode(&self) -> ComplianceMode {
+        self.mode.get_or_default()
+    }
+
+    fn set_compliance_mode(&mut self, mode: &ComplianceMode) {
+        self.mode.set(mode);
+    }
+
+    fn is_frozen(&self, account: &AccountId) -> bool {
+        self.frozen.contains(account)
+    }
+
+    fn set_frozen(&mut self, account: &AccountId, frozen: bool) {
+        if frozen {
+            self.frozen.insert(account, &());
+        } else {
+            self.frozen.remove(account);
+        }
+    }
+
+    fn is_allowed(&self, account: &AccountId) -> bool {
+        self.allowed.contains(account)
+    }
+
+    fn set_allowed(&mut self, account: &AccountId, allowed: bool) {
+        if allowed {
+            self.allowed.insert(account, &());
+        } else {
+            self.allowed.remove(account);
+        }
+    }
+}
+
+pub trait PSP22ComplianceInternalDefaultImpl:
+    StorageFieldGetter<PSP22ComplianceData> + PSP22ComplianceInternal
+where
+    PSP22ComplianceData: PSP22ComplianceStorage,
+{
+    fn _compliance_mode_default_impl(&self) -> ComplianceMode {
+        self.data().compliance_mode()
+    }
+
+    fn _set_compliance_mode_default_impl(&mut self, mode: &ComplianceMode) {
+        self.data().set_compliance_mode(mode);
+        Self::env().emit_event(ComplianceModeChanged { mode: *mode });
+    }
+
+    fn _is_frozen_default_impl(&self, account: &AccountId) -> bool {
+        self.data().is_frozen(account)
+    }
+
+    fn _freeze_default_impl(
+        &mut self,
+        account: &AccountId,
+    ) -> Result<(), PSP22Error> {
+        if self._is_frozen(account) {
+            return Err(PSP22Error::AccountFrozen);
+        }
+        self.data().set_frozen(account, true);
+        Self::env().emit_event(Frozen { account: *account });
+        Ok(())
+    }
+
+    fn _unfreeze_default_impl(
+        &mut self,
+        account: &AccountId,
+    ) -> Result<(), PSP22Error> {
+        if !self._is_frozen(account) {
+            return Err(PSP22Error::AccountNotFrozen);
+        }
+        self.data().set_frozen(account, false);
+        Self::env().emit_event(Unfrozen { account: *account });
+        Ok(())
+    }
+
+    fn _is_allowed_default_impl(&self, account: &AccountId) -> bool {
+        self.data().is_allowed(account)
+    }
+
+    fn _set_allowed_default_impl(
+        &mut self,
+        account: &AccountId,
+        allowed: bool,
+    ) {
+        self.data().set_allowed(account, allowed);
+        Self::env().emit_event(AllowListUpdated {
+            account: *account,
+            allowed,
+        });
+    }
+
+    fn _check_compliance_default_impl(
+        &self,
+        from: Option<&AccountId>,
+        to: Option<&AccountId>,
+    ) -> Result<(), PSP22Error> {
+        let mode = self._compliance_mode();
+        for account in [from, to].into_iter().flatten() {
+            if self._is_frozen(account) {
+                return Err(PSP22Error::AccountFrozen);
+            }
+            if mode == ComplianceMode::AllowList && !self._is_allowed(account) {
+                return Err(PSP22Error::AccountNotAllowed);
+            }
+        }
+        Ok(())
+    }
+}
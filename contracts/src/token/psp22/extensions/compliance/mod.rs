@@ -0,0 +1,30 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+pub use crate::token::psp22::PSP22Error;
+pub use ink::primitives::AccountId;
+pub use pendzl::traits::Balance;
+
+include!("compliance_types.rs");
+include!("compliance_events.rs");
+include!("compliance_trait.rs");
+
+#[cfg(feature = "psp22_compliance_impl")]
+mod implementation;
+
+#[cfg(feature = "psp22_compliance_impl")]
+pub use implementation::*;
+
+#[cfg(all(feature = "psp22_compliance_impl", feature = "access_control_impl"))]
+mod access_control_implementation;
+
+#[cfg(all(feature = "psp22_compliance_impl", feature = "ownable_impl"))]
+mod ownable_implementation;
+
+#[cfg(all(
+    feature = "psp22_compliance_impl",
+    feature = "access_control_impl"
+))]
+pub use access_control_implementation::*;
+
+#[cfg(all(feature = "psp22_compliance_impl", feature = "ownable_impl"))]
+pub use ownable_implementation::*;
@@ -0,0 +1,11 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+/// Emitted together with `Approval` when the allowance of `spender` for `owner`'s tokens is set to expire at `expires_at`.
+#[ink::event]
+pub struct ApprovalExpiry {
+    #[ink(topic)]
+    pub owner: AccountId,
+    #[ink(topic)]
+    pub spender: AccountId,
+    pub expires_at: Timestamp,
+}
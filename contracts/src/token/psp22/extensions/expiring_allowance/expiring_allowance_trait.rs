@@ -0,0 +1,118 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use ink::{contract_ref, env::DefaultEnvironment};
+
+pub type PSP22ExpiringAllowanceRef =
+    contract_ref!(PSP22ExpiringAllowance, DefaultEnvironment);
+
+/// trait extending PSP22 with allowances that expire at a timestamp.
+///
+/// Once expired, `allowance` returns 0 and the allowance can not be spent.
+/// `increase_allowance` and `decrease_allowance` of an expired allowance start from 0 without expiry.
+/// Allowances set by `approve` don't expire.
+#[ink::trait_definition]
+pub trait PSP22ExpiringAllowance {
+    /// Allows `spender` to withdraw up to `value` tokens from the caller's account until `expires_at`.
+    ///
+    /// On success `Approval` and `ApprovalExpiry` events are emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidAllowanceExpiry` if `expires_at` is not in the future.
+    #[ink(message)]
+    fn approve_until(
+        &mut self,
+        spender: AccountId,
+        value: Balance,
+        expires_at: Timestamp,
+    ) -> Result<(), PSP22Error>;
+
+    /// Returns the timestamp at which the allowance of `spender` for `owner`'s tokens expires
+    /// or None if it doesn't expire.
+    #[ink(message)]
+    fn allowance_expiry(
+        &self,
+        owner: AccountId,
+        spender: AccountId,
+    ) -> Option<Timestamp>;
+}
+
+/// trait that must be implemented by exactly one storage field of a contract storage
+/// so the Pendzl PSP22ExpiringAllowanceInternal and PSP22ExpiringAllowance implementation can be derived.
+pub trait PSP22ExpiringAllowanceStorage {
+    /// Returns the expiry of the allowance of `spender` for `owner`'s tokens.
+    fn expiry(&self, owner: &AccountId, spender: &AccountId)
+        -> Option<Timestamp>;
+
+    /// Sets the expiry of the allowance of `spender` for `owner`'s tokens. None removes the expiry.
+    fn set_expiry(
+        &mut self,
+        owner: &AccountId,
+        spender: &AccountId,
+        expires_at: &Option<Timestamp>,
+    );
+}
+
+/// trait that is derived by Pendzl PSP22ExpiringAllowance implementation macro assuming StorageFieldGetter<PSP22ExpiringAllowanceStorage> is implemented
+///
+/// functions of this trait are recomended to use while writing ink::messages
+pub trait PSP22ExpiringAllowanceInternal {
+    /// Sets the allowance of `spender` for `owner`'s tokens to `value` expiring at `expires_at`.
+    ///
+    /// On success `Approval` and `ApprovalExpiry` events are emitted.
+    ///
+    /// # Errors
+    /// Returns `InvalidAllowanceExpiry` if `expires_at` is not in the future.
+    fn _approve_until(
+        &mut self,
+        owner: &AccountId,
+        spender: &AccountId,
+        value: &Balance,
+        expires_at: &Timestamp,
+    ) -> Result<(), PSP22Error>;
+
+    /// Returns the timestamp at which the allowance of `spender` for `owner`'s tokens expires
+    /// or None if it doesn't expire.
+    fn _allowance_expiry(
+        &self,
+        owner: &AccountId,
+        spender: &AccountId,
+    ) -> Option<Timestamp>;
+
+    /// Returns true if the allowance of `spender` for `owner`'s tokens has expired.
+    ///
+    /// Used by the generated `PSP22Internal::_allowance` to return 0 for expired allowances.
+    fn _is_allowance_expired(
+        &self,
+        owner: &AccountId,
+        spender: &AccountId,
+    ) -> bool;
+
+    /// Removes the expiry of the allowance of `spender` for `owner`'s tokens.
+    ///
+    /// Called by the generated `PSP22Internal::_approve` so allowances set by `approve` don't expire.
+    fn _clear_allowance_expiry(&mut self, owner: &AccountId, spender: &AccountId);
+
+    /// Resets the allowance of `spender` for `owner`'s tokens to 0 and removes its expiry if it has expired.
+    ///
+    /// Called by the generated `PSP22::increase_allowance` and `PSP22::decrease_allowance`
+    /// so the owner changes an expired allowance starting from 0.
+    fn _reset_expired_allowance(
+        &mut self,
+        owner: &AccountId,
+        spender: &AccountId,
+    ) -> Result<(), PSP22Error>;
+
+    /// Checks that the allowance of `spender` for `owner`'s tokens has not expired.
+    ///
+    /// Called by the generated `PSP22Internal::_decrease_allowance_from_to` before the allowance is spent.
+    /// Expired allowances are reset before the owner decreases them, so only spending is rejected.
+    ///
+    /// # Errors
+    /// Returns `AllowanceExpired` if the allowance has expired.
+    fn _check_allowance_expiry(
+        &self,
+        owner: &AccountId,
+        spender: &AccountId,
+    ) -> Result<(), PSP22Error>;
+}
@@ -0,0 +1,146 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use ink::{primitives::AccountId, storage::Mapping};
+use pendzl::traits::{DefaultEnv, StorageFieldGetter, Timestamp};
+
+use super::{
+    ApprovalExpiry, PSP22ExpiringAllowanceInternal,
+    PSP22ExpiringAllowanceStorage,
+};
+use crate::token::psp22::{Balance, PSP22Error, PSP22Internal};
+
+#[derive(Default, Debug)]
+#[pendzl::storage_item]
+pub struct PSP22ExpiringAllowanceData {
+    pub expirations: Mapping<(AccountId, AccountId), Timestamp>,
+}
+
+impl PSP22ExpiringAllowanceStorage for PSP22ExpiringAllowanceData {
+    fn expiry(
+        &self,
+        owner: &AccountId,
+        spender: &AccountId,
+    ) -> Option<Timestamp> {
+        self.expirations.get((*owner, *spender))
+    }
+
+    fn set_expiry(
+        &mut self,
+        owner: &AccountId,
+        spender: &AccountId,
+        expires_at: &Option<Timestamp>,
+    ) {
+        match expires_at {
+            Some(expires_at) => {
+                self.expirations.insert((*owner, *spender), expires_at);
+            }
+            None => self.expirations.remove((*owner, *spender)),
+        }
+    }
+}
+
+pub trait PSP22ExpiringAllowanceDefaultImpl:
+    PSP22ExpiringAllowanceInternal + DefaultEnv
+{
+    fn approve_until_default_impl(
+        &mut self,
+        spender: AccountId,
+        value: Balance,
+        expires_at: Timestamp,
+    ) -> Result<(), PSP22Error> {
+        self._approve_until(
+            &Self::env().caller(),
+            &spender,
+            &value,
+            &expires_at,
+        )
+    }
+
+    fn allowance_expiry_default_impl(
+        &self,
+        owner: AccountId,
+        spender: AccountId,
+    ) -> Option<Timestamp> {
+        self._allowance_expiry(&owner, &spender)
+    }
+}
+
+pub trait PSP22ExpiringAllowanceInternalDefaultImpl:
+    StorageFieldGetter<PSP22ExpiringAllowanceData>
+    + PSP22ExpiringAllowanceInternal
+    + PSP22Internal
+where
+    PSP22ExpiringAllowanceData: PSP22ExpiringAllowanceStorage,
+{
+    fn _approve_until_default_impl(
+        &mut self,
+        owner: &AccountId,
+        spender: &AccountId,
+        value: &Balance,
+        expires_at: &Timestamp,
+    ) -> Result<(), PSP22Error> {
+        if *expires_at <= Self::env().block_timestamp() {
+            return Err(PSP22Error::InvalidAllowanceExpiry);
+        }
+        self._approve(owner, spender, value)?;
+        self.data().set_expiry(owner, spender, &Some(*expires_at));
+
+        Self::env().emit_event(ApprovalExpiry {
+            owner: *owner,
+            spender: *spender,
+            expires_at: *expires_at,
+        });
+        Ok(())
+    }
+
+    fn _allowance_expiry_default_impl(
+        &self,
+        owner: &AccountId,
+        spender: &AccountId,
+    ) -> Option<Timestamp> {
+        self.data().expiry(owner, spender)
+    }
+
+    fn _is_allowance_expired_default_impl(
+        &self,
+        owner: &AccountId,
+        spender: &AccountId,
+    ) -> bool {
+        self.data()
+            .expiry(owner, spender)
+            .is_some_and(|expires_at| {
+                expires_at <= Self::env().block_timestamp()
+            })
+    }
+
+    fn _clear_allowance_expiry_default_impl(
+        &mut self,
+        owner: &AccountId,
+        spender: &AccountId,
+    ) {
+        self.data().set_expiry(owner, spender, &None);
+    }
+
+    fn _reset_expired_allowance_default_impl(
+        &mut self,
+        owner: &AccountId,
+        spender: &AccountId,
+    ) -> Result<(), PSP22Error> {
+        if self._is_allowance_expired(owner, spender) {
+            self.data().set_expiry(owner, spender, &None);
+            self._approve(owner, spender, &0)?;
+        }
+        Ok(())
+    }
+
+    fn _check_allowance_expiry_default_impl(
+        &self,
+        owner: &AccountId,
+        spender: &AccountId,
+    ) -> Result<(), PSP22Error> {
+        if self._is_allowance_expired(owner, spender) {
+            return Err(PSP22Error::AllowanceExpired);
+        }
+        Ok(())
+    }
+}
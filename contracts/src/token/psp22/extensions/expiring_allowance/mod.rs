@@ -0,0 +1,14 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+pub use crate::token::psp22::PSP22Error;
+pub use ink::primitives::AccountId;
+pub use pendzl::traits::{Balance, Timestamp};
+
+include!("expiring_allowance_events.rs");
+include!("expiring_allowance_trait.rs");
+
+#[cfg(feature = "psp22_expiring_allowance_impl")]
+mod implementation;
+
+#[cfg(feature = "psp22_expiring_allowance_impl")]
+pub use implementation::*;
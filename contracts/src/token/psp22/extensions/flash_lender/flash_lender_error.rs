@@ -0,0 +1,21 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use pendzl::traits::String;
+
+/// The PSP22FlashBorrower error type. Returned by a borrower contract that rejects the flash loan.
+#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum PSP22FlashBorrowerError {
+    /// Returned if the flash loan is rejected.
+    FlashLoanRejected(String),
+}
+
+impl From<PSP22FlashBorrowerError> for PSP22Error {
+    fn from(error: PSP22FlashBorrowerError) -> Self {
+        match error {
+            PSP22FlashBorrowerError::FlashLoanRejected(message) => {
+                PSP22Error::FlashLoanRejected(message)
+            }
+        }
+    }
+}
@@ -0,0 +1,82 @@
+// Copyright (c) 2012-2022 Supercolony. All Rights Reserved.
+// Copyright (c) 2023 Brushfam. All Rights Reserved.
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use ink::{contract_ref, env::DefaultEnvironment, prelude::vec::Vec};
+
+pub type PSP22FlashLenderRef =
+    contract_ref!(PSP22FlashLender, DefaultEnvironment);
+
+/// trait extending PSP22 with flash minting (ERC-3156 analogue).
+///
+/// The loaned tokens are minted to the receiver, which has to approve the token contract
+/// to spend `amount + fee` before its `PSP22FlashBorrower::on_flash_loan` returns.
+/// `amount + fee` is then burned from the receiver.
+#[ink::trait_definition]
+pub trait PSP22FlashLender {
+    /// Returns the maximum amount of tokens available for a flash loan.
+    #[ink(message)]
+    fn max_flash_loan(&self) -> Balance;
+
+    /// Returns the fee charged for a flash loan of `amount` tokens.
+    #[ink(message)]
+    fn flash_fee(&self, amount: Balance) -> Result<Balance, PSP22Error>;
+
+    /// Mints `amount` tokens to `receiver`, calls `PSP22FlashBorrower::on_flash_loan`
+    /// on `receiver` and burns `amount + fee` from `receiver`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FlashLoanAmountExceeded` if `amount` is greater than `max_flash_loan`.
+    /// Returns `FlashLoanRejected` if `receiver` rejects the loan, doesn't implement `PSP22FlashBorrower` or the call traps.
+    /// Returns `InsufficientAllowance` if `receiver` didn't approve `amount + fee` to the token contract.
+    /// Returns `InsufficientBalance` if `receiver` doesn't hold `amount + fee` tokens after the callback.
+    #[ink(message)]
+    fn flash_loan(
+        &mut self,
+        receiver: AccountId,
+        amount: Balance,
+        data: Vec<u8>,
+    ) -> Result<(), PSP22Error>;
+}
+
+/// trait that is derived by Pendzl PSP22FlashLender implementation macro
+///
+/// functions of this trait are recomended to use while writing ink::messages
+pub trait PSP22FlashLenderInternal {
+    /// Returns the maximum amount of tokens available for a flash loan.
+    fn _max_flash_loan(&self) -> Balance;
+
+    /// Returns the fee charged for a flash loan of `amount` tokens. 0 by default.
+    fn _flash_fee(&self, amount: &Balance) -> Result<Balance, PSP22Error>;
+
+    /// Mints `amount` tokens to `receiver`, calls `PSP22FlashBorrower::on_flash_loan`
+    /// on `receiver` and burns `amount + fee` from `receiver`.
+    fn _flash_loan(
+        &mut self,
+        receiver: &AccountId,
+        amount: &Balance,
+        data: &Vec<u8>,
+    ) -> Result<(), PSP22Error>;
+}
+
+pub type PSP22FlashBorrowerRef =
+    contract_ref!(PSP22FlashBorrower, DefaultEnvironment);
+
+/// PSP22FlashBorrower is an interface for any contract that wants to receive flash loans from PSP22FlashLender token smart contracts.
+#[ink::trait_definition]
+pub trait PSP22FlashBorrower {
+    /// Receives a flash loan of `amount` tokens initiated by `initiator`.
+    ///
+    /// Before returning `Ok(())` the borrower has to approve the token contract
+    /// to spend `amount + fee` of its tokens.
+    /// Returns Error `FlashLoanRejected(String)` to reject the loan.
+    #[ink(message)]
+    fn on_flash_loan(
+        &mut self,
+        initiator: AccountId,
+        amount: Balance,
+        fee: Balance,
+        data: Vec<u8>,
+    ) -> Result<(), PSP22FlashBorrowerError>;
+}
@@ -0,0 +1,96 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use ink::{
+    codegen::TraitCallBuilder,
+    env::CallFlags,
+    prelude::{string::String, vec::Vec},
+    primitives::AccountId,
+};
+use pendzl::math::errors::MathError;
+use pendzl::traits::DefaultEnv;
+
+use super::{
+    PSP22FlashBorrower, PSP22FlashBorrowerRef, PSP22FlashLenderInternal,
+};
+use crate::token::psp22::{Balance, PSP22Error, PSP22Internal};
+
+pub trait PSP22FlashLenderDefaultImpl: PSP22FlashLenderInternal {
+    fn max_flash_loan_default_impl(&self) -> Balance {
+        self._max_flash_loan()
+    }
+
+    fn flash_fee_default_impl(
+        &self,
+        amount: Balance,
+    ) -> Result<Balance, PSP22Error> {
+        self._flash_fee(&amount)
+    }
+
+    fn flash_loan_default_impl(
+        &mut self,
+        receiver: AccountId,
+        amount: Balance,
+        data: Vec<u8>,
+    ) -> Result<(), PSP22Error> {
+        self._flash_loan(&receiver, &amount, &data)
+    }
+}
+
+pub trait PSP22FlashLenderInternalDefaultImpl:
+    PSP22Internal + PSP22FlashLenderInternal + DefaultEnv
+{
+    fn _max_flash_loan_default_impl(&self) -> Balance {
+        Balance::MAX.saturating_sub(self._total_supply())
+    }
+
+    fn _flash_fee_default_impl(
+        &self,
+        _amount: &Balance,
+    ) -> Result<Balance, PSP22Error> {
+        Ok(0)
+    }
+
+    fn _flash_loan_default_impl(
+        &mut self,
+        receiver: &AccountId,
+        amount: &Balance,
+        data: &Vec<u8>,
+    ) -> Result<(), PSP22Error> {
+        if *amount > self._max_flash_loan() {
+            return Err(PSP22Error::FlashLoanAmountExceeded);
+        }
+        let fee = self._flash_fee(amount)?;
+        self._mint_to(receiver, amount)?;
+
+        let mut borrower: PSP22FlashBorrowerRef = (*receiver).into();
+        let result = borrower
+            .call_mut()
+            .on_flash_loan(Self::env().caller(), *amount, fee, data.clone())
+            .call_v1()
+            .call_flags(CallFlags::ALLOW_REENTRY)
+            .try_invoke();
+
+        match result {
+            Ok(Ok(Ok(()))) => {}
+            Ok(Ok(Err(e))) => return Err(e.into()),
+            Ok(Err(_)) => {
+                return Err(PSP22Error::FlashLoanRejected(String::from(
+                    "Receiver doesn't implement PSP22FlashBorrower",
+                )))
+            }
+            Err(_) => {
+                return Err(PSP22Error::FlashLoanRejected(String::from(
+                    "Error during call to receiver",
+                )))
+            }
+        }
+
+        let repayment = amount.checked_add(fee).ok_or(MathError::Overflow)?;
+        self._decrease_allowance_from_to(
+            receiver,
+            &Self::env().account_id(),
+            &repayment,
+        )?;
+        self._burn_from(receiver, &repayment)
+    }
+}
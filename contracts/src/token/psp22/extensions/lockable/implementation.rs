@@ -0,0 +1,141 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use ink::{prelude::vec::Vec, primitives::AccountId, storage::Mapping};
+use pendzl::traits::{StorageFieldGetter, Timestamp};
+
+use super::{
+    Lock, Locked, PSP22LockableInternal, PSP22LockableStorage, Unlocked,
+};
+use crate::token::psp22::{Balance, PSP22Error, PSP22Internal};
+
+#[derive(Default, Debug)]
+#[pendzl::storage_item]
+pub struct PSP22LockableData {
+    pub locks: Mapping<AccountId, Vec<Lock>>,
+}
+
+impl PSP22LockableStorage for PSP22LockableData {
+    fn locks(&self, account: &AccountId) -> Vec<Lock> {
+        self.locks.get(account).unwrap_or_default()
+    }
+
+    fn set_locks(&mut self, account: &AccountId, locks: &Vec<Lock>) {
+        if locks.is_empty() {
+            self.locks.remove(account);
+        } else {
+            self.locks.insert(account, locks);
+        }
+    }
+}
+
+pub trait PSP22LockableDefaultImpl: PSP22LockableInternal {
+    fn locked_balance_of_default_impl(&self, account: AccountId) -> Balance {
+        self._locked_balance_of(&account)
+    }
+
+    fn unlocked_balance_of_default_impl(&self, account: AccountId) -> Balance {
+        self._unlocked_balance_of(&account)
+    }
+
+    fn locks_of_default_impl(&self, account: AccountId) -> Vec<Lock> {
+        self._locks_of(&account)
+    }
+}
+
+pub trait PSP22LockableInternalDefaultImpl:
+    StorageFieldGetter<PSP22LockableData> + PSP22Internal + PSP22LockableInternal
+where
+    PSP22LockableData: PSP22LockableStorage,
+{
+    fn _locked_balance_of_default_impl(&self, account: &AccountId) -> Balance {
+        let now = Self::env().block_timestamp();
+        self.data()
+            .locks(account)
+            .iter()
+            .filter(|lock| lock.until > now)
+            .fold(0, |locked, lock| locked.saturating_add(lock.amount))
+    }
+
+    fn _unlocked_balance_of_default_impl(
+        &self,
+        account: &AccountId,
+    ) -> Balance {
+        self._balance_of(account)
+            .saturating_sub(self._locked_balance_of(account))
+    }
+
+    fn _locks_of_default_impl(&self, account: &AccountId) -> Vec<Lock> {
+        self.data().locks(account)
+    }
+
+    fn _lock_default_impl(
+        &mut self,
+        account: &AccountId,
+        amount: &Balance,
+        until: &Timestamp,
+    ) -> Result<(), PSP22Error> {
+        if *until <= Self::env().block_timestamp() {
+            return Err(PSP22Error::InvalidLock);
+        }
+        if *amount > self._unlocked_balance_of(account) {
+            return Err(PSP22Error::InsufficientBalance);
+        }
+        self._unlock_expired(account);
+
+        let mut locks = self.data().locks(account);
+        locks.push(Lock {
+            amount: *amount,
+            until: *until,
+        });
+        self.data().set_locks(account, &locks);
+
+        Self::env().emit_event(Locked {
+            account: *account,
+            amount: *amount,
+            until: *until,
+        });
+        Ok(())
+    }
+
+    fn _unlock_expired_default_impl(&mut self, account: &AccountId) -> Balance {
+        let now = Self::env().block_timestamp();
+        let (active, expired): (Vec<Lock>, Vec<Lock>) = self
+            .data()
+            .locks(account)
+            .into_iter()
+            .partition(|lock| lock.until > now);
+        if expired.is_empty() {
+            return 0;
+        }
+        self.data().set_locks(account, &active);
+
+        let amount = expired.iter().fold(0, |unlocked: Balance, lock| {
+            unlocked.saturating_add(lock.amount)
+        });
+        Self::env().emit_event(Unlocked {
+            account: *account,
+            amount,
+        });
+        amount
+    }
+
+    fn _check_locked_default_impl(
+        &self,
+        from: Option<&AccountId>,
+        amount: &Balance,
+    ) -> Result<(), PSP22Error> {
+        let from = match from {
+            Some(from) => from,
+            None => return Ok(()),
+        };
+        let balance = self._balance_of(from);
+        if *amount > balance {
+            // InsufficientBalance is returned by the update itself
+            return Ok(());
+        }
+        if balance - *amount < self._locked_balance_of(from) {
+            return Err(PSP22Error::LockedBalance);
+        }
+        Ok(())
+    }
+}
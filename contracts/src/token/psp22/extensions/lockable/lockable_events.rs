@@ -0,0 +1,18 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+/// Emitted when `amount` of `account`'s balance is locked until `until`.
+#[ink::event]
+pub struct Locked {
+    #[ink(topic)]
+    pub account: AccountId,
+    pub amount: Balance,
+    pub until: Timestamp,
+}
+
+/// Emitted when expired locks of `account` holding `amount` in total are removed.
+#[ink::event]
+pub struct Unlocked {
+    #[ink(topic)]
+    pub account: AccountId,
+    pub amount: Balance,
+}
@@ -0,0 +1,79 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use ink::{contract_ref, env::DefaultEnvironment, prelude::vec::Vec};
+
+pub type PSP22LockableRef = contract_ref!(PSP22Lockable, DefaultEnvironment);
+
+/// trait extending PSP22 with balances locked until a timestamp.
+///
+/// Locked tokens stay in the account's balance (so they e.g. keep their voting power)
+/// but can not be transferred or burned before their lock expires.
+#[ink::trait_definition]
+pub trait PSP22Lockable {
+    /// Returns the amount of `account`'s balance that is currently locked.
+    #[ink(message)]
+    fn locked_balance_of(&self, account: AccountId) -> Balance;
+
+    /// Returns the amount of `account`'s balance that can be transferred.
+    #[ink(message)]
+    fn unlocked_balance_of(&self, account: AccountId) -> Balance;
+
+    /// Returns the locks of `account`, including the expired ones that were not removed yet.
+    #[ink(message)]
+    fn locks_of(&self, account: AccountId) -> Vec<Lock>;
+}
+
+/// trait that must be implemented by exactly one storage field of a contract storage
+/// so the Pendzl PSP22LockableInternal and PSP22Lockable implementation can be derived.
+pub trait PSP22LockableStorage {
+    /// Retrieves the locks of `account`.
+    fn locks(&self, account: &AccountId) -> Vec<Lock>;
+
+    /// Sets the locks of `account`.
+    fn set_locks(&mut self, account: &AccountId, locks: &Vec<Lock>);
+}
+
+/// trait that is derived by Pendzl PSP22Lockable implementation macro assuming StorageFieldGetter<PSP22LockableStorage> is implemented
+///
+/// functions of this trait are recomended to use while writing ink::messages
+pub trait PSP22LockableInternal {
+    /// Returns the amount of `account`'s balance that is currently locked.
+    fn _locked_balance_of(&self, account: &AccountId) -> Balance;
+
+    /// Returns the amount of `account`'s balance that can be transferred.
+    fn _unlocked_balance_of(&self, account: &AccountId) -> Balance;
+
+    /// Returns the locks of `account`.
+    fn _locks_of(&self, account: &AccountId) -> Vec<Lock>;
+
+    /// Locks `amount` of `account`'s balance until `until`. Removes the expired locks of `account`.
+    ///
+    /// On success a `Locked` event is emitted.
+    ///
+    /// # Errors
+    /// Returns `InvalidLock` if `until` is not in the future.
+    /// Returns `InsufficientBalance` if `account`'s unlocked balance is lower than `amount`.
+    fn _lock(
+        &mut self,
+        account: &AccountId,
+        amount: &Balance,
+        until: &Timestamp,
+    ) -> Result<(), PSP22Error>;
+
+    /// Removes the expired locks of `account` and returns the amount they were holding.
+    ///
+    /// An `Unlocked` event is emitted if any lock was removed.
+    fn _unlock_expired(&mut self, account: &AccountId) -> Balance;
+
+    /// Checks that moving `amount` tokens `from` doesn't use its locked balance.
+    ///
+    /// Called by the generated `PSP22Internal::_update` before the balances are updated.
+    ///
+    /// # Errors
+    /// Returns `LockedBalance` if `amount` is greater than the unlocked balance of `from`.
+    fn _check_locked(
+        &self,
+        from: Option<&AccountId>,
+        amount: &Balance,
+    ) -> Result<(), PSP22Error>;
+}
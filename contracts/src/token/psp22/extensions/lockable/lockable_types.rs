@@ -0,0 +1,14 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use scale::{Decode, Encode};
+
+/// `amount` of an account's balance that can not be transferred before `until`.
+#[derive(Debug, Encode, Decode, Clone, Copy, Default, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct Lock {
+    pub amount: Balance,
+    pub until: Timestamp,
+}
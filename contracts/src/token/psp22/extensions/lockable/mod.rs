@@ -0,0 +1,15 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+pub use crate::token::psp22::PSP22Error;
+pub use ink::primitives::AccountId;
+pub use pendzl::traits::{Balance, Timestamp};
+
+include!("lockable_types.rs");
+include!("lockable_events.rs");
+include!("lockable_trait.rs");
+
+#[cfg(feature = "psp22_lockable_impl")]
+mod implementation;
+
+#[cfg(feature = "psp22_lockable_impl")]
+pub use implementation::*;
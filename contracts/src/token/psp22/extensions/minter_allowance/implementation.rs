@@ -0,0 +1,116 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use ink::{primitives::AccountId, storage::Mapping};
+use pendzl::traits::{DefaultEnv, StorageFieldGetter};
+
+use super::{
+    MinterConfigured, MinterRemoved, PSP22MinterAllowanceInternal,
+    PSP22MinterAllowanceStorage,
+};
+use crate::access::access_control::{AccessControlInternal, RoleType};
+use crate::token::psp22::{Balance, PSP22Error};
+
+pub const MINTER_ADMIN: RoleType = ink::selector_id!("MINTER_ADMIN");
+
+#[derive(Default, Debug)]
+#[pendzl::storage_item]
+pub struct PSP22MinterAllowanceData {
+    pub minter_allowances: Mapping<AccountId, Balance>,
+}
+
+impl PSP22MinterAllowanceStorage for PSP22MinterAllowanceData {
+    fn minter_allowance(&self, minter: &AccountId) -> Option<Balance> {
+        self.minter_allowances.get(minter)
+    }
+
+    fn set_minter_allowance(
+        &mut self,
+        minter: &AccountId,
+        allowance: &Balance,
+    ) {
+        self.minter_allowances.insert(minter, allowance);
+    }
+
+    fn remove_minter(&mut self, minter: &AccountId) {
+        self.minter_allowances.remove(minter);
+    }
+}
+
+pub trait PSP22MinterAllowanceDefaultImpl:
+    AccessControlInternal + PSP22MinterAllowanceInternal + DefaultEnv
+{
+    fn minter_allowance_default_impl(&self, minter: AccountId) -> Balance {
+        self._minter_allowance(&minter)
+    }
+
+    fn is_minter_default_impl(&self, account: AccountId) -> bool {
+        self._is_minter(&account)
+    }
+
+    fn configure_minter_default_impl(
+        &mut self,
+        minter: AccountId,
+        allowance: Balance,
+    ) -> Result<(), PSP22Error> {
+        self._ensure_has_role(MINTER_ADMIN, Some(Self::env().caller()))?;
+        self._configure_minter(&minter, &allowance);
+        Ok(())
+    }
+
+    fn remove_minter_default_impl(
+        &mut self,
+        minter: AccountId,
+    ) -> Result<(), PSP22Error> {
+        self._ensure_has_role(MINTER_ADMIN, Some(Self::env().caller()))?;
+        self._remove_minter(&minter);
+        Ok(())
+    }
+}
+
+pub trait PSP22MinterAllowanceInternalDefaultImpl:
+    StorageFieldGetter<PSP22MinterAllowanceData>
+where
+    PSP22MinterAllowanceData: PSP22MinterAllowanceStorage,
+{
+    fn _minter_allowance_default_impl(&self, minter: &AccountId) -> Balance {
+        self.data().minter_allowance(minter).unwrap_or_default()
+    }
+
+    fn _is_minter_default_impl(&self, account: &AccountId) -> bool {
+        self.data().minter_allowance(account).is_some()
+    }
+
+    fn _configure_minter_default_impl(
+        &mut self,
+        minter: &AccountId,
+        allowance: &Balance,
+    ) {
+        self.data().set_minter_allowance(minter, allowance);
+        Self::env().emit_event(MinterConfigured {
+            minter: *minter,
+            allowance: *allowance,
+        });
+    }
+
+    fn _remove_minter_default_impl(&mut self, minter: &AccountId) {
+        self.data().remove_minter(minter);
+        Self::env().emit_event(MinterRemoved { minter: *minter });
+    }
+
+    fn _spend_minter_allowance_default_impl(
+        &mut self,
+        minter: &AccountId,
+        amount: &Balance,
+    ) -> Result<(), PSP22Error> {
+        let allowance = self
+            .data()
+            .minter_allowance(minter)
+            .ok_or(PSP22Error::MinterAllowanceExceeded)?;
+        if allowance < *amount {
+            return Err(PSP22Error::MinterAllowanceExceeded);
+        }
+        self.data()
+            .set_minter_allowance(minter, &(allowance - *amount));
+        Ok(())
+    }
+}
@@ -0,0 +1,17 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+
+/// Emitted when `minter` is allowed to mint up to `allowance` tokens.
+#[ink::event]
+pub struct MinterConfigured {
+    #[ink(topic)]
+    pub minter: AccountId,
+    pub allowance: Balance,
+}
+
+/// Emitted when `minter` is no longer allowed to mint.
+#[ink::event]
+pub struct MinterRemoved {
+    #[ink(topic)]
+    pub minter: AccountId,
+}
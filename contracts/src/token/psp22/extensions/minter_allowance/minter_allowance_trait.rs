@@ -0,0 +1,92 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use ink::{contract_ref, env::DefaultEnvironment};
+
+pub type PSP22MinterAllowanceRef =
+    contract_ref!(PSP22MinterAllowance, DefaultEnvironment);
+
+/// trait extending PSP22Mintable with a per-minter allowance.
+///
+/// Only configured minters can mint and each mint decreases the minter's remaining allowance.
+/// Configuring minters is restricted to accounts with the `MINTER_ADMIN` role.
+/// `#[pendzl::implementation]` fails to compile if PSP22MinterAllowance is used without PSP22Mintable.
+#[ink::trait_definition]
+pub trait PSP22MinterAllowance {
+    /// Returns the amount of tokens `minter` is still allowed to mint.
+    #[ink(message)]
+    fn minter_allowance(&self, minter: AccountId) -> Balance;
+
+    /// Returns true if `account` is a configured minter.
+    #[ink(message)]
+    fn is_minter(&self, account: AccountId) -> bool;
+
+    /// Makes `minter` a minter allowed to mint up to `allowance` tokens, replacing its previous allowance.
+    ///
+    /// On success a `MinterConfigured` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Custom("AC::MissingRole")` if the caller doesn't have the `MINTER_ADMIN` role.
+    #[ink(message)]
+    fn configure_minter(
+        &mut self,
+        minter: AccountId,
+        allowance: Balance,
+    ) -> Result<(), PSP22Error>;
+
+    /// Removes `minter` from minters.
+    ///
+    /// On success a `MinterRemoved` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Custom("AC::MissingRole")` if the caller doesn't have the `MINTER_ADMIN` role.
+    #[ink(message)]
+    fn remove_minter(&mut self, minter: AccountId) -> Result<(), PSP22Error>;
+}
+
+/// trait that must be implemented by exactly one storage field of a contract storage
+/// so the Pendzl PSP22MinterAllowanceInternal and PSP22MinterAllowance implementation can be derived.
+pub trait PSP22MinterAllowanceStorage {
+    /// Returns the remaining allowance of `minter` or None if `minter` is not a minter.
+    fn minter_allowance(&self, minter: &AccountId) -> Option<Balance>;
+
+    /// Sets the remaining allowance of `minter` to `allowance`.
+    fn set_minter_allowance(&mut self, minter: &AccountId, allowance: &Balance);
+
+    /// Removes `minter` from minters.
+    fn remove_minter(&mut self, minter: &AccountId);
+}
+
+/// trait that is derived by Pendzl PSP22MinterAllowance implementation macro assuming StorageFieldGetter<PSP22MinterAllowanceStorage> is implemented
+///
+/// functions of this trait are recomended to use while writing ink::messages
+pub trait PSP22MinterAllowanceInternal {
+    /// Returns the amount of tokens `minter` is still allowed to mint.
+    fn _minter_allowance(&self, minter: &AccountId) -> Balance;
+
+    /// Returns true if `account` is a configured minter.
+    fn _is_minter(&self, account: &AccountId) -> bool;
+
+    /// Makes `minter` a minter allowed to mint up to `allowance` tokens. Doesn't check the caller's role.
+    ///
+    /// On success a `MinterConfigured` event is emitted.
+    fn _configure_minter(&mut self, minter: &AccountId, allowance: &Balance);
+
+    /// Removes `minter` from minters. Doesn't check the caller's role.
+    ///
+    /// On success a `MinterRemoved` event is emitted.
+    fn _remove_minter(&mut self, minter: &AccountId);
+
+    /// Decreases the remaining allowance of `minter` by `amount`.
+    ///
+    /// Called by the generated `PSP22Mintable::mint` with the caller as `minter`.
+    ///
+    /// # Errors
+    /// Returns `MinterAllowanceExceeded` if `minter` is not a minter or its remaining allowance is lower than `amount`.
+    fn _spend_minter_allowance(
+        &mut self,
+        minter: &AccountId,
+        amount: &Balance,
+    ) -> Result<(), PSP22Error>;
+}
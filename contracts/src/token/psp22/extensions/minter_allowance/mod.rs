@@ -0,0 +1,14 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+pub use crate::token::psp22::PSP22Error;
+pub use ink::primitives::AccountId;
+pub use pendzl::traits::Balance;
+
+include!("minter_allowance_events.rs");
+include!("minter_allowance_trait.rs");
+
+#[cfg(feature = "psp22_minter_allowance_impl")]
+mod implementation;
+
+#[cfg(feature = "psp22_minter_allowance_impl")]
+pub use implementation::*;
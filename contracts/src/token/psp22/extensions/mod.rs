@@ -1,10 +1,40 @@
 // Copyright (c) 2024 C Forge. All Rights Reserved.
 // SPDX-License-Identifier: MIT
+#[cfg(feature = "psp22_batch")]
+pub mod batch;
+#[cfg(feature = "psp22_bridgeable")]
+pub mod bridgeable;
 #[cfg(feature = "psp22_burnable")]
 pub mod burnable;
+#[cfg(feature = "psp22_capped")]
+pub mod capped;
+#[cfg(feature = "psp22_compliance")]
+pub mod compliance;
+#[cfg(feature = "psp22_expiring_allowance")]
+pub mod expiring_allowance;
+#[cfg(feature = "psp22_flash_lender")]
+pub mod flash_lender;
+#[cfg(feature = "psp22_lockable")]
+pub mod lockable;
 #[cfg(any(feature = "psp22_metadata", feature = "psp22_vault_metadata"))]
 pub mod metadata;
 #[cfg(feature = "psp22_mintable")]
 pub mod mintable;
+#[cfg(feature = "psp22_minter_allowance")]
+pub mod minter_allowance;
+#[cfg(feature = "psp22_native_wrapper")]
+pub mod native_wrapper;
+#[cfg(feature = "psp22_permit")]
+pub mod permit;
+#[cfg(feature = "psp22_rebasing")]
+pub mod rebasing;
+#[cfg(feature = "psp22_snapshot")]
+pub mod snapshot;
+#[cfg(feature = "psp22_transfer_fee")]
+pub mod transfer_fee;
 #[cfg(feature = "psp22_vault")]
 pub mod vault;
+#[cfg(feature = "psp22_votes")]
+pub mod votes;
+#[cfg(feature = "psp22_wrapper")]
+pub mod wrapper;
@@ -0,0 +1,55 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use ink::primitives::AccountId;
+use pendzl::traits::DefaultEnv;
+
+use super::{Deposit, PSP22NativeWrapperInternal, Withdrawal};
+use crate::token::psp22::{Balance, PSP22Error, PSP22Internal};
+
+pub trait PSP22NativeWrapperDefaultImpl:
+    PSP22NativeWrapperInternal + DefaultEnv
+{
+    fn deposit_default_impl(&mut self) -> Result<(), PSP22Error> {
+        self._deposit(&Self::env().caller(), &Self::env().transferred_value())
+    }
+
+    fn withdraw_default_impl(
+        &mut self,
+        amount: Balance,
+    ) -> Result<(), PSP22Error> {
+        self._withdraw(&Self::env().caller(), &amount)
+    }
+}
+
+pub trait PSP22NativeWrapperInternalDefaultImpl:
+    PSP22Internal + DefaultEnv
+{
+    fn _deposit_default_impl(
+        &mut self,
+        account: &AccountId,
+        amount: &Balance,
+    ) -> Result<(), PSP22Error> {
+        self._mint_to(account, amount)?;
+        Self::env().emit_event(Deposit {
+            account: *account,
+            amount: *amount,
+        });
+        Ok(())
+    }
+
+    fn _withdraw_default_impl(
+        &mut self,
+        account: &AccountId,
+        amount: &Balance,
+    ) -> Result<(), PSP22Error> {
+        self._burn_from(account, amount)?;
+        if Self::env().transfer(*account, *amount).is_err() {
+            return Err(PSP22Error::NativeTransferFailed);
+        }
+        Self::env().emit_event(Withdrawal {
+            account: *account,
+            amount: *amount,
+        });
+        Ok(())
+    }
+}
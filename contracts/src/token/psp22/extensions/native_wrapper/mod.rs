@@ -0,0 +1,14 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+pub use crate::token::psp22::PSP22Error;
+pub use ink::primitives::AccountId;
+pub use pendzl::traits::Balance;
+
+include!("native_wrapper_events.rs");
+include!("native_wrapper_trait.rs");
+
+#[cfg(feature = "psp22_native_wrapper_impl")]
+mod implementation;
+
+#[cfg(feature = "psp22_native_wrapper_impl")]
+pub use implementation::*;
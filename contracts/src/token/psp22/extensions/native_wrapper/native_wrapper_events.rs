@@ -0,0 +1,17 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+/// Emitted when `amount` of native currency is deposited and `amount` tokens are minted to `account`.
+#[ink::event]
+pub struct Deposit {
+    #[ink(topic)]
+    pub account: AccountId,
+    pub amount: Balance,
+}
+
+/// Emitted when `amount` tokens are burned from `account` and `amount` of native currency is sent to `account`.
+#[ink::event]
+pub struct Withdrawal {
+    #[ink(topic)]
+    pub account: AccountId,
+    pub amount: Balance,
+}
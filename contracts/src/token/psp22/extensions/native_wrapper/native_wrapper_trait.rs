@@ -0,0 +1,53 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use ink::{contract_ref, env::DefaultEnvironment};
+
+pub type PSP22NativeWrapperRef =
+    contract_ref!(PSP22NativeWrapper, DefaultEnvironment);
+
+/// trait extending PSP22 with wrapping of the native currency (WETH analogue).
+///
+/// Tokens are minted one-to-one for the native currency transferred with `deposit`
+/// and burned one-to-one for the native currency sent back by `withdraw`.
+#[ink::trait_definition]
+pub trait PSP22NativeWrapper {
+    /// Mints to the caller as many tokens as the native currency transferred with the call.
+    ///
+    /// On success a `Deposit` event is emitted.
+    #[ink(message, payable)]
+    fn deposit(&mut self) -> Result<(), PSP22Error>;
+
+    /// Burns `amount` tokens from the caller and transfers `amount` of native currency to the caller.
+    ///
+    /// On success a `Withdrawal` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InsufficientBalance` if the caller holds less than `amount` tokens.
+    /// Returns `NativeTransferFailed` if the transfer of native currency fails.
+    #[ink(message)]
+    fn withdraw(&mut self, amount: Balance) -> Result<(), PSP22Error>;
+}
+
+/// trait that is derived by Pendzl PSP22NativeWrapper implementation macro
+///
+/// functions of this trait are recomended to use while writing ink::messages
+pub trait PSP22NativeWrapperInternal {
+    /// Mints `amount` tokens to `account` for `amount` of native currency received by the contract.
+    ///
+    /// On success a `Deposit` event is emitted.
+    fn _deposit(
+        &mut self,
+        account: &AccountId,
+        amount: &Balance,
+    ) -> Result<(), PSP22Error>;
+
+    /// Burns `amount` tokens from `account` and transfers `amount` of native currency to `account`.
+    ///
+    /// On success a `Withdrawal` event is emitted.
+    fn _withdraw(
+        &mut self,
+        account: &AccountId,
+        amount: &Balance,
+    ) -> Result<(), PSP22Error>;
+}
@@ -0,0 +1,127 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use ink::env::hash::Blake2x256;
+use ink::{primitives::AccountId, storage::Mapping};
+use pendzl::math::errors::MathError;
+use pendzl::traits::{Balance, StorageFieldGetter, Timestamp};
+
+use super::{PSP22PermitInternal, PSP22PermitStorage, PermitSignature};
+use crate::token::psp22::{PSP22Error, PSP22Internal};
+
+/// Name used to derive the default domain separator.
+pub const PERMIT_DOMAIN_NAME: &[u8] = b"PSP22Permit";
+
+#[derive(Default, Debug)]
+#[pendzl::storage_item]
+pub struct PSP22PermitData {
+    pub nonces: Mapping<AccountId, u64>,
+}
+
+impl PSP22PermitStorage for PSP22PermitData {
+    fn nonce(&self, owner: &AccountId) -> u64 {
+        self.nonces.get(owner).unwrap_or_default()
+    }
+
+    fn increase_nonce(&mut self, owner: &AccountId) -> Result<u64, PSP22Error> {
+        let nonce = self.nonce(owner);
+        self.nonces
+            .insert(owner, &(nonce.checked_add(1).ok_or(MathError::Overflow)?));
+        Ok(nonce)
+    }
+}
+
+pub trait PSP22PermitDefaultImpl: PSP22PermitInternal {
+    fn permit_default_impl(
+        &mut self,
+        owner: AccountId,
+        spender: AccountId,
+        value: Balance,
+        deadline: Timestamp,
+        signature: PermitSignature,
+    ) -> Result<(), PSP22Error> {
+        self._permit(&owner, &spender, &value, &deadline, &signature)
+    }
+
+    fn nonces_default_impl(&self, owner: AccountId) -> u64 {
+        self._nonces(&owner)
+    }
+
+    fn domain_separator_default_impl(&self) -> [u8; 32] {
+        self._domain_separator()
+    }
+}
+
+pub trait PSP22PermitInternalDefaultImpl:
+    StorageFieldGetter<PSP22PermitData> + PSP22Internal + PSP22PermitInternal
+where
+    PSP22PermitData: PSP22PermitStorage,
+{
+    fn _nonces_default_impl(&self, owner: &AccountId) -> u64 {
+        self.data().nonce(owner)
+    }
+
+    fn _use_nonce_default_impl(
+        &mut self,
+        owner: &AccountId,
+    ) -> Result<u64, PSP22Error> {
+        self.data().increase_nonce(owner)
+    }
+
+    fn _domain_separator_default_impl(&self) -> [u8; 32] {
+        Self::env().hash_encoded::<Blake2x256, _>(&(
+            PERMIT_DOMAIN_NAME,
+            Self::env().account_id(),
+        ))
+    }
+
+    fn _verify_signature_default_impl(
+        &self,
+        signer: &AccountId,
+        message_hash: &[u8; 32],
+        signature: &PermitSignature,
+    ) -> Result<(), PSP22Error> {
+        let is_valid = match signature {
+            PermitSignature::Sr25519(signature) => Self::env()
+                .sr25519_verify(signature, message_hash, signer.as_ref())
+                .is_ok(),
+            PermitSignature::Ecdsa(signature) => {
+                match Self::env().ecdsa_recover(signature, message_hash) {
+                    Ok(public_key) => {
+                        let signer_bytes: &[u8; 32] = signer.as_ref();
+                        Self::env().hash_bytes::<Blake2x256>(&public_key)
+                            == *signer_bytes
+                    }
+                    Err(_) => false,
+                }
+            }
+        };
+        if !is_valid {
+            return Err(PSP22Error::PermitInvalidSignature);
+        }
+        Ok(())
+    }
+
+    fn _permit_default_impl(
+        &mut self,
+        owner: &AccountId,
+        spender: &AccountId,
+        value: &Balance,
+        deadline: &Timestamp,
+        signature: &PermitSignature,
+    ) -> Result<(), PSP22Error> {
+        if Self::env().block_timestamp() > *deadline {
+            return Err(PSP22Error::PermitExpired);
+        }
+        let message_hash = Self::env().hash_encoded::<Blake2x256, _>(&(
+            self._domain_separator(),
+            owner,
+            spender,
+            value,
+            self._nonces(owner),
+            deadline,
+        ));
+        self._verify_signature(owner, &message_hash, signature)?;
+        self._use_nonce(owner)?;
+        self._approve(owner, spender, value)
+    }
+}
@@ -0,0 +1,98 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+
+use ink::{contract_ref, env::DefaultEnvironment};
+pub type PSP22PermitRef = contract_ref!(PSP22Permit, DefaultEnvironment);
+
+/// trait extending PSP22 with signature based approvals (EIP-2612 analogue).
+///
+/// The message that has to be signed by the `owner` is the blake2x256 hash of the
+/// SCALE encoded tuple `(domain_separator, owner, spender, value, nonce, deadline)`,
+/// where `nonce` is the value returned by `nonces(owner)` at the time of the `permit` call.
+///
+/// !!! Note
+/// Signatures are verified with `sr25519_verify` and `ecdsa_recover` host functions.
+/// `sr25519_verify` belongs to the unstable interface of pallet-contracts.
+#[ink::trait_definition]
+pub trait PSP22Permit {
+    /// Sets the allowance of `spender` over `owner`'s tokens to `value`
+    /// given `owner`'s signed approval.
+    ///
+    /// On success an `Approval` event is emitted and the nonce of `owner` is increased.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PermitExpired` if the current block timestamp is greater than `deadline`.
+    /// Returns `PermitInvalidSignature` if `signature` is not a valid signature of the permit made by `owner`.
+    #[ink(message)]
+    fn permit(
+        &mut self,
+        owner: AccountId,
+        spender: AccountId,
+        value: Balance,
+        deadline: Timestamp,
+        signature: PermitSignature,
+    ) -> Result<(), PSP22Error>;
+
+    /// Returns the current nonce of `owner`. It must be included in the next permit signed by `owner`.
+    #[ink(message)]
+    fn nonces(&self, owner: AccountId) -> u64;
+
+    /// Returns the domain separator used in the permit message.
+    #[ink(message)]
+    fn domain_separator(&self) -> [u8; 32];
+}
+
+/// trait that must be implemented by exactly one storage field of a contract storage
+/// so the Pendzl PSP22PermitInternal and PSP22Permit implementation can be derived.
+pub trait PSP22PermitStorage {
+    /// Returns the current nonce of `owner`.
+    fn nonce(&self, owner: &AccountId) -> u64;
+
+    /// Increases the nonce of `owner` by one and returns the consumed nonce.
+    fn increase_nonce(&mut self, owner: &AccountId) -> Result<u64, PSP22Error>;
+}
+
+/// trait that is derived by Pendzl PSP22Permit implementation macro assuming StorageFieldGetter<PSP22PermitStorage> is implemented
+///
+/// functions of this trait are recomended to use while writing ink::messages
+pub trait PSP22PermitInternal {
+    /// Returns the current nonce of `owner`.
+    fn _nonces(&self, owner: &AccountId) -> u64;
+
+    /// Consumes the current nonce of `owner` and returns it.
+    fn _use_nonce(&mut self, owner: &AccountId) -> Result<u64, PSP22Error>;
+
+    /// Returns the domain separator used in the permit message.
+    fn _domain_separator(&self) -> [u8; 32];
+
+    /// Verifies that `signature` is a valid signature of `message_hash` made by `signer`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PermitInvalidSignature` if the signature is not valid.
+    fn _verify_signature(
+        &self,
+        signer: &AccountId,
+        message_hash: &[u8; 32],
+        signature: &PermitSignature,
+    ) -> Result<(), PSP22Error>;
+
+    /// Sets the allowance of `spender` over `owner`'s tokens to `value`
+    /// given `owner`'s signed approval.
+    ///
+    /// On success emits `Approval` event.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PermitExpired` if the current block timestamp is greater than `deadline`.
+    /// Returns `PermitInvalidSignature` if the signature is not valid.
+    fn _permit(
+        &mut self,
+        owner: &AccountId,
+        spender: &AccountId,
+        value: &Balance,
+        deadline: &Timestamp,
+        signature: &PermitSignature,
+    ) -> Result<(), PSP22Error>;
+}
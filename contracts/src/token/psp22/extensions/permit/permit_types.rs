@@ -0,0 +1,13 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+
+/// Signature of a permit created by the `owner` of the tokens.
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum PermitSignature {
+    /// sr25519 signature - `owner` is the public key of the signer.
+    Sr25519([u8; 64]),
+    /// ecdsa (secp256k1) signature - `owner` is the blake2x256 hash of the
+    /// compressed public key of the signer.
+    Ecdsa([u8; 65]),
+}
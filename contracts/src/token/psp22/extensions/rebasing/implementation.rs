@@ -0,0 +1,284 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use ink::{primitives::AccountId, storage::Mapping};
+use pendzl::math::{errors::MathError, operations::*};
+use pendzl::traits::{DefaultEnv, StorageFieldGetter};
+
+use super::{
+    PSP22RebasingInternal, PSP22RebasingStorage, Rebased, TransferShares,
+};
+use crate::token::psp22::{Balance, PSP22Error, PSP22Storage};
+
+/// Index corresponding to one token per share.
+pub const REBASING_INDEX_PRECISION: Balance = 1_000_000_000_000_000_000;
+
+/// `PSP22Storage` backend keeping shares instead of balances.
+///
+/// The total supply is derived from the total shares, so increasing and decreasing it is a no-op
+/// and changes together with balances.
+/// Shares credited to an account are rounded down and shares debited from an account are rounded up.
+#[derive(Default, Debug)]
+#[pendzl::storage_item]
+pub struct PSP22RebasingData {
+    #[lazy]
+    pub total_shares: Balance,
+    #[lazy]
+    pub index: Option<Balance>,
+    pub shares: Mapping<AccountId, Balance>,
+    pub allowances: Mapping<(AccountId, AccountId), Balance>,
+}
+
+impl PSP22RebasingStorage for PSP22RebasingData {
+    fn shares_of(&self, account: &AccountId) -> Balance {
+        self.shares.get(account).unwrap_or_default()
+    }
+
+    fn total_shares(&self) -> Balance {
+        self.total_shares.get_or_default()
+    }
+
+    fn index(&self) -> Balance {
+        self.index
+            .get_or_default()
+            .unwrap_or(REBASING_INDEX_PRECISION)
+    }
+
+    fn set_index(&mut self, index: &Balance) {
+        self.index.set(&Some(*index));
+    }
+
+    fn convert_to_shares(
+        &self,
+        amount: &Balance,
+    ) -> Result<Balance, PSP22Error> {
+        Ok(mul_div(
+            *amount,
+            REBASING_INDEX_PRECISION,
+            self.index(),
+            Rounding::Down,
+        )?)
+    }
+
+    fn convert_to_debited_shares(
+        &self,
+        amount: &Balance,
+    ) -> Result<Balance, PSP22Error> {
+        Ok(mul_div(
+            *amount,
+            REBASING_INDEX_PRECISION,
+            self.index(),
+            Rounding::Up,
+        )?)
+    }
+
+    fn convert_to_balance(
+        &self,
+        shares: &Balance,
+    ) -> Result<Balance, PSP22Error> {
+        Ok(mul_div(
+            *shares,
+            self.index(),
+            REBASING_INDEX_PRECISION,
+            Rounding::Down,
+        )?)
+    }
+}
+
+impl PSP22Storage for PSP22RebasingData {
+    fn total_supply(&self) -> Balance {
+        self.convert_to_balance(&self.total_shares())
+            .unwrap_or(Balance::MAX)
+    }
+    fn increase_total_supply(
+        &mut self,
+        _amount: &Balance,
+    ) -> Result<(), PSP22Error> {
+        Ok(())
+    }
+    fn decrease_total_supply(
+        &mut self,
+        _amount: &Balance,
+    ) -> Result<(), PSP22Error> {
+        Ok(())
+    }
+
+    fn balance_of(&self, account: &AccountId) -> Balance {
+        self.convert_to_balance(&self.shares_of(account))
+            .unwrap_or(Balance::MAX)
+    }
+    fn increase_balance_of(
+        &mut self,
+        account: &AccountId,
+        amount: &Balance,
+    ) -> Result<(), PSP22Error> {
+        let shares = self.convert_to_shares(amount)?;
+        let new_total_shares = self
+            .total_shares()
+            .checked_add(shares)
+            .ok_or(MathError::Overflow)?;
+        let new_shares = self
+            .shares_of(account)
+            .checked_add(shares)
+            .ok_or(MathError::Overflow)?;
+        self.total_shares.set(&new_total_shares);
+        self.shares.insert(account, &new_shares);
+        Ok(())
+    }
+    fn decrease_balance_of(
+        &mut self,
+        account: &AccountId,
+        amount: &Balance,
+    ) -> Result<(), PSP22Error> {
+        if self.balance_of(account) < *amount {
+            return Err(PSP22Error::InsufficientBalance);
+        }
+        // rounded up, so the debited shares are always worth at least `amount`
+        let shares = self.convert_to_debited_shares(amount)?;
+        let new_total_shares = self
+            .total_shares()
+            .checked_sub(shares)
+            .ok_or(MathError::Underflow)?;
+        let new_shares = self
+            .shares_of(account)
+            .checked_sub(shares)
+            .ok_or(PSP22Error::InsufficientBalance)?;
+        self.total_shares.set(&new_total_shares);
+        self.shares.insert(account, &new_shares);
+        Ok(())
+    }
+
+    fn allowance(&self, owner: &AccountId, spender: &AccountId) -> Balance {
+        self.allowances.get((*owner, *spender)).unwrap_or_default()
+    }
+    fn set_allowance(
+        &mut self,
+        owner: &AccountId,
+        spender: &AccountId,
+        value: &Balance,
+    ) {
+        self.allowances.insert((*owner, *spender), value);
+    }
+    fn increase_allowance(
+        &mut self,
+        owner: &AccountId,
+        spender: &AccountId,
+        amount: &Balance,
+    ) -> Result<Balance, PSP22Error> {
+        let new_allowance = self
+            .allowance(owner, spender)
+            .checked_add(*amount)
+            .ok_or(MathError::Overflow)?;
+        self.allowances.insert((*owner, *spender), &new_allowance);
+        Ok(new_allowance)
+    }
+    fn decrease_allowance(
+        &mut self,
+        owner: &AccountId,
+        spender: &AccountId,
+        amount: &Balance,
+    ) -> Result<Balance, PSP22Error> {
+        let new_allowance = self
+            .allowance(owner, spender)
+            .checked_sub(*amount)
+            .ok_or(PSP22Error::InsufficientAllowance)?;
+        self.allowances.insert((*owner, *spender), &new_allowance);
+        Ok(new_allowance)
+    }
+}
+
+pub trait PSP22RebasingDefaultImpl: PSP22RebasingInternal {
+    fn shares_of_default_impl(&self, account: AccountId) -> Balance {
+        self._shares_of(&account)
+    }
+
+    fn total_shares_default_impl(&self) -> Balance {
+        self._total_shares()
+    }
+
+    fn convert_to_shares_default_impl(
+        &self,
+        amount: Balance,
+    ) -> Result<Balance, PSP22Error> {
+        self._convert_to_shares(&amount)
+    }
+
+    fn convert_to_balance_default_impl(
+        &self,
+        shares: Balance,
+    ) -> Result<Balance, PSP22Error> {
+        self._convert_to_balance(&shares)
+    }
+}
+
+pub trait PSP22RebasingInternalDefaultImpl:
+    StorageFieldGetter<PSP22RebasingData> + DefaultEnv
+where
+    PSP22RebasingData: PSP22RebasingStorage + PSP22Storage,
+{
+    fn _shares_of_default_impl(&self, account: &AccountId) -> Balance {
+        self.data().shares_of(account)
+    }
+
+    fn _total_shares_default_impl(&self) -> Balance {
+        self.data().total_shares()
+    }
+
+    fn _convert_to_shares_default_impl(
+        &self,
+        amount: &Balance,
+    ) -> Result<Balance, PSP22Error> {
+        self.data().convert_to_shares(amount)
+    }
+
+    fn _convert_to_balance_default_impl(
+        &self,
+        shares: &Balance,
+    ) -> Result<Balance, PSP22Error> {
+        self.data().convert_to_balance(shares)
+    }
+
+    fn _rebase_default_impl(
+        &mut self,
+        new_total_supply: &Balance,
+    ) -> Result<(), PSP22Error> {
+        let previous_total_supply = self.data().total_supply();
+        let total_shares = self.data().total_shares();
+        let new_index = mul_div(
+            *new_total_supply,
+            REBASING_INDEX_PRECISION,
+            total_shares,
+            Rounding::Down,
+        )?;
+        // a zero index would make every later share conversion divide by zero
+        if new_index == 0 {
+            return Err(PSP22Error::InvalidRebase);
+        }
+        self.data().set_index(&new_index);
+
+        Self::env().emit_event(Rebased {
+            previous_total_supply,
+            total_supply: self.data().total_supply(),
+            total_shares,
+        });
+        Ok(())
+    }
+
+    fn _emit_transfer_shares_default_impl(
+        &self,
+        from: Option<&AccountId>,
+        to: Option<&AccountId>,
+        amount: &Balance,
+    ) -> Result<(), PSP22Error> {
+        // the same rounding as used by `_update`: debits round up, mint credits round down
+        let shares = match from {
+            Some(_) => self.data().convert_to_debited_shares(amount)?,
+            None => self.data().convert_to_shares(amount)?,
+        };
+        Self::env().emit_event(TransferShares {
+            from: from.copied(),
+            to: to.copied(),
+            shares,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,14 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+pub use crate::token::psp22::PSP22Error;
+pub use ink::primitives::AccountId;
+pub use pendzl::traits::Balance;
+
+include!("rebasing_events.rs");
+include!("rebasing_trait.rs");
+
+#[cfg(feature = "psp22_rebasing_impl")]
+mod implementation;
+
+#[cfg(feature = "psp22_rebasing_impl")]
+pub use implementation::*;
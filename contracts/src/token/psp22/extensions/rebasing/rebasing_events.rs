@@ -0,0 +1,23 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+
+/// Emitted together with `Transfer` when `shares` shares are moved `from` `to`.
+/// `from` is None for mints and `to` is None for burns.
+/// `shares` are the shares debited from `from`; `to` can be credited one share less due to rounding.
+/// For mints `shares` are the shares credited to `to`.
+#[ink::event]
+pub struct TransferShares {
+    #[ink(topic)]
+    pub from: Option<AccountId>,
+    #[ink(topic)]
+    pub to: Option<AccountId>,
+    pub shares: Balance,
+}
+
+/// Emitted when the token is rebased so that `total_shares` shares are worth `total_supply` tokens.
+#[ink::event]
+pub struct Rebased {
+    pub previous_total_supply: Balance,
+    pub total_supply: Balance,
+    pub total_shares: Balance,
+}
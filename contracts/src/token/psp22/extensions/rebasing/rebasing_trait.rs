@@ -0,0 +1,96 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use ink::{contract_ref, env::DefaultEnvironment};
+
+pub type PSP22RebasingRef = contract_ref!(PSP22Rebasing, DefaultEnvironment);
+
+/// trait extending PSP22 with shares based balance accounting.
+///
+/// Accounts hold shares and the balance of an account is its shares multiplied by the index,
+/// so changing the index with a rebase changes all balances without transfers.
+#[ink::trait_definition]
+pub trait PSP22Rebasing {
+    /// Returns the shares held by `account`.
+    #[ink(message)]
+    fn shares_of(&self, account: AccountId) -> Balance;
+
+    /// Returns the total amount of shares.
+    #[ink(message)]
+    fn total_shares(&self) -> Balance;
+
+    /// Returns the amount of shares worth `amount` tokens, rounded down.
+    #[ink(message)]
+    fn convert_to_shares(&self, amount: Balance) -> Result<Balance, PSP22Error>;
+
+    /// Returns the amount of tokens `shares` shares are worth, rounded down.
+    #[ink(message)]
+    fn convert_to_balance(&self, shares: Balance) -> Result<Balance, PSP22Error>;
+}
+
+/// trait that must be implemented by exactly one storage field of a contract storage
+/// so the Pendzl PSP22RebasingInternal and PSP22Rebasing implementation can be derived.
+pub trait PSP22RebasingStorage {
+    /// Returns the shares held by `account`.
+    fn shares_of(&self, account: &AccountId) -> Balance;
+
+    /// Returns the total amount of shares.
+    fn total_shares(&self) -> Balance;
+
+    /// Returns the value of one share scaled by `REBASING_INDEX_PRECISION`.
+    fn index(&self) -> Balance;
+
+    /// Sets the value of one share scaled by `REBASING_INDEX_PRECISION`.
+    fn set_index(&mut self, index: &Balance);
+
+    /// Returns the amount of shares worth `amount` tokens, rounded down.
+    fn convert_to_shares(&self, amount: &Balance) -> Result<Balance, PSP22Error>;
+
+    /// Returns the amount of shares debited from an account for `amount` tokens, rounded up.
+    fn convert_to_debited_shares(
+        &self,
+        amount: &Balance,
+    ) -> Result<Balance, PSP22Error>;
+
+    /// Returns the amount of tokens `shares` shares are worth, rounded down.
+    fn convert_to_balance(&self, shares: &Balance) -> Result<Balance, PSP22Error>;
+}
+
+/// trait that is derived by Pendzl PSP22Rebasing implementation macro assuming StorageFieldGetter<PSP22RebasingStorage> is implemented
+///
+/// functions of this trait are recomended to use while writing ink::messages
+pub trait PSP22RebasingInternal {
+    /// Returns the shares held by `account`.
+    fn _shares_of(&self, account: &AccountId) -> Balance;
+
+    /// Returns the total amount of shares.
+    fn _total_shares(&self) -> Balance;
+
+    /// Returns the amount of shares worth `amount` tokens, rounded down.
+    fn _convert_to_shares(&self, amount: &Balance) -> Result<Balance, PSP22Error>;
+
+    /// Returns the amount of tokens `shares` shares are worth, rounded down.
+    fn _convert_to_balance(&self, shares: &Balance)
+        -> Result<Balance, PSP22Error>;
+
+    /// Changes the index so the total shares are worth `new_total_supply` tokens.
+    /// The resulting total supply can be slightly lower than `new_total_supply` due to rounding.
+    ///
+    /// On success a `Rebased` event is emitted.
+    ///
+    /// # Errors
+    /// Returns `Custom("M::DivByZero")` if there are no shares.
+    ///
+    /// Returns `InvalidRebase` if the new index would be 0, e.g. when `new_total_supply` is 0.
+    fn _rebase(&mut self, new_total_supply: &Balance) -> Result<(), PSP22Error>;
+
+    /// Emits a `TransferShares` event for `amount` tokens moved `from` `to`
+    /// with the shares debited from `from`, or credited to `to` for mints.
+    ///
+    /// Called by the generated `PSP22Internal::_update` after the balances are updated.
+    fn _emit_transfer_shares(
+        &self,
+        from: Option<&AccountId>,
+        to: Option<&AccountId>,
+        amount: &Balance,
+    ) -> Result<(), PSP22Error>;
+}
@@ -0,0 +1,177 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use ink::{primitives::AccountId, storage::Mapping};
+use pendzl::math::errors::MathError;
+use pendzl::traits::StorageFieldGetter;
+
+use super::{PSP22SnapshotInternal, PSP22SnapshotStorage, Snapshot};
+use crate::token::psp22::{Balance, PSP22Error, PSP22Internal};
+
+#[derive(Default, Debug)]
+#[pendzl::storage_item]
+pub struct PSP22SnapshotData {
+    #[lazy]
+    pub current_snapshot_id: u32,
+    /// (snapshot id, value) of (account, index); None account holds the total supply snapshots.
+    pub snapshots: Mapping<(Option<AccountId>, u32), (u32, Balance)>,
+    pub snapshots_count: Mapping<Option<AccountId>, u32>,
+}
+
+impl PSP22SnapshotStorage for PSP22SnapshotData {
+    fn current_snapshot_id(&self) -> u32 {
+        self.current_snapshot_id.get_or_default()
+    }
+
+    fn increase_snapshot_id(&mut self) -> Result<u32, PSP22Error> {
+        let id = self
+            .current_snapshot_id()
+            .checked_add(1)
+            .ok_or(MathError::Overflow)?;
+        self.current_snapshot_id.set(&id);
+        Ok(id)
+    }
+
+    fn value_at(
+        &self,
+        account: &Option<&AccountId>,
+        snapshot_id: &u32,
+    ) -> Option<Balance> {
+        let account = account.copied();
+        // binary search for the first snapshot recorded at or after snapshot_id
+        let mut low = 0;
+        let mut high = self.snapshots_count.get(account).unwrap_or_default();
+        let count = high;
+        while low < high {
+            let mid = low + (high - low) / 2;
+            let (id, _) =
+                self.snapshots.get((account, mid)).unwrap_or_default();
+            if id < *snapshot_id {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+        if low == count {
+            return None;
+        }
+        self.snapshots.get((account, low)).map(|(_, value)| value)
+    }
+
+    fn update_snapshot(
+        &mut self,
+        account: &Option<&AccountId>,
+        current_value: &Balance,
+    ) -> Result<(), PSP22Error> {
+        let current_id = self.current_snapshot_id();
+        if current_id == 0 {
+            return Ok(());
+        }
+        let account = account.copied();
+        let count = self.snapshots_count.get(account).unwrap_or_default();
+        if count > 0 {
+            let (last_id, _) =
+                self.snapshots.get((account, count - 1)).unwrap_or_default();
+            if last_id >= current_id {
+                return Ok(());
+            }
+        }
+        self.snapshots
+            .insert((account, count), &(current_id, *current_value));
+        self.snapshots_count.insert(
+            account,
+            &(count.checked_add(1).ok_or(MathError::Overflow)?),
+        );
+        Ok(())
+    }
+}
+
+pub trait PSP22SnapshotDefaultImpl: PSP22SnapshotInternal {
+    fn snapshot_default_impl(&mut self) -> Result<u32, PSP22Error> {
+        self._snapshot()
+    }
+
+    fn current_snapshot_id_default_impl(&self) -> u32 {
+        self._current_snapshot_id()
+    }
+
+    fn balance_of_at_default_impl(
+        &self,
+        account: AccountId,
+        snapshot_id: u32,
+    ) -> Result<Balance, PSP22Error> {
+        self._balance_of_at(&account, &snapshot_id)
+    }
+
+    fn total_supply_at_default_impl(
+        &self,
+        snapshot_id: u32,
+    ) -> Result<Balance, PSP22Error> {
+        self._total_supply_at(&snapshot_id)
+    }
+}
+
+pub trait PSP22SnapshotInternalDefaultImpl:
+    StorageFieldGetter<PSP22SnapshotData> + PSP22Internal
+where
+    PSP22SnapshotData: PSP22SnapshotStorage,
+{
+    fn _snapshot_default_impl(&mut self) -> Result<u32, PSP22Error> {
+        let id = self.data().increase_snapshot_id()?;
+        Self::env().emit_event(Snapshot { id });
+        Ok(id)
+    }
+
+    fn _current_snapshot_id_default_impl(&self) -> u32 {
+        self.data().current_snapshot_id()
+    }
+
+    fn _balance_of_at_default_impl(
+        &self,
+        account: &AccountId,
+        snapshot_id: &u32,
+    ) -> Result<Balance, PSP22Error> {
+        if *snapshot_id == 0 || *snapshot_id > self.data().current_snapshot_id()
+        {
+            return Err(PSP22Error::NonexistentSnapshot);
+        }
+        Ok(self
+            .data()
+            .value_at(&Some(account), snapshot_id)
+            .unwrap_or_else(|| self._balance_of(account)))
+    }
+
+    fn _total_supply_at_default_impl(
+        &self,
+        snapshot_id: &u32,
+    ) -> Result<Balance, PSP22Error> {
+        if *snapshot_id == 0 || *snapshot_id > self.data().current_snapshot_id()
+        {
+            return Err(PSP22Error::NonexistentSnapshot);
+        }
+        Ok(self
+            .data()
+            .value_at(&None, snapshot_id)
+            .unwrap_or_else(|| self._total_supply()))
+    }
+
+    fn _update_snapshots_default_impl(
+        &mut self,
+        from: Option<&AccountId>,
+        to: Option<&AccountId>,
+        _amount: &Balance,
+    ) -> Result<(), PSP22Error> {
+        if let Some(from) = from {
+            let balance = self._balance_of(from);
+            self.data().update_snapshot(&Some(from), &balance)?;
+        }
+        if let Some(to) = to {
+            let balance = self._balance_of(to);
+            self.data().update_snapshot(&Some(to), &balance)?;
+        }
+        if from.is_none() || to.is_none() {
+            let total_supply = self._total_supply();
+            self.data().update_snapshot(&None, &total_supply)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,14 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+pub use crate::token::psp22::PSP22Error;
+pub use ink::primitives::AccountId;
+pub use pendzl::traits::Balance;
+
+include!("snapshot_events.rs");
+include!("snapshot_trait.rs");
+
+#[cfg(feature = "psp22_snapshot_impl")]
+mod implementation;
+
+#[cfg(feature = "psp22_snapshot_impl")]
+pub use implementation::*;
@@ -0,0 +1,8 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+/// Emitted when a snapshot identified by `id` is created.
+#[ink::event]
+pub struct Snapshot {
+    #[ink(topic)]
+    pub id: u32,
+}
@@ -0,0 +1,113 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use ink::{contract_ref, env::DefaultEnvironment};
+
+pub type PSP22SnapshotRef = contract_ref!(PSP22Snapshot, DefaultEnvironment);
+
+/// trait extending PSP22 with snapshots of balances and total supply
+///
+/// Snapshots are identified by increasing ids starting from 1.
+/// Balances are recorded lazily - only when they change after a snapshot is created.
+#[ink::trait_definition]
+pub trait PSP22Snapshot {
+    /// Creates a new snapshot and returns its id.
+    ///
+    /// On success a `Snapshot` event is emitted.
+    ///
+    /// See [`PSP22SnapshotInternal::_snapshot`].
+    #[ink(message)]
+    fn snapshot(&mut self) -> Result<u32, PSP22Error>;
+
+    /// Returns the id of the latest snapshot. 0 if no snapshot was created.
+    #[ink(message)]
+    fn current_snapshot_id(&self) -> u32;
+
+    /// Returns the balance of `account` at the time `snapshot_id` was created.
+    ///
+    /// # Errors
+    ///
+    /// Returns `NonexistentSnapshot` if `snapshot_id` is 0 or greater than the current snapshot id.
+    #[ink(message)]
+    fn balance_of_at(
+        &self,
+        account: AccountId,
+        snapshot_id: u32,
+    ) -> Result<Balance, PSP22Error>;
+
+    /// Returns the total supply at the time `snapshot_id` was created.
+    ///
+    /// # Errors
+    ///
+    /// Returns `NonexistentSnapshot` if `snapshot_id` is 0 or greater than the current snapshot id.
+    #[ink(message)]
+    fn total_supply_at(&self, snapshot_id: u32) -> Result<Balance, PSP22Error>;
+}
+
+/// trait that must be implemented by exactly one storage field of a contract storage
+/// so the Pendzl PSP22SnapshotInternal and PSP22Snapshot implementation can be derived.
+///
+/// `account` equal to None refers to the snapshots of the total supply.
+pub trait PSP22SnapshotStorage {
+    /// Retrieves the id of the latest snapshot.
+    fn current_snapshot_id(&self) -> u32;
+
+    /// Increases the current snapshot id and returns the new one.
+    fn increase_snapshot_id(&mut self) -> Result<u32, PSP22Error>;
+
+    /// Retrieves the value of `account` recorded for `snapshot_id`.
+    /// None if the value didn't change since `snapshot_id` was created.
+    fn value_at(
+        &self,
+        account: &Option<&AccountId>,
+        snapshot_id: &u32,
+    ) -> Option<Balance>;
+
+    /// Records `current_value` of `account` for the current snapshot
+    /// if it wasn't recorded yet.
+    fn update_snapshot(
+        &mut self,
+        account: &Option<&AccountId>,
+        current_value: &Balance,
+    ) -> Result<(), PSP22Error>;
+}
+
+/// trait that is derived by Pendzl PSP22Snapshot implementation macro assuming StorageFieldGetter<PSP22SnapshotStorage> is implemented
+///
+/// functions of this trait are recomended to use while writing ink::messages
+pub trait PSP22SnapshotInternal {
+    /// Creates a new snapshot and returns its id.
+    ///
+    /// On success emits `Snapshot` event.
+    fn _snapshot(&mut self) -> Result<u32, PSP22Error>;
+
+    /// Retrieves the id of the latest snapshot.
+    fn _current_snapshot_id(&self) -> u32;
+
+    /// Retrieves the balance of `account` at the time `snapshot_id` was created.
+    ///
+    /// # Errors
+    /// Returns `NonexistentSnapshot` if `snapshot_id` is 0 or greater than the current snapshot id.
+    fn _balance_of_at(
+        &self,
+        account: &AccountId,
+        snapshot_id: &u32,
+    ) -> Result<Balance, PSP22Error>;
+
+    /// Retrieves the total supply at the time `snapshot_id` was created.
+    ///
+    /// # Errors
+    /// Returns `NonexistentSnapshot` if `snapshot_id` is 0 or greater than the current snapshot id.
+    fn _total_supply_at(&self, snapshot_id: &u32)
+        -> Result<Balance, PSP22Error>;
+
+    /// Records balances of `from` and `to` and the total supply before `amount` tokens are moved `from` `to`.
+    /// Depending if `from` is None and `to` is none operation corresponds to transfer, mint, burn.
+    ///
+    /// Called by the generated `PSP22Internal::_update` before the balances are updated.
+    fn _update_snapshots(
+        &mut self,
+        from: Option<&AccountId>,
+        to: Option<&AccountId>,
+        amount: &Balance,
+    ) -> Result<(), PSP22Error>;
+}
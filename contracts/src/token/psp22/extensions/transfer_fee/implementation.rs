@@ -0,0 +1,148 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use ink::{primitives::AccountId, storage::Mapping};
+use pendzl::math::operations::{mul_div, Rounding};
+use pendzl::traits::StorageFieldGetter;
+
+use super::{
+    PSP22TransferFeeInternal, PSP22TransferFeeStorage, MAX_TRANSFER_FEE_BPS,
+};
+use crate::token::psp22::{Balance, PSP22Error};
+
+#[derive(Default, Debug)]
+#[pendzl::storage_item]
+pub struct PSP22TransferFeeData {
+    #[lazy]
+    pub transfer_fee_bps: u16,
+    #[lazy]
+    pub fee_recipient: AccountId,
+    pub fee_exempt: Mapping<AccountId, ()>,
+}
+
+impl PSP22TransferFeeData {
+    pub fn new(
+        transfer_fee_bps: u16,
+        fee_recipient: AccountId,
+    ) -> Result<Self, PSP22Error> {
+        if transfer_fee_bps > MAX_TRANSFER_FEE_BPS {
+            return Err(PSP22Error::InvalidTransferFee);
+        }
+        let mut instance: PSP22TransferFeeData = Default::default();
+        instance.transfer_fee_bps.set(&transfer_fee_bps);
+        instance.fee_recipient.set(&fee_recipient);
+        Ok(instance)
+    }
+}
+
+impl PSP22TransferFeeStorage for PSP22TransferFeeData {
+    fn transfer_fee_bps(&self) -> u16 {
+        self.transfer_fee_bps.get_or_default()
+    }
+
+    fn set_transfer_fee_bps(&mut self, fee_bps: &u16) {
+        self.transfer_fee_bps.set(fee_bps);
+    }
+
+    fn fee_recipient(&self) -> Option<AccountId> {
+        self.fee_recipient.get()
+    }
+
+    fn set_fee_recipient(&mut self, fee_recipient: &AccountId) {
+        self.fee_recipient.set(fee_recipient);
+    }
+
+    fn is_fee_exempt(&self, account: &AccountId) -> bool {
+        self.fee_exempt.contains(account)
+    }
+
+    fn set_fee_exempt(&mut self, account: &AccountId, exempt: bool) {
+        if exempt {
+            self.fee_exempt.insert(account, &());
+        } else {
+            self.fee_exempt.remove(account);
+        }
+    }
+}
+
+pub trait PSP22TransferFeeDefaultImpl: PSP22TransferFeeInternal {
+    fn transfer_fee_bps_default_impl(&self) -> u16 {
+        self._transfer_fee_bps()
+    }
+
+    fn fee_recipient_default_impl(&self) -> Option<AccountId> {
+        self._fee_recipient()
+    }
+
+    fn is_fee_exempt_default_impl(&self, account: AccountId) -> bool {
+        self._is_fee_exempt(&account)
+    }
+}
+
+pub trait PSP22TransferFeeInternalDefaultImpl:
+    StorageFieldGetter<PSP22TransferFeeData> + PSP22TransferFeeInternal
+where
+    PSP22TransferFeeData: PSP22TransferFeeStorage,
+{
+    fn _transfer_fee_bps_default_impl(&self) -> u16 {
+        self.data().transfer_fee_bps()
+    }
+
+    fn _set_transfer_fee_bps_default_impl(
+        &mut self,
+        fee_bps: &u16,
+    ) -> Result<(), PSP22Error> {
+        if *fee_bps > MAX_TRANSFER_FEE_BPS {
+            return Err(PSP22Error::InvalidTransferFee);
+        }
+        self.data().set_transfer_fee_bps(fee_bps);
+        Ok(())
+    }
+
+    fn _fee_recipient_default_impl(&self) -> Option<AccountId> {
+        self.data().fee_recipient()
+    }
+
+    fn _set_fee_recipient_default_impl(&mut self, fee_recipient: &AccountId) {
+        self.data().set_fee_recipient(fee_recipient);
+    }
+
+    fn _is_fee_exempt_default_impl(&self, account: &AccountId) -> bool {
+        self.data().is_fee_exempt(account)
+    }
+
+    fn _set_fee_exempt_default_impl(
+        &mut self,
+        account: &AccountId,
+        exempt: bool,
+    ) {
+        self.data().set_fee_exempt(account, exempt);
+    }
+
+    fn _transfer_fee_default_impl(
+        &self,
+        from: Option<&AccountId>,
+        to: Option<&AccountId>,
+        amount: &Balance,
+    ) -> Result<Balance, PSP22Error> {
+        let (from, to) = match (from, to) {
+            (Some(from), Some(to)) => (from, to),
+            _ => return Ok(0),
+        };
+        let fee_recipient = match self._fee_recipient() {
+            Some(fee_recipient) => fee_recipient,
+            None => return Ok(0),
+        };
+        if *to == fee_recipient
+            || self._is_fee_exempt(from)
+            || self._is_fee_exempt(to)
+        {
+            return Ok(0);
+        }
+        Ok(mul_div(
+            *amount,
+            self._transfer_fee_bps() as u128,
+            MAX_TRANSFER_FEE_BPS as u128,
+            Rounding::Down,
+        )?)
+    }
+}
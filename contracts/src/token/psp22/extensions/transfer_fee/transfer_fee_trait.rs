@@ -0,0 +1,91 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use ink::{contract_ref, env::DefaultEnvironment};
+
+pub type PSP22TransferFeeRef =
+    contract_ref!(PSP22TransferFee, DefaultEnvironment);
+
+/// Denominator of the transfer fee expressed in basis points.
+pub const MAX_TRANSFER_FEE_BPS: u16 = 10_000;
+
+/// trait extending PSP22 with a fee charged on transfers and sent to a fee recipient.
+///
+/// The fee is charged on top of the tokens leaving the sender: the receiver gets
+/// `amount - fee` and the fee recipient gets `fee`, each with its own `Transfer` event.
+/// Mints and burns are not charged.
+#[ink::trait_definition]
+pub trait PSP22TransferFee {
+    /// Returns the transfer fee in basis points.
+    #[ink(message)]
+    fn transfer_fee_bps(&self) -> u16;
+
+    /// Returns the account receiving the transfer fees or None if it was never set.
+    #[ink(message)]
+    fn fee_recipient(&self) -> Option<AccountId>;
+
+    /// Returns true if transfers from or to `account` are not charged.
+    #[ink(message)]
+    fn is_fee_exempt(&self, account: AccountId) -> bool;
+}
+
+/// trait that must be implemented by exactly one storage field of a contract storage
+/// so the Pendzl PSP22TransferFeeInternal and PSP22TransferFee implementation can be derived.
+pub trait PSP22TransferFeeStorage {
+    /// Retrieves the transfer fee in basis points.
+    fn transfer_fee_bps(&self) -> u16;
+
+    /// Sets the transfer fee in basis points.
+    fn set_transfer_fee_bps(&mut self, fee_bps: &u16);
+
+    /// Retrieves the fee recipient or None if it was never set.
+    fn fee_recipient(&self) -> Option<AccountId>;
+
+    /// Sets the fee recipient.
+    fn set_fee_recipient(&mut self, fee_recipient: &AccountId);
+
+    /// Returns true if `account` is fee exempt.
+    fn is_fee_exempt(&self, account: &AccountId) -> bool;
+
+    /// Sets whether `account` is fee exempt.
+    fn set_fee_exempt(&mut self, account: &AccountId, exempt: bool);
+}
+
+/// trait that is derived by Pendzl PSP22TransferFee implementation macro assuming StorageFieldGetter<PSP22TransferFeeStorage> is implemented
+///
+/// functions of this trait are recomended to use while writing ink::messages
+pub trait PSP22TransferFeeInternal {
+    /// Retrieves the transfer fee in basis points.
+    fn _transfer_fee_bps(&self) -> u16;
+
+    /// Sets the transfer fee in basis points.
+    ///
+    /// # Errors
+    /// Returns `InvalidTransferFee` if `fee_bps` is greater than `MAX_TRANSFER_FEE_BPS`.
+    fn _set_transfer_fee_bps(&mut self, fee_bps: &u16) -> Result<(), PSP22Error>;
+
+    /// Retrieves the fee recipient or None if it was never set.
+    fn _fee_recipient(&self) -> Option<AccountId>;
+
+    /// Sets the fee recipient.
+    fn _set_fee_recipient(&mut self, fee_recipient: &AccountId);
+
+    /// Returns true if `account` is fee exempt.
+    fn _is_fee_exempt(&self, account: &AccountId) -> bool;
+
+    /// Sets whether `account` is fee exempt.
+    fn _set_fee_exempt(&mut self, account: &AccountId, exempt: bool);
+
+    /// Returns the fee charged for moving `amount` tokens `from` `to`.
+    ///
+    /// The fee is 0 for mints and burns, for transfers from or to a fee exempt account,
+    /// for transfers to the fee recipient and when no fee recipient is set.
+    /// Otherwise it is `amount * transfer_fee_bps / MAX_TRANSFER_FEE_BPS` rounded down.
+    ///
+    /// Called by the generated `PSP22Internal::_update` before the balances are updated.
+    fn _transfer_fee(
+        &self,
+        from: Option<&AccountId>,
+        to: Option<&AccountId>,
+        amount: &Balance,
+    ) -> Result<Balance, PSP22Error>;
+}
@@ -0,0 +1,40 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+/// Emitted when `owner` requests a deposit of `assets` to be claimed by `controller`.
+#[ink::event]
+pub struct DepositRequest {
+    #[ink(topic)]
+    pub controller: AccountId,
+    #[ink(topic)]
+    pub owner: AccountId,
+    pub assets: Balance,
+}
+
+/// Emitted when `sender` requests a redeem of `shares` of `owner` to be claimed by `controller`.
+#[ink::event]
+pub struct RedeemRequest {
+    #[ink(topic)]
+    pub controller: AccountId,
+    #[ink(topic)]
+    pub owner: AccountId,
+    pub sender: AccountId,
+    pub shares: Balance,
+}
+
+/// Emitted when pending deposit `assets` of `controller` are converted to claimable `shares`.
+#[ink::event]
+pub struct DepositFulfilled {
+    #[ink(topic)]
+    pub controller: AccountId,
+    pub assets: Balance,
+    pub shares: Balance,
+}
+
+/// Emitted when pending redeem `shares` of `controller` are converted to claimable `assets`.
+#[ink::event]
+pub struct RedeemFulfilled {
+    #[ink(topic)]
+    pub controller: AccountId,
+    pub assets: Balance,
+    pub shares: Balance,
+}
@@ -0,0 +1,202 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use ink::{contract_ref, env::DefaultEnvironment};
+
+pub type PSP22VaultAsyncRef = contract_ref!(PSP22VaultAsync, DefaultEnvironment);
+
+/// trait extending PSP22Vault with asynchronous deposit and redeem requests, based on the ERC-7540
+/// "Asynchronous ERC-4626 Tokenized Vaults", as defined in https://eips.ethereum.org/EIPS/eip-7540.
+///
+/// - A request is pending until the vault operator fulfills it with `_fulfill_deposit` or `_fulfill_redeem`
+///   at the share price of the moment of fulfillment. Fulfilled requests are claimable with `claim`.
+/// - Requested shares are escrowed in the vault until fulfilled and burned on fulfillment.
+///   Shares of fulfilled deposits are minted to the vault and escrowed until claimed.
+/// - Pending deposit assets and claimable redeem assets are excluded from total assets.
+/// - Synchronous `PSP22Vault::withdraw` and `PSP22Vault::redeem` are disabled (`max_withdraw` and `max_redeem` are 0).
+///   Synchronous `PSP22Vault::deposit` and `PSP22Vault::mint` stay available.
+///
+/// Requests of a controller are aggregated, there are no request ids.
+///
+/// `#[pendzl::implementation]` fails to compile if PSP22VaultAsync is used together with
+/// PSP22VaultFees (fulfillment doesn't charge fees) or PSP22VaultNative (`request_deposit` is not payable).
+#[ink::trait_definition]
+pub trait PSP22VaultAsync {
+    /// Transfers `assets` of the underlying asset from the caller to the vault and
+    /// adds them to the pending deposit request of `controller`.
+    ///
+    /// On success a `DepositRequest` event is emitted.
+    #[ink(message)]
+    fn request_deposit(
+        &mut self,
+        assets: Balance,
+        controller: AccountId,
+    ) -> Result<(), PSP22Error>;
+
+    /// Returns the amount of assets of the pending deposit request of `controller`.
+    #[ink(message)]
+    fn pending_deposit_request(&self, controller: AccountId) -> Balance;
+
+    /// Returns the amount of assets of the claimable deposit request of `controller`.
+    #[ink(message)]
+    fn claimable_deposit_request(&self, controller: AccountId) -> Balance;
+
+    /// Escrows `shares` of `owner` in the vault and adds them to the pending redeem request of `controller`.
+    ///
+    /// If caller is not `owner`, `shares` are spent from the allowance of `owner` to the caller.
+    /// On success a `RedeemRequest` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InsufficientAllowance` if caller is not `owner` and the allowance is too low.
+    /// Returns `InsufficientBalance` if `owner` doesn't have enough shares.
+    #[ink(message)]
+    fn request_redeem(
+        &mut self,
+        shares: Balance,
+        controller: AccountId,
+        owner: AccountId,
+    ) -> Result<(), PSP22Error>;
+
+    /// Returns the amount of shares of the pending redeem request of `controller`.
+    #[ink(message)]
+    fn pending_redeem_request(&self, controller: AccountId) -> Balance;
+
+    /// Returns the amount of shares of the claimable redeem request of `controller`.
+    #[ink(message)]
+    fn claimable_redeem_request(&self, controller: AccountId) -> Balance;
+
+    /// Claims the claimable deposit and redeem requests of the caller as controller,
+    /// transferring the shares and sending the assets to `receiver`.
+    ///
+    /// Returns the amount of claimed shares and the amount of claimed assets.
+    /// On success a `Deposit` event is emitted if there were shares to claim
+    /// and a `Withdraw` event is emitted if there were assets to claim.
+    #[ink(message)]
+    fn claim(
+        &mut self,
+        receiver: AccountId,
+    ) -> Result<(Balance, Balance), PSP22Error>;
+}
+
+/// trait that must be implemented by exactly one storage field of a contract storage
+/// so the Pendzl PSP22VaultAsyncInternal and PSP22VaultAsync implementation can be derived.
+pub trait PSP22VaultAsyncStorage {
+    /// Retrieves the assets of the pending deposit request of `controller`.
+    fn pending_deposit(&self, controller: &AccountId) -> Balance;
+
+    /// Sets the assets of the pending deposit request of `controller`.
+    fn set_pending_deposit(&mut self, controller: &AccountId, assets: &Balance);
+
+    /// Retrieves the assets and shares of the claimable deposit request of `controller`.
+    fn claimable_deposit(&self, controller: &AccountId) -> (Balance, Balance);
+
+    /// Sets the assets and shares of the claimable deposit request of `controller`.
+    fn set_claimable_deposit(
+        &mut self,
+        controller: &AccountId,
+        claimable: &(Balance, Balance),
+    );
+
+    /// Retrieves the shares of the pending redeem request of `controller`.
+    fn pending_redeem(&self, controller: &AccountId) -> Balance;
+
+    /// Sets the shares of the pending redeem request of `controller`.
+    fn set_pending_redeem(&mut self, controller: &AccountId, shares: &Balance);
+
+    /// Retrieves the assets and shares of the claimable redeem request of `controller`.
+    fn claimable_redeem(&self, controller: &AccountId) -> (Balance, Balance);
+
+    /// Sets the assets and shares of the claimable redeem request of `controller`.
+    fn set_claimable_redeem(
+        &mut self,
+        controller: &AccountId,
+        claimable: &(Balance, Balance),
+    );
+
+    /// Retrieves the assets held by the vault for pending deposit and claimable redeem requests.
+    fn reserved_assets(&self) -> Balance;
+
+    /// Sets the assets held by the vault for pending deposit and claimable redeem requests.
+    fn set_reserved_assets(&mut self, assets: &Balance);
+}
+
+/// trait that is derived by Pendzl PSP22VaultAsync implementation macro assuming StorageFieldGetter<PSP22VaultAsyncStorage> is implemented
+///
+/// functions of this trait are recomended to use while writing ink::messages
+pub trait PSP22VaultAsyncInternal {
+    /// Retrieves the assets of the pending deposit request of `controller`.
+    fn _pending_deposit_request(&self, controller: &AccountId) -> Balance;
+
+    /// Retrieves the assets of the claimable deposit request of `controller`.
+    fn _claimable_deposit_request(&self, controller: &AccountId) -> Balance;
+
+    /// Retrieves the shares of the pending redeem request of `controller`.
+    fn _pending_redeem_request(&self, controller: &AccountId) -> Balance;
+
+    /// Retrieves the shares of the claimable redeem request of `controller`.
+    fn _claimable_redeem_request(&self, controller: &AccountId) -> Balance;
+
+    /// Retrieves the assets held by the vault for pending deposit and claimable redeem requests.
+    ///
+    /// Subtracted from the generated `PSP22VaultInternal::_total_assets`.
+    fn _reserved_assets(&self) -> Balance;
+
+    /// Receives `assets` from `owner` and adds them to the pending deposit request of `controller`.
+    ///
+    /// On success a `DepositRequest` event is emitted.
+    fn _request_deposit(
+        &mut self,
+        owner: &AccountId,
+        controller: &AccountId,
+        assets: &Balance,
+    ) -> Result<(), PSP22Error>;
+
+    /// Escrows `shares` of `owner` in the vault and adds them to the pending redeem request of `controller`.
+    ///
+    /// If `caller` is not `owner`, `shares` are spent from the allowance of `owner` to `caller`.
+    /// On success a `RedeemRequest` event is emitted.
+    fn _request_redeem(
+        &mut self,
+        caller: &AccountId,
+        owner: &AccountId,
+        controller: &AccountId,
+        shares: &Balance,
+    ) -> Result<(), PSP22Error>;
+
+    /// Converts `assets` of the pending deposit request of `controller` to claimable shares
+    /// at the current `_preview_deposit` and mints them to the vault.
+    ///
+    /// Returns the amount of claimable shares.
+    /// On success a `DepositFulfilled` event is emitted.
+    ///
+    /// # Errors
+    /// Returns `InsufficientPendingRequest` if `assets` are greater than the pending deposit request.
+    fn _fulfill_deposit(
+        &mut self,
+        controller: &AccountId,
+        assets: &Balance,
+    ) -> Result<Balance, PSP22Error>;
+
+    /// Converts `shares` of the pending redeem request of `controller` to claimable assets
+    /// at the current `_preview_redeem` and burns the escrowed shares.
+    ///
+    /// Returns the amount of claimable assets.
+    /// On success a `RedeemFulfilled` event is emitted.
+    ///
+    /// # Errors
+    /// Returns `InsufficientPendingRequest` if `shares` are greater than the pending redeem request.
+    fn _fulfill_redeem(
+        &mut self,
+        controller: &AccountId,
+        shares: &Balance,
+    ) -> Result<Balance, PSP22Error>;
+
+    /// Transfers the claimable deposit shares and sends the claimable redeem assets of `controller` to `receiver`.
+    ///
+    /// Returns the amount of claimed shares and the amount of claimed assets.
+    fn _claim(
+        &mut self,
+        controller: &AccountId,
+        receiver: &AccountId,
+    ) -> Result<(Balance, Balance), PSP22Error>;
+}
@@ -0,0 +1,350 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use ink::{primitives::AccountId, storage::Mapping};
+use pendzl::traits::{Balance, DefaultEnv, StorageFieldGetter};
+
+use super::{
+    DepositFulfilled, DepositRequest, PSP22VaultAsyncInternal,
+    PSP22VaultAsyncStorage, RedeemFulfilled, RedeemRequest,
+};
+use crate::token::psp22::vault::{Deposit, PSP22VaultInternal, Withdraw};
+use crate::token::psp22::{PSP22Error, PSP22Internal};
+
+#[derive(Default, Debug)]
+#[pendzl::storage_item]
+pub struct PSP22VaultAsyncData {
+    pub pending_deposits: Mapping<AccountId, Balance>,
+    pub claimable_deposits: Mapping<AccountId, (Balance, Balance)>,
+    pub pending_redeems: Mapping<AccountId, Balance>,
+    pub claimable_redeems: Mapping<AccountId, (Balance, Balance)>,
+    #[lazy]
+    pub reserved_assets: Balance,
+}
+
+impl PSP22VaultAsyncStorage for PSP22VaultAsyncData {
+    fn pending_deposit(&self, controller: &AccountId) -> Balance {
+        self.pending_deposits.get(controller).unwrap_or_default()
+    }
+
+    fn set_pending_deposit(
+        &mut self,
+        controller: &AccountId,
+        assets: &Balance,
+    ) {
+        if *assets == 0 {
+            self.pending_deposits.remove(controller);
+        } else {
+            self.pending_deposits.insert(controller, assets);
+        }
+    }
+
+    fn claimable_deposit(&self, controller: &AccountId) -> (Balance, Balance) {
+        self.claimable_deposits.get(controller).unwrap_or_default()
+    }
+
+    fn set_claimable_deposit(
+        &mut self,
+        controller: &AccountId,
+        claimable: &(Balance, Balance),
+    ) {
+        if *claimable == (0, 0) {
+            self.claimable_deposits.remove(controller);
+        } else {
+            self.claimable_deposits.insert(controller, claimable);
+        }
+    }
+
+    fn pending_redeem(&self, controller: &AccountId) -> Balance {
+        self.pending_redeems.get(controller).unwrap_or_default()
+    }
+
+    fn set_pending_redeem(&mut self, controller: &AccountId, shares: &Balance) {
+        if *shares == 0 {
+            self.pending_redeems.remove(controller);
+        } else {
+            self.pending_redeems.insert(controller, shares);
+        }
+    }
+
+    fn claimable_redeem(&self, controller: &AccountId) -> (Balance, Balance) {
+        self.claimable_redeems.get(controller).unwrap_or_default()
+    }
+
+    fn set_claimable_redeem(
+        &mut self,
+        controller: &AccountId,
+        claimable: &(Balance, Balance),
+    ) {
+        if *claimable == (0, 0) {
+            self.claimable_redeems.remove(controller);
+        } else {
+            self.claimable_redeems.insert(controller, claimable);
+        }
+    }
+
+    fn reserved_assets(&self) -> Balance {
+        self.reserved_assets.get_or_default()
+    }
+
+    fn set_reserved_assets(&mut self, assets: &Balance) {
+        self.reserved_assets.set(assets);
+    }
+}
+
+pub trait PSP22VaultAsyncDefaultImpl:
+    PSP22VaultAsyncInternal + DefaultEnv
+{
+    fn request_deposit_default_impl(
+        &mut self,
+        assets: Balance,
+        controller: AccountId,
+    ) -> Result<(), PSP22Error> {
+        self._request_deposit(&Self::env().caller(), &controller, &assets)
+    }
+
+    fn pending_deposit_request_default_impl(
+        &self,
+        controller: AccountId,
+    ) -> Balance {
+        self._pending_deposit_request(&controller)
+    }
+
+    fn claimable_deposit_request_default_impl(
+        &self,
+        controller: AccountId,
+    ) -> Balance {
+        self._claimable_deposit_request(&controller)
+    }
+
+    fn request_redeem_default_impl(
+        &mut self,
+        shares: Balance,
+        controller: AccountId,
+        owner: AccountId,
+    ) -> Result<(), PSP22Error> {
+        self._request_redeem(
+            &Self::env().caller(),
+            &owner,
+            &controller,
+            &shares,
+        )
+    }
+
+    fn pending_redeem_request_default_impl(
+        &self,
+        controller: AccountId,
+    ) -> Balance {
+        self._pending_redeem_request(&controller)
+    }
+
+    fn claimable_redeem_request_default_impl(
+        &self,
+        controller: AccountId,
+    ) -> Balance {
+        self._claimable_redeem_request(&controller)
+    }
+
+    fn claim_default_impl(
+        &mut self,
+        receiver: AccountId,
+    ) -> Result<(Balance, Balance), PSP22Error> {
+        self._claim(&Self::env().caller(), &receiver)
+    }
+}
+
+pub trait PSP22VaultAsyncInternalDefaultImpl:
+    StorageFieldGetter<PSP22VaultAsyncData>
+    + PSP22VaultAsyncInternal
+    + PSP22VaultInternal
+    + PSP22Internal
+    + DefaultEnv
+where
+    PSP22VaultAsyncData: PSP22VaultAsyncStorage,
+{
+    fn _pending_deposit_request_default_impl(
+        &self,
+        controller: &AccountId,
+    ) -> Balance {
+        self.data().pending_deposit(controller)
+    }
+
+    fn _claimable_deposit_request_default_impl(
+        &self,
+        controller: &AccountId,
+    ) -> Balance {
+        self.data().claimable_deposit(controller).0
+    }
+
+    fn _pending_redeem_request_default_impl(
+        &self,
+        controller: &AccountId,
+    ) -> Balance {
+        self.data().pending_redeem(controller)
+    }
+
+    fn _claimable_redeem_request_default_impl(
+        &self,
+        controller: &AccountId,
+    ) -> Balance {
+        self.data().claimable_redeem(controller).1
+    }
+
+    fn _reserved_assets_default_impl(&self) -> Balance {
+        self.data().reserved_assets()
+    }
+
+    fn _request_deposit_default_impl(
+        &mut self,
+        owner: &AccountId,
+        controller: &AccountId,
+        assets: &Balance,
+    ) -> Result<(), PSP22Error> {
+        self._receive_assets(owner, assets)?;
+
+        // assets are bounded by the supply of the underlying asset
+        let pending = self.data().pending_deposit(controller) + *assets;
+        self.data().set_pending_deposit(controller, &pending);
+        let reserved = self.data().reserved_assets() + *assets;
+        self.data().set_reserved_assets(&reserved);
+
+        Self::env().emit_event(DepositRequest {
+            controller: *controller,
+            owner: *owner,
+            assets: *assets,
+        });
+        Ok(())
+    }
+
+    fn _request_redeem_default_impl(
+        &mut self,
+        caller: &AccountId,
+        owner: &AccountId,
+        controller: &AccountId,
+        shares: &Balance,
+    ) -> Result<(), PSP22Error> {
+        if caller != owner {
+            self._decrease_allowance_from_to(owner, caller, shares)?;
+        }
+        self._update(Some(owner), Some(&Self::env().account_id()), shares)?;
+
+        // shares are bounded by the total supply
+        let pending = self.data().pending_redeem(controller) + *shares;
+        self.data().set_pending_redeem(controller, &pending);
+
+        Self::env().emit_event(RedeemRequest {
+            controller: *controller,
+            owner: *owner,
+            sender: *caller,
+            shares: *shares,
+        });
+        Ok(())
+    }
+
+    fn _fulfill_deposit_default_impl(
+        &mut self,
+        controller: &AccountId,
+        assets: &Balance,
+    ) -> Result<Balance, PSP22Error> {
+        let pending = self.data().pending_deposit(controller);
+        if *assets > pending {
+            return Err(PSP22Error::InsufficientPendingRequest);
+        }
+        // priced while the assets are still reserved
+        let shares = self._preview_deposit(assets)?;
+
+        self.data()
+            .set_pending_deposit(controller, &(pending - *assets));
+        let reserved = self.data().reserved_assets() - *assets;
+        self.data().set_reserved_assets(&reserved);
+        self._mint_to(&Self::env().account_id(), &shares)?;
+
+        let (claimable_assets, claimable_shares) =
+            self.data().claimable_deposit(controller);
+        self.data().set_claimable_deposit(
+            controller,
+            &(claimable_assets + *assets, claimable_shares + shares),
+        );
+
+        Self::env().emit_event(DepositFulfilled {
+            controller: *controller,
+            assets: *assets,
+            shares,
+        });
+        Ok(shares)
+    }
+
+    fn _fulfill_redeem_default_impl(
+        &mut self,
+        controller: &AccountId,
+        shares: &Balance,
+    ) -> Result<Balance, PSP22Error> {
+        let pending = self.data().pending_redeem(controller);
+        if *shares > pending {
+            return Err(PSP22Error::InsufficientPendingRequest);
+        }
+        // priced while the escrowed shares are still in the supply
+        let assets = self._preview_redeem(shares)?;
+
+        self.data()
+            .set_pending_redeem(controller, &(pending - *shares));
+        self._burn_from(&Self::env().account_id(), shares)?;
+        let reserved = self.data().reserved_assets() + assets;
+        self.data().set_reserved_assets(&reserved);
+
+        let (claimable_assets, claimable_shares) =
+            self.data().claimable_redeem(controller);
+        self.data().set_claimable_redeem(
+            controller,
+            &(claimable_assets + assets, claimable_shares + *shares),
+        );
+
+        Self::env().emit_event(RedeemFulfilled {
+            controller: *controller,
+            assets,
+            shares: *shares,
+        });
+        Ok(assets)
+    }
+
+    fn _claim_default_impl(
+        &mut self,
+        controller: &AccountId,
+        receiver: &AccountId,
+    ) -> Result<(Balance, Balance), PSP22Error> {
+        let (deposit_assets, deposit_shares) =
+            self.data().claimable_deposit(controller);
+        let (redeem_assets, redeem_shares) =
+            self.data().claimable_redeem(controller);
+        self.data().set_claimable_deposit(controller, &(0, 0));
+        self.data().set_claimable_redeem(controller, &(0, 0));
+
+        if deposit_shares > 0 {
+            self._update(
+                Some(&Self::env().account_id()),
+                Some(receiver),
+                &deposit_shares,
+            )?;
+            Self::env().emit_event(Deposit {
+                sender: *controller,
+                owner: *receiver,
+                assets: deposit_assets,
+                shares: deposit_shares,
+            });
+        }
+
+        if redeem_assets > 0 {
+            let reserved = self.data().reserved_assets() - redeem_assets;
+            self.data().set_reserved_assets(&reserved);
+            self._send_assets(receiver, &redeem_assets)?;
+            Self::env().emit_event(Withdraw {
+                sender: *controller,
+                receiver: *receiver,
+                owner: *controller,
+                assets: redeem_assets,
+                shares: redeem_shares,
+            });
+        }
+
+        Ok((deposit_shares, redeem_assets))
+    }
+}
@@ -0,0 +1,11 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+/// Emitted when management and performance fees are accrued by minting shares to the fee recipient.
+#[ink::event]
+pub struct FeesAccrued {
+    #[ink(topic)]
+    pub recipient: AccountId,
+    pub management_fee: Balance,
+    pub performance_fee: Balance,
+    pub shares: Balance,
+}
@@ -0,0 +1,242 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use ink::{contract_ref, env::DefaultEnvironment};
+
+pub type PSP22VaultFeesRef = contract_ref!(PSP22VaultFees, DefaultEnvironment);
+
+/// Denominator of the vault fees expressed in basis points.
+pub const MAX_VAULT_FEE_BPS: u16 = 10_000;
+
+/// Duration of a year in milliseconds, the period the management fee is expressed for.
+pub const MILLISECONDS_PER_YEAR: u64 = 365 * 24 * 60 * 60 * 1000;
+
+/// Precision of the share price tracked by the high-water mark.
+pub const VAULT_FEES_PRICE_PRECISION: u128 = 1_000_000_000_000_000_000;
+
+/// trait extending PSP22Vault with entry, exit, management and performance fees.
+///
+/// - Entry and exit fees are charged in assets and sent to the fee recipient on deposit and withdraw.
+///   They are included in `preview_*` so previews match the executed deposit, mint, withdraw and redeem.
+/// - Management fee is a yearly rate charged on total assets proportionally to the elapsed time.
+/// - Performance fee is charged on the share price growth above the high-water mark.
+///
+/// Management and performance fees are accrued by minting shares to the fee recipient before each
+/// deposit and withdraw. Pending fee shares are included in share conversions, so previews don't
+/// change when the fees get accrued.
+#[ink::trait_definition]
+pub trait PSP22VaultFees {
+    /// Returns the fee charged on deposited assets in basis points.
+    #[ink(message)]
+    fn entry_fee_bps(&self) -> u16;
+
+    /// Returns the fee charged on withdrawn assets in basis points.
+    #[ink(message)]
+    fn exit_fee_bps(&self) -> u16;
+
+    /// Returns the yearly fee charged on total assets in basis points.
+    #[ink(message)]
+    fn management_fee_bps(&self) -> u16;
+
+    /// Returns the fee charged on share price growth above the high-water mark in basis points.
+    #[ink(message)]
+    fn performance_fee_bps(&self) -> u16;
+
+    /// Returns the account receiving the fees or None if it was never set.
+    #[ink(message)]
+    fn fee_recipient(&self) -> Option<AccountId>;
+
+    /// Returns the highest share price (scaled by `VAULT_FEES_PRICE_PRECISION`) fees were accrued at.
+    #[ink(message)]
+    fn high_water_mark(&self) -> Balance;
+
+    /// Returns the timestamp of the last fee accrual.
+    #[ink(message)]
+    fn last_fee_accrual(&self) -> Timestamp;
+
+    /// Returns the amount of shares that would be minted to the fee recipient if fees were accrued now.
+    #[ink(message)]
+    fn pending_fee_shares(&self) -> Result<Balance, MathError>;
+
+    /// Accrues management and performance fees by minting shares to the fee recipient.
+    ///
+    /// Returns the amount of minted shares.
+    /// On success a `FeesAccrued` event is emitted if any shares were minted.
+    #[ink(message)]
+    fn accrue_fees(&mut self) -> Result<Balance, PSP22Error>;
+}
+
+/// trait that must be implemented by exactly one storage field of a contract storage
+/// so the Pendzl PSP22VaultFeesInternal and PSP22VaultFees implementation can be derived.
+pub trait PSP22VaultFeesStorage {
+    /// Retrieves the entry fee in basis points.
+    fn entry_fee_bps(&self) -> u16;
+
+    /// Sets the entry fee in basis points.
+    fn set_entry_fee_bps(&mut self, fee_bps: &u16);
+
+    /// Retrieves the exit fee in basis points.
+    fn exit_fee_bps(&self) -> u16;
+
+    /// Sets the exit fee in basis points.
+    fn set_exit_fee_bps(&mut self, fee_bps: &u16);
+
+    /// Retrieves the management fee in basis points.
+    fn management_fee_bps(&self) -> u16;
+
+    /// Sets the management fee in basis points.
+    fn set_management_fee_bps(&mut self, fee_bps: &u16);
+
+    /// Retrieves the performance fee in basis points.
+    fn performance_fee_bps(&self) -> u16;
+
+    /// Sets the performance fee in basis points.
+    fn set_performance_fee_bps(&mut self, fee_bps: &u16);
+
+    /// Retrieves the fee recipient or None if it was never set.
+    fn fee_recipient(&self) -> Option<AccountId>;
+
+    /// Sets the fee recipient.
+    fn set_fee_recipient(&mut self, fee_recipient: &AccountId);
+
+    /// Retrieves the high-water mark or None if fees were never accrued.
+    fn high_water_mark(&self) -> Option<Balance>;
+
+    /// Sets the high-water mark.
+    fn set_high_water_mark(&mut self, high_water_mark: &Balance);
+
+    /// Retrieves the timestamp of the last fee accrual or None if fees were never accrued.
+    fn last_fee_accrual(&self) -> Option<Timestamp>;
+
+    /// Sets the timestamp of the last fee accrual.
+    fn set_last_fee_accrual(&mut self, timestamp: &Timestamp);
+}
+
+/// trait that is derived by Pendzl PSP22VaultFees implementation macro assuming StorageFieldGetter<PSP22VaultFeesStorage> is implemented
+///
+/// functions of this trait are recomended to use while writing ink::messages
+pub trait PSP22VaultFeesInternal {
+    /// Retrieves the entry fee in basis points.
+    fn _entry_fee_bps(&self) -> u16;
+
+    /// Sets the entry fee in basis points.
+    ///
+    /// # Errors
+    /// Returns `InvalidVaultFee` if `fee_bps` is greater than `MAX_VAULT_FEE_BPS`.
+    fn _set_entry_fee_bps(&mut self, fee_bps: &u16) -> Result<(), PSP22Error>;
+
+    /// Retrieves the exit fee in basis points.
+    fn _exit_fee_bps(&self) -> u16;
+
+    /// Sets the exit fee in basis points.
+    ///
+    /// # Errors
+    /// Returns `InvalidVaultFee` if `fee_bps` is greater than `MAX_VAULT_FEE_BPS`.
+    fn _set_exit_fee_bps(&mut self, fee_bps: &u16) -> Result<(), PSP22Error>;
+
+    /// Retrieves the management fee in basis points.
+    fn _management_fee_bps(&self) -> u16;
+
+    /// Accrues the fees at the current rate and sets the management fee in basis points.
+    ///
+    /// # Errors
+    /// Returns `InvalidVaultFee` if `fee_bps` is greater than `MAX_VAULT_FEE_BPS`.
+    fn _set_management_fee_bps(
+        &mut self,
+        fee_bps: &u16,
+    ) -> Result<(), PSP22Error>;
+
+    /// Retrieves the performance fee in basis points.
+    fn _performance_fee_bps(&self) -> u16;
+
+    /// Accrues the fees at the current rate and sets the performance fee in basis points.
+    ///
+    /// # Errors
+    /// Returns `InvalidVaultFee` if `fee_bps` is greater than `MAX_VAULT_FEE_BPS`.
+    fn _set_performance_fee_bps(
+        &mut self,
+        fee_bps: &u16,
+    ) -> Result<(), PSP22Error>;
+
+    /// Retrieves the fee recipient or None if it was never set.
+    fn _fee_recipient(&self) -> Option<AccountId>;
+
+    /// Accrues the fees to the current fee recipient and sets a new one.
+    fn _set_fee_recipient(
+        &mut self,
+        fee_recipient: &AccountId,
+    ) -> Result<(), PSP22Error>;
+
+    /// Retrieves the high-water mark.
+    ///
+    /// Before the first accrual it is the share price of an empty vault.
+    fn _high_water_mark(&self) -> Balance;
+
+    /// Retrieves the timestamp of the last fee accrual.
+    ///
+    /// Before the first accrual it is the current block timestamp.
+    fn _last_fee_accrual(&self) -> Timestamp;
+
+    /// Returns the current share price scaled by `VAULT_FEES_PRICE_PRECISION`,
+    /// given the amount of `total_assets` and `total_shares`.
+    fn _share_price(
+        &self,
+        total_assets: &Balance,
+        total_shares: &Balance,
+    ) -> Result<Balance, MathError>;
+
+    /// Returns the fee to be charged on top of `assets` (fee on raw), rounded up.
+    fn _fee_on_raw(
+        &self,
+        assets: &Balance,
+        fee_bps: u16,
+    ) -> Result<Balance, MathError>;
+
+    /// Returns the fee part of `assets` that already include the fee (fee on total), rounded up.
+    fn _fee_on_total(
+        &self,
+        assets: &Balance,
+        fee_bps: u16,
+    ) -> Result<Balance, MathError>;
+
+    /// Returns the pending management and performance fees in assets.
+    ///
+    /// No fees are pending while the fee recipient is not set.
+    fn _pending_fees(&self) -> Result<(Balance, Balance), MathError>;
+
+    /// Returns the amount of shares worth the pending fees.
+    fn _pending_fee_shares(&self) -> Result<Balance, MathError>;
+
+    /// Same as `PSP22VaultInternal::_convert_to_shares` but with the pending fee shares
+    /// included in the total supply.
+    ///
+    /// Used by the generated `PSP22VaultInternal::_convert_to_shares`.
+    fn _convert_to_shares_with_pending_fees(
+        &self,
+        assets: &Balance,
+        round: Rounding,
+    ) -> Result<Balance, MathError>;
+
+    /// Same as `PSP22VaultInternal::_convert_to_assets` but with the pending fee shares
+    /// included in the total supply.
+    ///
+    /// Used by the generated `PSP22VaultInternal::_convert_to_assets`.
+    fn _convert_to_assets_with_pending_fees(
+        &self,
+        shares: &Balance,
+        round: Rounding,
+    ) -> Result<Balance, MathError>;
+
+    /// Mints the pending fee shares to the fee recipient, updates the high-water mark
+    /// and the timestamp of the last accrual.
+    ///
+    /// On success a `FeesAccrued` event is emitted if any shares were minted.
+    ///
+    /// Called by the generated `PSP22VaultInternal::_deposit` and `_withdraw`.
+    fn _accrue_fees(&mut self) -> Result<Balance, PSP22Error>;
+
+    /// Sends `fee` assets to the fee recipient.
+    /// Does nothing if the fee recipient is the vault itself or is not set, the fee stays in the vault.
+    ///
+    /// Called by the generated `PSP22VaultInternal::_deposit` and `_withdraw`.
+    fn _send_fee(&mut self, fee: &Balance) -> Result<(), PSP22Error>;
+}
@@ -0,0 +1,451 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use ink::primitives::AccountId;
+use pendzl::math::{
+    errors::MathError,
+    operations::{mul_div, Rounding},
+};
+use pendzl::traits::{Balance, DefaultEnv, StorageFieldGetter, Timestamp};
+
+use super::{
+    FeesAccrued, PSP22VaultFeesInternal, PSP22VaultFeesStorage,
+    MAX_VAULT_FEE_BPS, MILLISECONDS_PER_YEAR, VAULT_FEES_PRICE_PRECISION,
+};
+use crate::token::psp22::vault::PSP22VaultInternal;
+use crate::token::psp22::{PSP22Error, PSP22Internal};
+
+#[derive(Default, Debug)]
+#[pendzl::storage_item]
+pub struct PSP22VaultFeesData {
+    #[lazy]
+    pub entry_fee_bps: u16,
+    #[lazy]
+    pub exit_fee_bps: u16,
+    #[lazy]
+    pub management_fee_bps: u16,
+    #[lazy]
+    pub performance_fee_bps: u16,
+    #[lazy]
+    pub fee_recipient: AccountId,
+    #[lazy]
+    pub high_water_mark: Balance,
+    #[lazy]
+    pub last_fee_accrual: Timestamp,
+}
+
+impl PSP22VaultFeesData {
+    pub fn new(
+        entry_fee_bps: u16,
+        exit_fee_bps: u16,
+        management_fee_bps: u16,
+        performance_fee_bps: u16,
+        fee_recipient: AccountId,
+    ) -> Result<Self, PSP22Error> {
+        if entry_fee_bps > MAX_VAULT_FEE_BPS
+            || exit_fee_bps > MAX_VAULT_FEE_BPS
+            || management_fee_bps > MAX_VAULT_FEE_BPS
+            || performance_fee_bps > MAX_VAULT_FEE_BPS
+        {
+            return Err(PSP22Error::InvalidVaultFee);
+        }
+        let mut instance: PSP22VaultFeesData = Default::default();
+        instance.entry_fee_bps.set(&entry_fee_bps);
+        instance.exit_fee_bps.set(&exit_fee_bps);
+        instance.management_fee_bps.set(&management_fee_bps);
+        instance.performance_fee_bps.set(&performance_fee_bps);
+        instance.fee_recipient.set(&fee_recipient);
+        instance
+            .last_fee_accrual
+            .set(&ink::env::block_timestamp::<ink::env::DefaultEnvironment>());
+        Ok(instance)
+    }
+}
+
+impl PSP22VaultFeesStorage for PSP22VaultFeesData {
+    fn entry_fee_bps(&self) -> u16 {
+        self.entry_fee_bps.get_or_default()
+    }
+
+    fn set_entry_fee_bps(&mut self, fee_bps: &u16) {
+        self.entry_fee_bps.set(fee_bps);
+    }
+
+    fn exit_fee_bps(&self) -> u16 {
+        self.exit_fee_bps.get_or_default()
+    }
+
+    fn set_exit_fee_bps(&mut self, fee_bps: &u16) {
+        self.exit_fee_bps.set(fee_bps);
+    }
+
+    fn management_fee_bps(&self) -> u16 {
+        self.management_fee_bps.get_or_default()
+    }
+
+    fn set_management_fee_bps(&mut self, fee_bps: &u16) {
+        self.management_fee_bps.set(fee_bps);
+    }
+
+    fn performance_fee_bps(&self) -> u16 {
+        self.performance_fee_bps.get_or_default()
+    }
+
+    fn set_performance_fee_bps(&mut self, fee_bps: &u16) {
+        self.performance_fee_bps.set(fee_bps);
+    }
+
+    fn fee_recipient(&self) -> Option<AccountId> {
+        self.fee_recipient.get()
+    }
+
+    fn set_fee_recipient(&mut self, fee_recipient: &AccountId) {
+        self.fee_recipient.set(fee_recipient);
+    }
+
+    fn high_water_mark(&self) -> Option<Balance> {
+        self.high_water_mark.get()
+    }
+
+    fn set_high_water_mark(&mut self, high_water_mark: &Balance) {
+        self.high_water_mark.set(high_water_mark);
+    }
+
+    fn last_fee_accrual(&self) -> Option<Timestamp> {
+        self.last_fee_accrual.get()
+    }
+
+    fn set_last_fee_accrual(&mut self, timestamp: &Timestamp) {
+        self.last_fee_accrual.set(timestamp);
+    }
+}
+
+pub trait PSP22VaultFeesDefaultImpl: PSP22VaultFeesInternal {
+    fn entry_fee_bps_default_impl(&self) -> u16 {
+        self._entry_fee_bps()
+    }
+
+    fn exit_fee_bps_default_impl(&self) -> u16 {
+        self._exit_fee_bps()
+    }
+
+    fn management_fee_bps_default_impl(&self) -> u16 {
+        self._management_fee_bps()
+    }
+
+    fn performance_fee_bps_default_impl(&self) -> u16 {
+        self._performance_fee_bps()
+    }
+
+    fn fee_recipient_default_impl(&self) -> Option<AccountId> {
+        self._fee_recipient()
+    }
+
+    fn high_water_mark_default_impl(&self) -> Balance {
+        self._high_water_mark()
+    }
+
+    fn last_fee_accrual_default_impl(&self) -> Timestamp {
+        self._last_fee_accrual()
+    }
+
+    fn pending_fee_shares_default_impl(&self) -> Result<Balance, MathError> {
+        self._pending_fee_shares()
+    }
+
+    fn accrue_fees_default_impl(&mut self) -> Result<Balance, PSP22Error> {
+        self._accrue_fees()
+    }
+}
+
+pub trait PSP22VaultFeesInternalDefaultImpl:
+    StorageFieldGetter<PSP22VaultFeesData>
+    + PSP22VaultFeesInternal
+    + PSP22VaultInternal
+    + PSP22Internal
+    + DefaultEnv
+where
+    PSP22VaultFeesData: PSP22VaultFeesStorage,
+{
+    fn _entry_fee_bps_default_impl(&self) -> u16 {
+        self.data().entry_fee_bps()
+    }
+
+    fn _set_entry_fee_bps_default_impl(
+        &mut self,
+        fee_bps: &u16,
+    ) -> Result<(), PSP22Error> {
+        if *fee_bps > MAX_VAULT_FEE_BPS {
+            return Err(PSP22Error::InvalidVaultFee);
+        }
+        self.data().set_entry_fee_bps(fee_bps);
+        Ok(())
+    }
+
+    fn _exit_fee_bps_default_impl(&self) -> u16 {
+        self.data().exit_fee_bps()
+    }
+
+    fn _set_exit_fee_bps_default_impl(
+        &mut self,
+        fee_bps: &u16,
+    ) -> Result<(), PSP22Error> {
+        if *fee_bps > MAX_VAULT_FEE_BPS {
+            return Err(PSP22Error::InvalidVaultFee);
+        }
+        self.data().set_exit_fee_bps(fee_bps);
+        Ok(())
+    }
+
+    fn _management_fee_bps_default_impl(&self) -> u16 {
+        self.data().management_fee_bps()
+    }
+
+    fn _set_management_fee_bps_default_impl(
+        &mut self,
+        fee_bps: &u16,
+    ) -> Result<(), PSP22Error> {
+        if *fee_bps > MAX_VAULT_FEE_BPS {
+            return Err(PSP22Error::InvalidVaultFee);
+        }
+        self._accrue_fees()?;
+        self.data().set_management_fee_bps(fee_bps);
+        Ok(())
+    }
+
+    fn _performance_fee_bps_default_impl(&self) -> u16 {
+        self.data().performance_fee_bps()
+    }
+
+    fn _set_performance_fee_bps_default_impl(
+        &mut self,
+        fee_bps: &u16,
+    ) -> Result<(), PSP22Error> {
+        if *fee_bps > MAX_VAULT_FEE_BPS {
+            return Err(PSP22Error::InvalidVaultFee);
+        }
+        self._accrue_fees()?;
+        self.data().set_performance_fee_bps(fee_bps);
+        Ok(())
+    }
+
+    fn _fee_recipient_default_impl(&self) -> Option<AccountId> {
+        self.data().fee_recipient()
+    }
+
+    fn _set_fee_recipient_default_impl(
+        &mut self,
+        fee_recipient: &AccountId,
+    ) -> Result<(), PSP22Error> {
+        self._accrue_fees()?;
+        self.data().set_fee_recipient(fee_recipient);
+        Ok(())
+    }
+
+    fn _high_water_mark_default_impl(&self) -> Balance {
+        match self.data().high_water_mark() {
+            Some(high_water_mark) => high_water_mark,
+            None => self._share_price(&0, &0).unwrap_or_default(),
+        }
+    }
+
+    fn _last_fee_accrual_default_impl(&self) -> Timestamp {
+        self.data()
+            .last_fee_accrual()
+            .unwrap_or(Self::env().block_timestamp())
+    }
+
+    fn _share_price_default_impl(
+        &self,
+        total_assets: &Balance,
+        total_shares: &Balance,
+    ) -> Result<Balance, MathError> {
+        let decimals_offset = 10_u128.pow(self._decimals_offset() as u32);
+        mul_div(
+            total_assets.checked_add(1).ok_or(MathError::Overflow)?,
+            VAULT_FEES_PRICE_PRECISION,
+            total_shares
+                .checked_add(decimals_offset)
+                .ok_or(MathError::Overflow)?,
+            Rounding::Down,
+        )
+    }
+
+    fn _fee_on_raw_default_impl(
+        &self,
+        assets: &Balance,
+        fee_bps: u16,
+    ) -> Result<Balance, MathError> {
+        mul_div(
+            *assets,
+            fee_bps as u128,
+            MAX_VAULT_FEE_BPS as u128,
+            Rounding::Up,
+        )
+    }
+
+    fn _fee_on_total_default_impl(
+        &self,
+        assets: &Balance,
+        fee_bps: u16,
+    ) -> Result<Balance, MathError> {
+        mul_div(
+            *assets,
+            fee_bps as u128,
+            fee_bps as u128 + MAX_VAULT_FEE_BPS as u128,
+            Rounding::Up,
+        )
+    }
+
+    fn _pending_fees_default_impl(
+        &self,
+    ) -> Result<(Balance, Balance), MathError> {
+        if self._fee_recipient().is_none() {
+            return Ok((0, 0));
+        }
+        let total_assets = self._total_assets();
+        let total_shares = self._total_supply();
+        let elapsed = Self::env()
+            .block_timestamp()
+            .saturating_sub(self._last_fee_accrual());
+
+        let management_fee = core::cmp::min(
+            mul_div(
+                total_assets,
+                self._management_fee_bps() as u128 * elapsed as u128,
+                MAX_VAULT_FEE_BPS as u128 * MILLISECONDS_PER_YEAR as u128,
+                Rounding::Down,
+            )?,
+            total_assets,
+        );
+
+        // performance is measured on the share price net of the management fee
+        let share_price =
+            self._share_price(&(total_assets - management_fee), &total_shares)?;
+        let high_water_mark = self._high_water_mark();
+        if share_price <= high_water_mark {
+            return Ok((management_fee, 0));
+        }
+        let decimals_offset = 10_u128.pow(self._decimals_offset() as u32);
+        let profit = mul_div(
+            share_price - high_water_mark,
+            total_shares
+                .checked_add(decimals_offset)
+                .ok_or(MathError::Overflow)?,
+            VAULT_FEES_PRICE_PRECISION,
+            Rounding::Down,
+        )?;
+        let performance_fee = mul_div(
+            profit,
+            self._performance_fee_bps() as u128,
+            MAX_VAULT_FEE_BPS as u128,
+            Rounding::Down,
+        )?;
+
+        Ok((management_fee, performance_fee))
+    }
+
+    fn _pending_fee_shares_default_impl(&self) -> Result<Balance, MathError> {
+        let (management_fee, performance_fee) = self._pending_fees()?;
+        let total_assets = self._total_assets();
+        let fees = core::cmp::min(
+            management_fee
+                .checked_add(performance_fee)
+                .ok_or(MathError::Overflow)?,
+            total_assets,
+        );
+        if fees == 0 {
+            return Ok(0);
+        }
+        let decimals_offset = 10_u128.pow(self._decimals_offset() as u32);
+        // shares worth `fees` once minted: fees / (total_assets + 1) of the diluted supply
+        mul_div(
+            fees,
+            self._total_supply()
+                .checked_add(decimals_offset)
+                .ok_or(MathError::Overflow)?,
+            total_assets + 1 - fees,
+            Rounding::Down,
+        )
+    }
+
+    fn _convert_to_shares_with_pending_fees_default_impl(
+        &self,
+        assets: &Balance,
+        round: Rounding,
+    ) -> Result<Balance, MathError> {
+        let total_shares = self
+            ._total_supply()
+            .checked_add(self._pending_fee_shares()?)
+            .ok_or(MathError::Overflow)?;
+        let total_assets = self._total_assets();
+        let decimals_offset = 10_u128.pow(self._decimals_offset() as u32);
+        mul_div(
+            *assets,
+            total_shares
+                .checked_add(decimals_offset)
+                .ok_or(MathError::Overflow)?,
+            total_assets.checked_add(1).ok_or(MathError::Overflow)?,
+            round,
+        )
+    }
+
+    fn _convert_to_assets_with_pending_fees_default_impl(
+        &self,
+        shares: &Balance,
+        round: Rounding,
+    ) -> Result<Balance, MathError> {
+        let total_shares = self
+            ._total_supply()
+            .checked_add(self._pending_fee_shares()?)
+            .ok_or(MathError::Overflow)?;
+        let total_assets = self._total_assets();
+        let decimals_offset = 10_u128.pow(self._decimals_offset() as u32);
+        mul_div(
+            *shares,
+            total_assets.checked_add(1).ok_or(MathError::Overflow)?,
+            total_shares
+                .checked_add(decimals_offset)
+                .ok_or(MathError::Overflow)?,
+            round,
+        )
+    }
+
+    fn _accrue_fees_default_impl(&mut self) -> Result<Balance, PSP22Error> {
+        let (management_fee, performance_fee) = self._pending_fees()?;
+        let shares = self._pending_fee_shares()?;
+
+        // shares are only pending if the fee recipient is set
+        if let Some(recipient) = self._fee_recipient().filter(|_| shares > 0) {
+            self._mint_to(&recipient, &shares)?;
+            Self::env().emit_event(FeesAccrued {
+                recipient,
+                management_fee,
+                performance_fee,
+                shares,
+            });
+        }
+
+        let share_price =
+            self._share_price(&self._total_assets(), &self._total_supply())?;
+        let high_water_mark =
+            core::cmp::max(self._high_water_mark(), share_price);
+        self.data().set_high_water_mark(&high_water_mark);
+        self.data()
+            .set_last_fee_accrual(&Self::env().block_timestamp());
+        Ok(shares)
+    }
+
+    fn _send_fee_default_impl(
+        &mut self,
+        fee: &Balance,
+    ) -> Result<(), PSP22Error> {
+        match self._fee_recipient() {
+            Some(recipient)
+                if *fee > 0 && recipient != Self::env().account_id() =>
+            {
+                self._send_assets(&recipient, fee)
+            }
+            _ => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,17 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+pub use crate::token::psp22::PSP22Error;
+pub use ink::primitives::AccountId;
+pub use pendzl::{
+    math::{errors::MathError, operations::Rounding},
+    traits::{Balance, Timestamp},
+};
+
+include!("fees_events.rs");
+include!("fees_trait.rs");
+
+#[cfg(feature = "psp22_vault_fees_impl")]
+mod implementation;
+
+#[cfg(feature = "psp22_vault_fees_impl")]
+pub use implementation::*;
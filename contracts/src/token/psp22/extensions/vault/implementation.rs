@@ -12,7 +12,7 @@ use crate::token::psp22::implementation::PSP22Data;
 use crate::token::psp22::{PSP22Error, PSP22};
 use crate::token::psp22::{PSP22Internal, PSP22Ref, PSP22Storage};
 
-use ink::prelude::{string::ToString, vec::*};
+use ink::prelude::vec::*;
 
 use ink::codegen::TraitCallBuilder;
 use pendzl::math::operations::Rounding;
@@ -192,23 +192,43 @@ where
         self._convert_to_assets(&shares, Rounding::Down)
     }
 
-    fn _deposit_default_impl(
+    fn _receive_assets_default_impl(
         &mut self,
-        caller: &AccountId,
-        receiver: &AccountId,
+        from: &AccountId,
         assets: &Balance,
-        shares: &Balance,
     ) -> Result<(), PSP22Error> {
         self._asset()
             .call_mut()
             .transfer_from(
-                *caller,
+                *from,
                 Self::env().account_id(),
                 *assets,
                 Vec::<u8>::new(),
             )
             .call_v1()
-            .invoke()?;
+            .invoke()
+    }
+
+    fn _send_assets_default_impl(
+        &mut self,
+        to: &AccountId,
+        assets: &Balance,
+    ) -> Result<(), PSP22Error> {
+        self._asset()
+            .call_mut()
+            .transfer(*to, *assets, Vec::<u8>::new())
+            .call_v1()
+            .invoke()
+    }
+
+    fn _deposit_default_impl(
+        &mut self,
+        caller: &AccountId,
+        receiver: &AccountId,
+        assets: &Balance,
+        shares: &Balance,
+    ) -> Result<(), PSP22Error> {
+        self._receive_assets(caller, assets)?;
         self._mint_to(receiver, shares)?;
 
         Self::env().emit_event(Deposit {
@@ -234,11 +254,7 @@ where
         }
 
         self._burn_from(owner, shares)?;
-        self._asset()
-            .call_mut()
-            .transfer(*receiver, *assets, Vec::<u8>::new())
-            .call_v1()
-            .invoke()?;
+        self._send_assets(receiver, assets)?;
 
         Self::env().emit_event(Withdraw {
             sender: *caller,
@@ -249,6 +265,80 @@ where
         });
         Ok(())
     }
+
+    fn _deposit_checked_default_impl(
+        &mut self,
+        caller: &AccountId,
+        receiver: &AccountId,
+        assets: &Balance,
+        min_shares: &Balance,
+    ) -> Result<Balance, PSP22Error> {
+        if *assets > self._max_deposit(receiver) {
+            return Err(PSP22Error::MaxDepositExceeded);
+        }
+        let shares = self._preview_deposit(assets)?;
+        if shares < *min_shares {
+            return Err(PSP22Error::SharesBelowMin);
+        }
+        self._deposit(caller, receiver, assets, &shares)?;
+        Ok(shares)
+    }
+
+    fn _mint_checked_default_impl(
+        &mut self,
+        caller: &AccountId,
+        receiver: &AccountId,
+        shares: &Balance,
+        max_assets: &Balance,
+    ) -> Result<Balance, PSP22Error> {
+        if *shares > self._max_mint(receiver) {
+            return Err(PSP22Error::MaxMintExceeded);
+        }
+        let assets = self._preview_mint(shares)?;
+        if assets > *max_assets {
+            return Err(PSP22Error::AssetsAboveMax);
+        }
+        self._deposit(caller, receiver, &assets, shares)?;
+        Ok(assets)
+    }
+
+    fn _withdraw_checked_default_impl(
+        &mut self,
+        caller: &AccountId,
+        receiver: &AccountId,
+        owner: &AccountId,
+        assets: &Balance,
+        max_shares: &Balance,
+    ) -> Result<Balance, PSP22Error> {
+        if *assets > self._max_withdraw(owner) {
+            return Err(PSP22Error::MaxWithdrawExceeded);
+        }
+        let shares = self._preview_withdraw(assets)?;
+        if shares > *max_shares {
+            return Err(PSP22Error::SharesAboveMax);
+        }
+        self._withdraw(caller, receiver, owner, assets, &shares)?;
+        Ok(*assets)
+    }
+
+    fn _redeem_checked_default_impl(
+        &mut self,
+        caller: &AccountId,
+        receiver: &AccountId,
+        owner: &AccountId,
+        shares: &Balance,
+        min_assets: &Balance,
+    ) -> Result<Balance, PSP22Error> {
+        if *shares > self._max_redeem(owner) {
+            return Err(PSP22Error::MaxRedeemExceeded);
+        }
+        let assets = self._preview_redeem(shares)?;
+        if assets < *min_assets {
+            return Err(PSP22Error::AssetsBelowMin);
+        }
+        self._withdraw(caller, receiver, owner, &assets, shares)?;
+        Ok(assets)
+    }
 }
 
 pub trait PSP22VaultDefaultImpl:
@@ -327,12 +417,7 @@ pub trait PSP22VaultDefaultImpl:
         assets: Balance,
         receiver: AccountId,
     ) -> Result<Balance, PSP22Error> {
-        if assets > self._max_deposit(&receiver) {
-            return Err(PSP22Error::Custom("V:MaxDeposit".to_string()));
-        }
-        let shares = self._preview_deposit(&assets)?;
-        self._deposit(&Self::env().caller(), &receiver, &assets, &shares)?;
-        Ok(shares)
+        self._deposit_checked(&Self::env().caller(), &receiver, &assets, &0)
     }
 
     fn mint_default_impl(
@@ -340,12 +425,12 @@ pub trait PSP22VaultDefaultImpl:
         shares: Balance,
         receiver: AccountId,
     ) -> Result<Balance, PSP22Error> {
-        if shares > self._max_mint(&receiver) {
-            return Err(PSP22Error::Custom("V:MaxMint".to_string()));
-        }
-        let assets = self._preview_mint(&shares)?;
-        self._deposit(&Self::env().caller(), &receiver, &assets, &shares)?;
-        Ok(assets)
+        self._mint_checked(
+            &Self::env().caller(),
+            &receiver,
+            &shares,
+            &Balance::MAX,
+        )
     }
 
     fn withdraw_default_impl(
@@ -354,18 +439,13 @@ pub trait PSP22VaultDefaultImpl:
         receiver: AccountId,
         owner: AccountId,
     ) -> Result<Balance, PSP22Error> {
-        if assets > self._max_withdraw(&owner) {
-            return Err(PSP22Error::Custom("V:MaxWithdraw".to_string()));
-        }
-        let shares = self._preview_withdraw(&assets)?;
-        self._withdraw(
+        self._withdraw_checked(
             &Self::env().caller(),
             &receiver,
             &owner,
             &assets,
-            &shares,
-        )?;
-        Ok(assets)
+            &Balance::MAX,
+        )
     }
 
     fn redeem_default_impl(
@@ -374,17 +454,12 @@ pub trait PSP22VaultDefaultImpl:
         receiver: AccountId,
         owner: AccountId,
     ) -> Result<Balance, PSP22Error> {
-        if shares > self._max_redeem(&owner) {
-            return Err(PSP22Error::Custom("V:MaxRedeem".to_string()));
-        }
-        let assets = self._preview_redeem(&shares)?;
-        self._withdraw(
+        self._redeem_checked(
             &Self::env().caller(),
             &receiver,
             &owner,
-            &assets,
             &shares,
-        )?;
-        Ok(assets)
+            &0,
+        )
     }
 }
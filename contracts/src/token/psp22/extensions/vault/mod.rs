@@ -10,6 +10,15 @@ pub use pendzl::{
 include!("vault_events.rs");
 include!("vault_trait.rs");
 
+#[cfg(feature = "psp22_vault_async")]
+pub mod async_requests;
+#[cfg(feature = "psp22_vault_fees")]
+pub mod fees;
+#[cfg(feature = "psp22_vault_native")]
+pub mod native;
+#[cfg(feature = "psp22_vault_slippage")]
+pub mod slippage;
+
 #[cfg(all(feature = "psp22_vault_impl"))]
 mod implementation;
 
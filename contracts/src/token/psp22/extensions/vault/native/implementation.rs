@@ -0,0 +1,59 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use ink::primitives::AccountId;
+use pendzl::traits::{Balance, DefaultEnv};
+
+use crate::token::psp22::vault::{PSP22VaultDefaultImpl, PSP22VaultInternal};
+use crate::token::psp22::PSP22Error;
+
+pub trait PSP22VaultNativeDefaultImpl: PSP22VaultDefaultImpl {
+    fn deposit_native_default_impl(
+        &mut self,
+        receiver: AccountId,
+    ) -> Result<Balance, PSP22Error> {
+        self.deposit_default_impl(Self::env().transferred_value(), receiver)
+    }
+
+    fn mint_native_default_impl(
+        &mut self,
+        shares: Balance,
+        receiver: AccountId,
+    ) -> Result<Balance, PSP22Error> {
+        self.mint_default_impl(shares, receiver)
+    }
+}
+
+/// Implementations of `PSP22VaultInternal` functions moving the native currency instead of a PSP22 asset.
+pub trait PSP22VaultNativeInternalDefaultImpl:
+    PSP22VaultInternal + DefaultEnv
+{
+    fn _total_assets_native_default_impl(&self) -> Balance {
+        // the value transferred with the ongoing call is excluded until the call ends,
+        // also after it was received by `_deposit`
+        Self::env()
+            .balance()
+            .saturating_sub(Self::env().transferred_value())
+    }
+
+    fn _receive_assets_native_default_impl(
+        &mut self,
+        _from: &AccountId,
+        assets: &Balance,
+    ) -> Result<(), PSP22Error> {
+        if Self::env().transferred_value() != *assets {
+            return Err(PSP22Error::InvalidTransferredValue);
+        }
+        Ok(())
+    }
+
+    fn _send_assets_native_default_impl(
+        &mut self,
+        to: &AccountId,
+        assets: &Balance,
+    ) -> Result<(), PSP22Error> {
+        if Self::env().transfer(*to, *assets).is_err() {
+            return Err(PSP22Error::NativeTransferFailed);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,54 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use ink::{contract_ref, env::DefaultEnvironment};
+
+pub type PSP22VaultNativeRef =
+    contract_ref!(PSP22VaultNative, DefaultEnvironment);
+
+/// Id of the asset of a vault over the native currency, returned by `PSP22Vault::asset`.
+///
+/// Pass it as the asset to `PSP22VaultData::new` together with the decimals of the native currency.
+pub const NATIVE_ASSET_ID: [u8; 32] = [0xEE; 32];
+
+/// trait extending PSP22Vault whose underlying asset is the native currency.
+///
+/// Assets are deposited by transferring the native currency with `deposit_native` and `mint_native`
+/// and are withdrawn as native currency by `PSP22Vault::withdraw` and `PSP22Vault::redeem`.
+/// Total assets are the balance of the vault minus the native currency transferred with the ongoing call.
+/// The transferred value is excluded for the whole call, so code running after the deposit
+/// in the same call (e.g. the `PSP22VaultFees` accrual after `_deposit`) sees the total assets
+/// without the deposited assets.
+/// `PSP22Vault::deposit` and `PSP22Vault::mint` are not payable, so they only succeed for 0 assets.
+#[ink::trait_definition]
+pub trait PSP22VaultNative {
+    /// Deposits the native currency transferred with the call and mints shares to `receiver`.
+    ///
+    /// Returns the amount of minted shares.
+    /// On success a `Deposit` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MaxDepositExceeded` if the transferred value is above `max_deposit`.
+    #[ink(message, payable)]
+    fn deposit_native(
+        &mut self,
+        receiver: AccountId,
+    ) -> Result<Balance, PSP22Error>;
+
+    /// Mints exactly `shares` to `receiver` for the native currency transferred with the call.
+    ///
+    /// The transferred value must be equal to `preview_mint(shares)`.
+    /// Returns the amount of deposited assets.
+    /// On success a `Deposit` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MaxMintExceeded` if `shares` is above `max_mint`.
+    /// Returns `InvalidTransferredValue` if the transferred value is not equal to the assets to deposit.
+    #[ink(message, payable)]
+    fn mint_native(
+        &mut self,
+        shares: Balance,
+        receiver: AccountId,
+    ) -> Result<Balance, PSP22Error>;
+}
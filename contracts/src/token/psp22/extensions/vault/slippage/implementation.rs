@@ -0,0 +1,94 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use ink::primitives::AccountId;
+use pendzl::traits::{Balance, DefaultEnv, Timestamp};
+
+use super::PSP22VaultSlippageInternal;
+use crate::token::psp22::vault::PSP22VaultInternal;
+use crate::token::psp22::PSP22Error;
+
+pub trait PSP22VaultSlippageDefaultImpl:
+    PSP22VaultSlippageInternal + PSP22VaultInternal + DefaultEnv
+{
+    fn deposit_with_min_shares_default_impl(
+        &mut self,
+        assets: Balance,
+        receiver: AccountId,
+        min_shares: Balance,
+        deadline: Option<Timestamp>,
+    ) -> Result<Balance, PSP22Error> {
+        self._check_deadline(&deadline)?;
+        self._deposit_checked(
+            &Self::env().caller(),
+            &receiver,
+            &assets,
+            &min_shares,
+        )
+    }
+
+    fn mint_with_max_assets_default_impl(
+        &mut self,
+        shares: Balance,
+        receiver: AccountId,
+        max_assets: Balance,
+        deadline: Option<Timestamp>,
+    ) -> Result<Balance, PSP22Error> {
+        self._check_deadline(&deadline)?;
+        self._mint_checked(
+            &Self::env().caller(),
+            &receiver,
+            &shares,
+            &max_assets,
+        )
+    }
+
+    fn withdraw_with_max_shares_default_impl(
+        &mut self,
+        assets: Balance,
+        receiver: AccountId,
+        owner: AccountId,
+        max_shares: Balance,
+        deadline: Option<Timestamp>,
+    ) -> Result<Balance, PSP22Error> {
+        self._check_deadline(&deadline)?;
+        self._withdraw_checked(
+            &Self::env().caller(),
+            &receiver,
+            &owner,
+            &assets,
+            &max_shares,
+        )
+    }
+
+    fn redeem_with_min_assets_default_impl(
+        &mut self,
+        shares: Balance,
+        receiver: AccountId,
+        owner: AccountId,
+        min_assets: Balance,
+        deadline: Option<Timestamp>,
+    ) -> Result<Balance, PSP22Error> {
+        self._check_deadline(&deadline)?;
+        self._redeem_checked(
+            &Self::env().caller(),
+            &receiver,
+            &owner,
+            &shares,
+            &min_assets,
+        )
+    }
+}
+
+pub trait PSP22VaultSlippageInternalDefaultImpl: DefaultEnv {
+    fn _check_deadline_default_impl(
+        &self,
+        deadline: &Option<Timestamp>,
+    ) -> Result<(), PSP22Error> {
+        if let Some(deadline) = deadline {
+            if Self::env().block_timestamp() > *deadline {
+                return Err(PSP22Error::VaultDeadlineExpired);
+            }
+        }
+        Ok(())
+    }
+}
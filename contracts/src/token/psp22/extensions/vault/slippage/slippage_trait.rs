@@ -0,0 +1,109 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use ink::{contract_ref, env::DefaultEnvironment};
+
+pub type PSP22VaultSlippageRef =
+    contract_ref!(PSP22VaultSlippage, DefaultEnvironment);
+
+/// trait extending PSP22Vault with slippage protected deposit, mint, withdraw and redeem,
+/// based on the ERC-5143 "Slippage Protection for Tokenized Vault", as defined in
+/// https://eips.ethereum.org/EIPS/eip-5143.
+///
+/// Each message executes as its `PSP22Vault` counterpart, but fails if the amount exchanged
+/// at inclusion time is worse than the limit given by the caller or if the block timestamp
+/// is past the optional `deadline`.
+/// Can not be combined with PSP22VaultNative (the messages are not payable).
+#[ink::trait_definition]
+pub trait PSP22VaultSlippage {
+    /// Same as `PSP22Vault::deposit`, but fails if less than `min_shares` would be minted.
+    ///
+    /// Returns the amount of minted shares.
+    /// On success a `Deposit` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns `VaultDeadlineExpired` if the block timestamp is past `deadline`.
+    /// Returns `MaxDepositExceeded` if `assets` are above `max_deposit`.
+    /// Returns `SharesBelowMin` if the minted shares would be less than `min_shares`.
+    #[ink(message)]
+    fn deposit_with_min_shares(
+        &mut self,
+        assets: Balance,
+        receiver: AccountId,
+        min_shares: Balance,
+        deadline: Option<Timestamp>,
+    ) -> Result<Balance, PSP22Error>;
+
+    /// Same as `PSP22Vault::mint`, but fails if more than `max_assets` would be deposited.
+    ///
+    /// Returns the amount of deposited assets.
+    /// On success a `Deposit` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns `VaultDeadlineExpired` if the block timestamp is past `deadline`.
+    /// Returns `MaxMintExceeded` if `shares` are above `max_mint`.
+    /// Returns `AssetsAboveMax` if the deposited assets would be more than `max_assets`.
+    #[ink(message)]
+    fn mint_with_max_assets(
+        &mut self,
+        shares: Balance,
+        receiver: AccountId,
+        max_assets: Balance,
+        deadline: Option<Timestamp>,
+    ) -> Result<Balance, PSP22Error>;
+
+    /// Same as `PSP22Vault::withdraw`, but fails if more than `max_shares` would be burned.
+    ///
+    /// Returns the amount of withdrawn assets.
+    /// On success a `Withdraw` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns `VaultDeadlineExpired` if the block timestamp is past `deadline`.
+    /// Returns `MaxWithdrawExceeded` if `assets` are above `max_withdraw`.
+    /// Returns `SharesAboveMax` if the burned shares would be more than `max_shares`.
+    #[ink(message)]
+    fn withdraw_with_max_shares(
+        &mut self,
+        assets: Balance,
+        receiver: AccountId,
+        owner: AccountId,
+        max_shares: Balance,
+        deadline: Option<Timestamp>,
+    ) -> Result<Balance, PSP22Error>;
+
+    /// Same as `PSP22Vault::redeem`, but fails if less than `min_assets` would be withdrawn.
+    ///
+    /// Returns the amount of withdrawn assets.
+    /// On success a `Withdraw` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns `VaultDeadlineExpired` if the block timestamp is past `deadline`.
+    /// Returns `MaxRedeemExceeded` if `shares` are above `max_redeem`.
+    /// Returns `AssetsBelowMin` if the withdrawn assets would be less than `min_assets`.
+    #[ink(message)]
+    fn redeem_with_min_assets(
+        &mut self,
+        shares: Balance,
+        receiver: AccountId,
+        owner: AccountId,
+        min_assets: Balance,
+        deadline: Option<Timestamp>,
+    ) -> Result<Balance, PSP22Error>;
+}
+
+/// trait that is derived by Pendzl PSP22VaultSlippage implementation macro
+///
+/// functions of this trait are recomended to use while writing ink::messages
+pub trait PSP22VaultSlippageInternal {
+    /// Checks that the block timestamp is not past `deadline`. `None` means no deadline.
+    ///
+    /// # Errors
+    /// Returns `VaultDeadlineExpired` if the block timestamp is past `deadline`.
+    fn _check_deadline(
+        &self,
+        deadline: &Option<Timestamp>,
+    ) -> Result<(), PSP22Error>;
+}
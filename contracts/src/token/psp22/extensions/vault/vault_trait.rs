@@ -284,6 +284,24 @@ pub trait PSP22VaultInternal {
     /// doc @ PSP22Vault::preview_redeem
     fn _preview_redeem(&self, shares: &Balance) -> Result<Balance, MathError>;
 
+    /// Transfers `assets` of the underlying asset from `from` to the vault.
+    ///
+    /// Called by `_deposit`. Default implementation calls `transfer_from` on the asset.
+    fn _receive_assets(
+        &mut self,
+        from: &AccountId,
+        assets: &Balance,
+    ) -> Result<(), PSP22Error>;
+
+    /// Transfers `assets` of the underlying asset from the vault to `to`.
+    ///
+    /// Called by `_withdraw`. Default implementation calls `transfer` on the asset.
+    fn _send_assets(
+        &mut self,
+        to: &AccountId,
+        assets: &Balance,
+    ) -> Result<(), PSP22Error>;
+
     /// Common workflow for deposit/mint operations.
     ///
     /// - Handles transfer of assets from caller to contract, followed by minting of shares to the receiver.
@@ -308,6 +326,48 @@ pub trait PSP22VaultInternal {
         assets: &Balance,
         shares: &Balance,
     ) -> Result<(), PSP22Error>;
+
+    /// Deposits `assets` from `caller` for `receiver` if they do not exceed `_max_deposit`
+    /// and mint at least `min_shares`. Returns the minted shares.
+    fn _deposit_checked(
+        &mut self,
+        caller: &AccountId,
+        receiver: &AccountId,
+        assets: &Balance,
+        min_shares: &Balance,
+    ) -> Result<Balance, PSP22Error>;
+
+    /// Mints `shares` for `receiver` from the assets of `caller` if they do not exceed `_max_mint`
+    /// and cost at most `max_assets`. Returns the deposited assets.
+    fn _mint_checked(
+        &mut self,
+        caller: &AccountId,
+        receiver: &AccountId,
+        shares: &Balance,
+        max_assets: &Balance,
+    ) -> Result<Balance, PSP22Error>;
+
+    /// Withdraws `assets` of `owner` to `receiver` if they do not exceed `_max_withdraw`
+    /// and burn at most `max_shares`. Returns the withdrawn assets.
+    fn _withdraw_checked(
+        &mut self,
+        caller: &AccountId,
+        receiver: &AccountId,
+        owner: &AccountId,
+        assets: &Balance,
+        max_shares: &Balance,
+    ) -> Result<Balance, PSP22Error>;
+
+    /// Redeems `shares` of `owner` for assets sent to `receiver` if they do not exceed `_max_redeem`
+    /// and return at least `min_assets`. Returns the withdrawn assets.
+    fn _redeem_checked(
+        &mut self,
+        caller: &AccountId,
+        receiver: &AccountId,
+        owner: &AccountId,
+        shares: &Balance,
+        min_assets: &Balance,
+    ) -> Result<Balance, PSP22Error>;
 }
 
 /// trait that must be implemented by exactly one storage field of a contract storage
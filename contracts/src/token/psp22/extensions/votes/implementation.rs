@@ -0,0 +1,257 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use ink::{primitives::AccountId, storage::Mapping};
+use pendzl::math::errors::MathError;
+use pendzl::traits::{DefaultEnv, StorageFieldGetter};
+
+use super::{
+    Checkpoint, DelegateChanged, DelegateVotesChanged, PSP22VotesInternal,
+    PSP22VotesStorage,
+};
+use crate::token::psp22::{Balance, PSP22Error, PSP22Internal};
+use pendzl::traits::Timestamp;
+
+#[derive(Default, Debug)]
+#[pendzl::storage_item]
+pub struct PSP22VotesData {
+    pub delegates: Mapping<AccountId, AccountId>,
+    /// checkpoints of (account, index); None account holds the total supply checkpoints.
+    pub checkpoints: Mapping<(Option<AccountId>, u32), Checkpoint>,
+    pub checkpoints_count: Mapping<Option<AccountId>, u32>,
+}
+
+impl PSP22VotesStorage for PSP22VotesData {
+    fn delegates(&self, account: &AccountId) -> Option<AccountId> {
+        self.delegates.get(account)
+    }
+
+    fn set_delegate(&mut self, account: &AccountId, delegatee: &AccountId) {
+        self.delegates.insert(account, delegatee);
+    }
+
+    fn latest_votes(&self, account: &Option<&AccountId>) -> Balance {
+        let account = account.copied();
+        match self.checkpoints_count.get(account).unwrap_or_default() {
+            0 => 0,
+            count => {
+                self.checkpoints
+                    .get((account, count - 1))
+                    .unwrap_or_default()
+                    .votes
+            }
+        }
+    }
+
+    fn past_votes(
+        &self,
+        account: &Option<&AccountId>,
+        timestamp: &Timestamp,
+    ) -> Balance {
+        let account = account.copied();
+        // binary search for the first checkpoint after timestamp
+        let mut low = 0;
+        let mut high = self.checkpoints_count.get(account).unwrap_or_default();
+        while low < high {
+            let mid = low + (high - low) / 2;
+            let checkpoint =
+                self.checkpoints.get((account, mid)).unwrap_or_default();
+            if checkpoint.timestamp > *timestamp {
+                high = mid;
+            } else {
+                low = mid + 1;
+            }
+        }
+        match high {
+            0 => 0,
+            high => {
+                self.checkpoints
+                    .get((account, high - 1))
+                    .unwrap_or_default()
+                    .votes
+            }
+        }
+    }
+
+    fn push_checkpoint(
+        &mut self,
+        account: &Option<&AccountId>,
+        timestamp: &Timestamp,
+        votes: &Balance,
+    ) -> Result<(), PSP22Error> {
+        let account = account.copied();
+        let count = self.checkpoints_count.get(account).unwrap_or_default();
+        let checkpoint = Checkpoint {
+            timestamp: *timestamp,
+            votes: *votes,
+        };
+        if count > 0 {
+            let last = self
+                .checkpoints
+                .get((account, count - 1))
+                .unwrap_or_default();
+            // there can be only one checkpoint per block
+            if last.timestamp == *timestamp {
+                self.checkpoints.insert((account, count - 1), &checkpoint);
+                return Ok(());
+            }
+        }
+        self.checkpoints.insert((account, count), &checkpoint);
+        self.checkpoints_count.insert(
+            account,
+            &(count.checked_add(1).ok_or(MathError::Overflow)?),
+        );
+        Ok(())
+    }
+}
+
+pub trait PSP22VotesDefaultImpl: PSP22VotesInternal + DefaultEnv {
+    fn delegate_default_impl(
+        &mut self,
+        delegatee: AccountId,
+    ) -> Result<(), PSP22Error> {
+        self._delegate(&Self::env().caller(), &delegatee)
+    }
+
+    fn delegates_default_impl(&self, account: AccountId) -> Option<AccountId> {
+        self._delegates(&account)
+    }
+
+    fn get_votes_default_impl(&self, account: AccountId) -> Balance {
+        self._get_votes(&account)
+    }
+
+    fn get_past_votes_default_impl(
+        &self,
+        account: AccountId,
+        timestamp: Timestamp,
+    ) -> Result<Balance, PSP22Error> {
+        self._get_past_votes(&account, &timestamp)
+    }
+
+    fn get_past_total_supply_default_impl(
+        &self,
+        timestamp: Timestamp,
+    ) -> Result<Balance, PSP22Error> {
+        self._get_past_total_supply(&timestamp)
+    }
+}
+
+pub trait PSP22VotesInternalDefaultImpl:
+    StorageFieldGetter<PSP22VotesData> + PSP22Internal + PSP22VotesInternal
+where
+    PSP22VotesData: PSP22VotesStorage,
+{
+    fn _delegates_default_impl(
+        &self,
+        account: &AccountId,
+    ) -> Option<AccountId> {
+        self.data().delegates(account)
+    }
+
+    fn _get_votes_default_impl(&self, account: &AccountId) -> Balance {
+        self.data().latest_votes(&Some(account))
+    }
+
+    fn _get_past_votes_default_impl(
+        &self,
+        account: &AccountId,
+        timestamp: &Timestamp,
+    ) -> Result<Balance, PSP22Error> {
+        if *timestamp >= Self::env().block_timestamp() {
+            return Err(PSP22Error::FutureLookup);
+        }
+        Ok(self.data().past_votes(&Some(account), timestamp))
+    }
+
+    fn _get_past_total_supply_default_impl(
+        &self,
+        timestamp: &Timestamp,
+    ) -> Result<Balance, PSP22Error> {
+        if *timestamp >= Self::env().block_timestamp() {
+            return Err(PSP22Error::FutureLookup);
+        }
+        Ok(self.data().past_votes(&None, timestamp))
+    }
+
+    fn _delegate_default_impl(
+        &mut self,
+        account: &AccountId,
+        delegatee: &AccountId,
+    ) -> Result<(), PSP22Error> {
+        let old_delegate = self._delegates(account);
+        self.data().set_delegate(account, delegatee);
+
+        Self::env().emit_event(DelegateChanged {
+            delegator: *account,
+            from_delegate: old_delegate,
+            to_delegate: Some(*delegatee),
+        });
+
+        let voting_units = self._balance_of(account);
+        self._move_delegate_votes(
+            old_delegate.as_ref(),
+            Some(delegatee),
+            &voting_units,
+        )
+    }
+
+    fn _move_delegate_votes_default_impl(
+        &mut self,
+        from: Option<&AccountId>,
+        to: Option<&AccountId>,
+        amount: &Balance,
+    ) -> Result<(), PSP22Error> {
+        if from == to || *amount == 0 {
+            return Ok(());
+        }
+        let timestamp = Self::env().block_timestamp();
+        if let Some(from) = from {
+            let previous_votes = self.data().latest_votes(&Some(from));
+            let new_votes = previous_votes
+                .checked_sub(*amount)
+                .ok_or(MathError::Underflow)?;
+            self.data()
+                .push_checkpoint(&Some(from), &timestamp, &new_votes)?;
+            Self::env().emit_event(DelegateVotesChanged {
+                delegate: *from,
+                previous_votes,
+                new_votes,
+            });
+        }
+        if let Some(to) = to {
+            let previous_votes = self.data().latest_votes(&Some(to));
+            let new_votes = previous_votes
+                .checked_add(*amount)
+                .ok_or(MathError::Overflow)?;
+            self.data()
+                .push_checkpoint(&Some(to), &timestamp, &new_votes)?;
+            Self::env().emit_event(DelegateVotesChanged {
+                delegate: *to,
+                previous_votes,
+                new_votes,
+            });
+        }
+        Ok(())
+    }
+
+    fn _update_votes_default_impl(
+        &mut self,
+        from: Option<&AccountId>,
+        to: Option<&AccountId>,
+        amount: &Balance,
+    ) -> Result<(), PSP22Error> {
+        if from.is_none() || to.is_none() {
+            let timestamp = Self::env().block_timestamp();
+            let total_supply = self._total_supply();
+            self.data()
+                .push_checkpoint(&None, &timestamp, &total_supply)?;
+        }
+        let from_delegate = from.and_then(|from| self._delegates(from));
+        let to_delegate = to.and_then(|to| self._delegates(to));
+        self._move_delegate_votes(
+            from_delegate.as_ref(),
+            to_delegate.as_ref(),
+            amount,
+        )
+    }
+}
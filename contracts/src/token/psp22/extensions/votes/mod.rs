@@ -0,0 +1,15 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+pub use crate::token::psp22::PSP22Error;
+pub use ink::primitives::AccountId;
+pub use pendzl::traits::{Balance, Timestamp};
+
+include!("votes_types.rs");
+include!("votes_events.rs");
+include!("votes_trait.rs");
+
+#[cfg(feature = "psp22_votes_impl")]
+mod implementation;
+
+#[cfg(feature = "psp22_votes_impl")]
+pub use implementation::*;
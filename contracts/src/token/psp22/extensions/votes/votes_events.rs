@@ -0,0 +1,21 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+/// Emitted when `delegator` changes its delegate from `from_delegate` to `to_delegate`.
+#[ink::event]
+pub struct DelegateChanged {
+    #[ink(topic)]
+    pub delegator: AccountId,
+    #[ink(topic)]
+    pub from_delegate: Option<AccountId>,
+    #[ink(topic)]
+    pub to_delegate: Option<AccountId>,
+}
+
+/// Emitted when the voting power of `delegate` changes from `previous_votes` to `new_votes`.
+#[ink::event]
+pub struct DelegateVotesChanged {
+    #[ink(topic)]
+    pub delegate: AccountId,
+    pub previous_votes: Balance,
+    pub new_votes: Balance,
+}
@@ -0,0 +1,140 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use ink::{contract_ref, env::DefaultEnvironment};
+
+pub type PSP22VotesRef = contract_ref!(PSP22Votes, DefaultEnvironment);
+
+/// trait extending PSP22 with delegation and historical voting power
+///
+/// Voting power of an account is the sum of balances of accounts that delegated to it.
+/// Balances that are not delegated don't count as voting power, also for their owner
+/// (an account has to delegate to itself to vote with its own balance).
+#[ink::trait_definition]
+pub trait PSP22Votes {
+    /// Delegates the voting power of the caller to `delegatee`.
+    ///
+    /// On success `DelegateChanged` and `DelegateVotesChanged` events are emitted.
+    #[ink(message)]
+    fn delegate(&mut self, delegatee: AccountId) -> Result<(), PSP22Error>;
+
+    /// Returns the delegate of `account`. None if `account` didn't delegate.
+    #[ink(message)]
+    fn delegates(&self, account: AccountId) -> Option<AccountId>;
+
+    /// Returns the current voting power of `account`.
+    #[ink(message)]
+    fn get_votes(&self, account: AccountId) -> Balance;
+
+    /// Returns the voting power of `account` at `timestamp`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FutureLookup` if `timestamp` is not in the past.
+    #[ink(message)]
+    fn get_past_votes(
+        &self,
+        account: AccountId,
+        timestamp: Timestamp,
+    ) -> Result<Balance, PSP22Error>;
+
+    /// Returns the total supply at `timestamp`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FutureLookup` if `timestamp` is not in the past.
+    #[ink(message)]
+    fn get_past_total_supply(
+        &self,
+        timestamp: Timestamp,
+    ) -> Result<Balance, PSP22Error>;
+}
+
+/// trait that must be implemented by exactly one storage field of a contract storage
+/// so the Pendzl PSP22VotesInternal and PSP22Votes implementation can be derived.
+///
+/// `account` equal to None refers to the checkpoints of the total supply.
+pub trait PSP22VotesStorage {
+    /// Retrieves the delegate of `account`.
+    fn delegates(&self, account: &AccountId) -> Option<AccountId>;
+
+    /// Sets the delegate of `account` to `delegatee`.
+    fn set_delegate(&mut self, account: &AccountId, delegatee: &AccountId);
+
+    /// Retrieves the latest voting power of `account`.
+    fn latest_votes(&self, account: &Option<&AccountId>) -> Balance;
+
+    /// Retrieves the voting power of `account` at `timestamp`.
+    fn past_votes(
+        &self,
+        account: &Option<&AccountId>,
+        timestamp: &Timestamp,
+    ) -> Balance;
+
+    /// Records that the voting power of `account` is `votes` starting from `timestamp`.
+    fn push_checkpoint(
+        &mut self,
+        account: &Option<&AccountId>,
+        timestamp: &Timestamp,
+        votes: &Balance,
+    ) -> Result<(), PSP22Error>;
+}
+
+/// trait that is derived by Pendzl PSP22Votes implementation macro assuming StorageFieldGetter<PSP22VotesStorage> is implemented
+///
+/// functions of this trait are recomended to use while writing ink::messages
+pub trait PSP22VotesInternal {
+    /// Retrieves the delegate of `account`.
+    fn _delegates(&self, account: &AccountId) -> Option<AccountId>;
+
+    /// Retrieves the current voting power of `account`.
+    fn _get_votes(&self, account: &AccountId) -> Balance;
+
+    /// Retrieves the voting power of `account` at `timestamp`.
+    ///
+    /// # Errors
+    /// Returns `FutureLookup` if `timestamp` is not in the past.
+    fn _get_past_votes(
+        &self,
+        account: &AccountId,
+        timestamp: &Timestamp,
+    ) -> Result<Balance, PSP22Error>;
+
+    /// Retrieves the total supply at `timestamp`.
+    ///
+    /// # Errors
+    /// Returns `FutureLookup` if `timestamp` is not in the past.
+    fn _get_past_total_supply(
+        &self,
+        timestamp: &Timestamp,
+    ) -> Result<Balance, PSP22Error>;
+
+    /// Delegates the voting power of `account` to `delegatee`.
+    ///
+    /// On success emits `DelegateChanged` event.
+    fn _delegate(
+        &mut self,
+        account: &AccountId,
+        delegatee: &AccountId,
+    ) -> Result<(), PSP22Error>;
+
+    /// Moves `amount` of voting power from `from` delegate to `to` delegate.
+    ///
+    /// On success emits `DelegateVotesChanged` event for each delegate.
+    fn _move_delegate_votes(
+        &mut self,
+        from: Option<&AccountId>,
+        to: Option<&AccountId>,
+        amount: &Balance,
+    ) -> Result<(), PSP22Error>;
+
+    /// Moves voting units after `amount` tokens were moved `from` `to`.
+    /// Depending if `from` is None and `to` is none operation corresponds to transfer, mint, burn.
+    ///
+    /// Called by the generated `PSP22Internal::_update` after the balances are updated.
+    fn _update_votes(
+        &mut self,
+        from: Option<&AccountId>,
+        to: Option<&AccountId>,
+        amount: &Balance,
+    ) -> Result<(), PSP22Error>;
+}
@@ -0,0 +1,14 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use scale::{Decode, Encode};
+
+/// Voting power of an account (or the total supply) starting from `timestamp`.
+#[derive(Debug, Encode, Decode, Clone, Copy, Default, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct Checkpoint {
+    pub timestamp: Timestamp,
+    pub votes: Balance,
+}
@@ -0,0 +1,111 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use ink::codegen::TraitCallBuilder;
+use ink::prelude::vec::Vec;
+use ink::primitives::AccountId;
+use ink::ToAccountId;
+use pendzl::traits::{Balance, DefaultEnv, StorageFieldGetter};
+
+use super::{PSP22WrapperInternal, PSP22WrapperStorage};
+use crate::token::psp22::{PSP22Error, PSP22Internal, PSP22Ref, PSP22};
+
+#[derive(Default, Debug)]
+#[pendzl::storage_item]
+pub struct PSP22WrapperData {
+    #[lazy]
+    pub underlying: PSP22Ref,
+}
+
+impl PSP22WrapperData {
+    pub fn new(underlying: AccountId) -> Self {
+        let mut instance: PSP22WrapperData = Default::default();
+        instance.underlying.set(&underlying.into());
+        instance
+    }
+}
+
+impl PSP22WrapperStorage for PSP22WrapperData {
+    fn underlying(&self) -> PSP22Ref {
+        self.underlying.get().unwrap()
+    }
+}
+
+pub trait PSP22WrapperDefaultImpl: PSP22WrapperInternal + DefaultEnv {
+    fn underlying_default_impl(&self) -> AccountId {
+        self._underlying().to_account_id()
+    }
+
+    fn deposit_for_default_impl(
+        &mut self,
+        account: AccountId,
+        amount: Balance,
+    ) -> Result<(), PSP22Error> {
+        self._deposit_for(&Self::env().caller(), &account, &amount)
+    }
+
+    fn withdraw_to_default_impl(
+        &mut self,
+        account: AccountId,
+        amount: Balance,
+    ) -> Result<(), PSP22Error> {
+        self._withdraw_to(&Self::env().caller(), &account, &amount)
+    }
+}
+
+pub trait PSP22WrapperInternalDefaultImpl:
+    StorageFieldGetter<PSP22WrapperData> + PSP22Internal + PSP22WrapperInternal
+where
+    PSP22WrapperData: PSP22WrapperStorage,
+{
+    fn _underlying_default_impl(&self) -> PSP22Ref {
+        self.data().underlying()
+    }
+
+    fn _deposit_for_default_impl(
+        &mut self,
+        caller: &AccountId,
+        account: &AccountId,
+        amount: &Balance,
+    ) -> Result<(), PSP22Error> {
+        self._underlying()
+            .call_mut()
+            .transfer_from(
+                *caller,
+                Self::env().account_id(),
+                *amount,
+                Vec::<u8>::new(),
+            )
+            .call_v1()
+            .invoke()?;
+        self._mint_to(account, amount)
+    }
+
+    fn _withdraw_to_default_impl(
+        &mut self,
+        caller: &AccountId,
+        account: &AccountId,
+        amount: &Balance,
+    ) -> Result<(), PSP22Error> {
+        self._burn_from(caller, amount)?;
+        self._underlying()
+            .call_mut()
+            .transfer(*account, *amount, Vec::<u8>::new())
+            .call_v1()
+            .invoke()
+    }
+
+    fn _recover_default_impl(
+        &mut self,
+        account: &AccountId,
+    ) -> Result<Balance, PSP22Error> {
+        let underlying_balance = self
+            ._underlying()
+            .call()
+            .balance_of(Self::env().account_id())
+            .call_v1()
+            .invoke();
+        let value = underlying_balance.saturating_sub(self._total_supply());
+        self._mint_to(account, &value)?;
+        Ok(value)
+    }
+}
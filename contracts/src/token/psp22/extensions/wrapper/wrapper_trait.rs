@@ -0,0 +1,86 @@
+// Copyright (c) 2012-2022 Supercolony. All Rights Reserved.
+// Copyright (c) 2023 Brushfam. All Rights Reserved.
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use ink::{contract_ref, env::DefaultEnvironment};
+
+pub type PSP22WrapperRef = contract_ref!(PSP22Wrapper, DefaultEnvironment);
+
+/// trait extending PSP22 with wrapping of an underlying PSP22 token (ERC20Wrapper analogue).
+///
+/// Wrapped tokens are minted one-to-one for the underlying tokens deposited
+/// and burned one-to-one for the underlying tokens withdrawn.
+#[ink::trait_definition]
+pub trait PSP22Wrapper {
+    /// Returns the address of the underlying token.
+    #[ink(message)]
+    fn underlying(&self) -> AccountId;
+
+    /// Transfers `amount` of underlying tokens from the caller to the wrapper
+    /// and mints `amount` wrapped tokens to `account`.
+    ///
+    /// The caller has to approve the wrapper to spend `amount` of its underlying tokens.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error returned by the underlying token's `transfer_from`.
+    #[ink(message)]
+    fn deposit_for(
+        &mut self,
+        account: AccountId,
+        amount: Balance,
+    ) -> Result<(), PSP22Error>;
+
+    /// Burns `amount` wrapped tokens from the caller and transfers `amount`
+    /// of underlying tokens to `account`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InsufficientBalance` if the caller holds less than `amount` wrapped tokens.
+    /// Returns any error returned by the underlying token's `transfer`.
+    #[ink(message)]
+    fn withdraw_to(
+        &mut self,
+        account: AccountId,
+        amount: Balance,
+    ) -> Result<(), PSP22Error>;
+}
+
+/// trait that must be implemented by exactly one storage field of a contract storage
+/// so the Pendzl PSP22WrapperInternal and PSP22Wrapper implementation can be derived.
+pub trait PSP22WrapperStorage {
+    /// Retrieves the reference to the underlying token.
+    fn underlying(&self) -> PSP22Ref;
+}
+
+/// trait that is derived by Pendzl PSP22Wrapper implementation macro assuming StorageFieldGetter<PSP22WrapperStorage> is implemented
+///
+/// functions of this trait are recomended to use while writing ink::messages
+pub trait PSP22WrapperInternal {
+    /// Returns reference to the underlying token.
+    fn _underlying(&self) -> PSP22Ref;
+
+    /// Transfers `amount` of underlying tokens from `caller` to the wrapper
+    /// and mints `amount` wrapped tokens to `account`.
+    fn _deposit_for(
+        &mut self,
+        caller: &AccountId,
+        account: &AccountId,
+        amount: &Balance,
+    ) -> Result<(), PSP22Error>;
+
+    /// Burns `amount` wrapped tokens from `caller` and transfers `amount`
+    /// of underlying tokens to `account`.
+    fn _withdraw_to(
+        &mut self,
+        caller: &AccountId,
+        account: &AccountId,
+        amount: &Balance,
+    ) -> Result<(), PSP22Error>;
+
+    /// Mints to `account` wrapped tokens for the underlying tokens held by the wrapper
+    /// that are not backing the total supply, e.g. tokens transferred to the wrapper by mistake.
+    ///
+    /// Returns the amount of minted tokens.
+    fn _recover(&mut self, account: &AccountId) -> Result<Balance, PSP22Error>;
+}
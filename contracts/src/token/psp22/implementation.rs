@@ -2,9 +2,16 @@
 // SPDX-License-Identifier: MIT
 
 use super::{
-    Approval, Balance, PSP22Error, PSP22Internal, PSP22Storage, Transfer,
+    Approval, Balance, PSP22Error, PSP22Internal, PSP22Receiver,
+    PSP22ReceiverRef, PSP22Storage, Transfer,
+};
+use ink::{
+    codegen::TraitCallBuilder,
+    env::CallFlags,
+    prelude::{string::String, vec::Vec},
+    primitives::AccountId,
+    storage::Mapping,
 };
-use ink::{prelude::vec::Vec, primitives::AccountId, storage::Mapping};
 use pendzl::math::errors::MathError;
 use pendzl::traits::{DefaultEnv, StorageFieldGetter};
 
@@ -15,6 +22,17 @@ pub struct PSP22Data {
     pub total_supply: Balance,
     pub balances: Mapping<AccountId, Balance>,
     pub allowances: Mapping<(AccountId, AccountId), Balance>,
+    #[lazy]
+    pub infinite_allowance: bool,
+}
+
+impl PSP22Data {
+    /// Creates `PSP22Data` treating allowances equal to `Balance::MAX` as infinite.
+    pub fn with_infinite_allowance() -> Self {
+        let mut instance: PSP22Data = Default::default();
+        instance.infinite_allowance.set(&true);
+        instance
+    }
 }
 
 impl PSP22Storage for PSP22Data {
@@ -76,6 +94,9 @@ impl PSP22Storage for PSP22Data {
     fn allowance(&self, owner: &AccountId, spender: &AccountId) -> Balance {
         self.allowances.get(&(*owner, *spender)).unwrap_or_default()
     }
+    fn infinite_allowance_enabled(&self) -> bool {
+        self.infinite_allowance.get_or_default()
+    }
     fn set_allowance(
         &mut self,
         owner: &AccountId,
@@ -133,10 +154,10 @@ pub trait PSP22DefaultImpl: DefaultEnv + PSP22Internal {
         &mut self,
         to: AccountId,
         value: Balance,
-        _data: Vec<u8>,
+        data: Vec<u8>,
     ) -> Result<(), PSP22Error> {
         let from = Self::env().caller();
-        self._update(Some(&from), Some(&to), &value)?;
+        self._transfer(&from, &to, &value, &data)?;
         Ok(())
     }
 
@@ -145,11 +166,11 @@ pub trait PSP22DefaultImpl: DefaultEnv + PSP22Internal {
         from: AccountId,
         to: AccountId,
         value: Balance,
-        _data: Vec<u8>,
+        data: Vec<u8>,
     ) -> Result<(), PSP22Error> {
         let caller = Self::env().caller();
         self._decrease_allowance_from_to(&from, &caller, &value)?;
-        self._update(Some(&from), Some(&to), &value)?;
+        self._transfer(&from, &to, &value, &data)?;
         Ok(())
     }
 
@@ -178,13 +199,26 @@ pub trait PSP22DefaultImpl: DefaultEnv + PSP22Internal {
         delta_value: Balance,
     ) -> Result<(), PSP22Error> {
         let owner = Self::env().caller();
+        // spending an infinite allowance doesn't decrease it, so the owner lowers it by approving
+        if self._infinite_allowance_enabled()
+            && self._allowance(&owner, &spender) == Balance::MAX
+        {
+            return self._approve(
+                &owner,
+                &spender,
+                &(Balance::MAX - delta_value),
+            );
+        }
         self._decrease_allowance_from_to(&owner, &spender, &delta_value)
     }
 }
 
-pub trait PSP22InternalDefaultImpl: StorageFieldGetter<PSP22Data>
+/// `Data` is the storage field holding balances and allowances,
+/// `PSP22Data` unless a different `PSP22Storage` backend (e.g. `PSP22RebasingData`) is used.
+pub trait PSP22InternalDefaultImpl<Data = PSP22Data>:
+    StorageFieldGetter<Data>
 where
-    PSP22Data: PSP22Storage,
+    Data: PSP22Storage,
 {
     fn _total_supply_default_impl(&self) -> Balance {
         self.data().total_supply()
@@ -233,8 +267,41 @@ where
         from: &AccountId,
         to: &AccountId,
         amount: &Balance,
+        data: &Vec<u8>,
+    ) -> Result<(), PSP22Error> {
+        self._update_default_impl(Some(from), Some(to), amount)?;
+        self._do_safe_transfer_check_default_impl(from, to, amount, data)
+    }
+
+    fn _do_safe_transfer_check_default_impl(
+        &mut self,
+        from: &AccountId,
+        to: &AccountId,
+        amount: &Balance,
+        data: &Vec<u8>,
     ) -> Result<(), PSP22Error> {
-        self._update_default_impl(Some(from), Some(to), amount)
+        // the caller is on the call stack without allowing reentry, e.g. a vault pulling in its assets
+        if !Self::env().is_contract(to) || *to == Self::env().caller() {
+            return Ok(());
+        }
+
+        let mut receiver: PSP22ReceiverRef = (*to).into();
+        let result = receiver
+            .call_mut()
+            .before_received(Self::env().caller(), *from, *amount, data.clone())
+            .call_v1()
+            .call_flags(CallFlags::ALLOW_REENTRY)
+            .try_invoke();
+
+        match result {
+            Ok(Ok(Ok(()))) => Ok(()),
+            Ok(Ok(Err(e))) => Err(e.into()),
+            // the recipient doesn't implement PSP22Receiver
+            Ok(Err(_)) => Ok(()),
+            Err(_) => Err(PSP22Error::SafeTransferCheckFailed(String::from(
+                "Error during call to receiver",
+            ))),
+        }
     }
 
     fn _mint_to_default_impl(
@@ -298,4 +365,8 @@ where
         });
         Ok(())
     }
+
+    fn _infinite_allowance_enabled_default_impl(&self) -> bool {
+        self.data().infinite_allowance_enabled()
+    }
 }
@@ -12,11 +12,41 @@ pub use implementation::*;
 
 mod extensions;
 
+#[cfg(feature = "psp22_batch")]
+pub use extensions::batch;
+#[cfg(feature = "psp22_bridgeable")]
+pub use extensions::bridgeable;
 #[cfg(feature = "psp22_burnable")]
 pub use extensions::burnable;
+#[cfg(feature = "psp22_capped")]
+pub use extensions::capped;
+#[cfg(feature = "psp22_compliance")]
+pub use extensions::compliance;
+#[cfg(feature = "psp22_expiring_allowance")]
+pub use extensions::expiring_allowance;
+#[cfg(feature = "psp22_flash_lender")]
+pub use extensions::flash_lender;
+#[cfg(feature = "psp22_lockable")]
+pub use extensions::lockable;
 #[cfg(any(feature = "psp22_metadata", feature = "psp22_vault_metadata"))]
 pub use extensions::metadata;
 #[cfg(feature = "psp22_mintable")]
 pub use extensions::mintable;
+#[cfg(feature = "psp22_minter_allowance")]
+pub use extensions::minter_allowance;
+#[cfg(feature = "psp22_native_wrapper")]
+pub use extensions::native_wrapper;
+#[cfg(feature = "psp22_permit")]
+pub use extensions::permit;
+#[cfg(feature = "psp22_rebasing")]
+pub use extensions::rebasing;
+#[cfg(feature = "psp22_snapshot")]
+pub use extensions::snapshot;
+#[cfg(feature = "psp22_transfer_fee")]
+pub use extensions::transfer_fee;
 #[cfg(feature = "psp22_vault")]
 pub use extensions::vault;
+#[cfg(feature = "psp22_votes")]
+pub use extensions::votes;
+#[cfg(feature = "psp22_wrapper")]
+pub use extensions::wrapper;
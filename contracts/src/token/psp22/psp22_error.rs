@@ -24,6 +24,84 @@ pub enum PSP22Error {
     PermitInvalidSignature,
     /// Returned if permit deadline is expired
     PermitExpired,
+    /// Returned if minting would make the total supply exceed the cap
+    CapExceeded,
+    /// Returned if votes are requested for a timestamp that is not in the past
+    FutureLookup,
+    /// Returned if snapshot id is 0 or greater than the current snapshot id
+    NonexistentSnapshot,
+    /// Returned if flash loan amount exceeds the maximum flash loan
+    FlashLoanAmountExceeded,
+    /// Returned if flash loan receiver rejects the loan
+    FlashLoanRejected(String),
+    /// Returned if transfer of native currency fails
+    NativeTransferFailed,
+    /// Returned if transfer fee is greater than 100%
+    InvalidTransferFee,
+    /// Returned if account is frozen
+    AccountFrozen,
+    /// Returned if account is not frozen
+    AccountNotFrozen,
+    /// Returned if account is not on the allow list
+    AccountNotAllowed,
+    /// Returned if lock timestamp is not in the future
+    InvalidLock,
+    /// Returned if transfer would use locked balance
+    LockedBalance,
+    /// Returned if bridge nonce was already used for the source chain
+    BridgeNonceUsed,
+    /// Returned if caller is not a minter or mint exceeds its minter allowance
+    MinterAllowanceExceeded,
+    /// Returned if transfer at `index` of a batch fails, `reason` is the debug formatted error of the transfer
+    BatchTransferFailed { index: u32, reason: String },
+    /// Returned if allowance expiry is not in the future
+    InvalidAllowanceExpiry,
+    /// Returned if spent allowance has expired
+    AllowanceExpired,
+    /// Returned if a vault fee is greater than 100%
+    InvalidVaultFee,
+    /// Returned if the native currency transferred with the call doesn't match the deposited assets
+    InvalidTransferredValue,
+    /// Returned if a vault request is fulfilled for more than is pending
+    InsufficientPendingRequest,
+    /// Returned if a vault operation is executed past its deadline
+    VaultDeadlineExpired,
+    /// Returned if deposited assets are greater than the maximum deposit
+    MaxDepositExceeded,
+    /// Returned if minted shares are greater than the maximum mint
+    MaxMintExceeded,
+    /// Returned if withdrawn assets are greater than the maximum withdraw
+    MaxWithdrawExceeded,
+    /// Returned if redeemed shares are greater than the maximum redeem
+    MaxRedeemExceeded,
+    /// Returned if a vault deposit would mint less shares than the given minimum
+    SharesBelowMin,
+    /// Returned if a vault mint would take more assets than the given maximum
+    AssetsAboveMax,
+    /// Returned if a vault withdraw would burn more shares than the given maximum
+    SharesAboveMax,
+    /// Returned if a vault redeem would send less assets than the given minimum
+    AssetsBelowMin,
+    /// Returned if a rebase would make shares worth nothing
+    InvalidRebase,
+}
+
+/// The PSP22Receiver error type. Returned by a recipient contract that rejects the transfer.
+#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum PSP22ReceiverError {
+    /// Returned if transfer is rejected.
+    TransferRejected(String),
+}
+
+impl From<PSP22ReceiverError> for PSP22Error {
+    fn from(error: PSP22ReceiverError) -> Self {
+        match error {
+            PSP22ReceiverError::TransferRejected(message) => {
+                PSP22Error::SafeTransferCheckFailed(message)
+            }
+        }
+    }
 }
 
 /// Compatibility with pendzl::math::errors::MathError.
@@ -17,8 +17,10 @@ pub use pendzl::traits::Balance;
 /// !!! Note
 /// Pendzl implementation allows to use zero address as a valid address
 /// and doen't revert ZeroAddress errors.
-/// Pendzl implementation doesn't check if the recipient is a contract
-/// and doesn't revert SafeTransferCheckFailed
+/// Pendzl implementation calls `PSP22Receiver::before_received` on contract
+/// recipients of `transfer` and `transfer_from` and reverts with
+/// SafeTransferCheckFailed if the recipient rejects the transfer.
+/// The recipient is not called when it is the caller, e.g. a contract pulling tokens in with `transfer_from`.
 #[ink::trait_definition]
 pub trait PSP22 {
     /// Returns the total token supply.
@@ -67,6 +69,7 @@ pub trait PSP22 {
     /// the the account Balance of `from`.
     /// Returns with error `ZeroSenderAddress` if sender's address is zero.
     /// Returns with error `ZeroRecipientAddress` if recipient's address is zero.
+    /// Returns with error `SafeTransferCheckFailed` if the recipient is a contract and rejected the transfer.
     #[ink(message)]
     fn transfer_from(
         &mut self,
@@ -162,6 +165,13 @@ pub trait PSP22Storage {
     /// Returns the allowance of `spender` to spend `owner`'s tokens.
     fn allowance(&self, owner: &AccountId, spender: &AccountId) -> Balance;
 
+    /// Returns true if allowances equal to `Balance::MAX` are infinite and are not decreased when spent.
+    ///
+    /// Returns false unless the storage opts in.
+    fn infinite_allowance_enabled(&self) -> bool {
+        false
+    }
+
     /// Sets the allowance of `spender` to spend `owner`'s tokens to the given `value`.
     fn set_allowance(
         &mut self,
@@ -214,17 +224,34 @@ pub trait PSP22Internal {
         amount: &Balance,
     ) -> Result<(), PSP22Error>;
 
-    /// Transfer 'amount' 'from' 'to'.
+    /// Transfer 'amount' 'from' 'to' and notifies 'to' via `_do_safe_transfer_check`.
     ///
     /// On success emits a `Transfer` event.
     ///
     /// # Errors
     /// Returns `InsufficientBalance` if 'from' doesn't have enought balance.
+    /// Returns `SafeTransferCheckFailed` if 'to' rejects the transfer.
     fn _transfer(
         &mut self,
         from: &AccountId,
         to: &AccountId,
         amount: &Balance,
+        data: &Vec<u8>,
+    ) -> Result<(), PSP22Error>;
+
+    /// Calls `PSP22Receiver::before_received` on 'to' if it is a contract other than the caller.
+    ///
+    /// The caller is skipped as calling it back would trap unless it allowed reentry.
+    /// Recipients that don't implement `PSP22Receiver` are accepted.
+    ///
+    /// # Errors
+    /// Returns `SafeTransferCheckFailed` if 'to' rejects the transfer or the call traps.
+    fn _do_safe_transfer_check(
+        &mut self,
+        from: &AccountId,
+        to: &AccountId,
+        amount: &Balance,
+        data: &Vec<u8>,
     ) -> Result<(), PSP22Error>;
 
     /// Mints 'amount' 'to'.
@@ -261,6 +288,8 @@ pub trait PSP22Internal {
     /// Decrease an allowance of `spender` to spend tokens of `owner` by `amount`.
     ///
     /// On success emits `Approval` event.
+    /// If `_infinite_allowance_enabled` returns true and the allowance is `Balance::MAX`,
+    /// the generated implementation leaves the allowance unchanged and emits no event.
     ///
     /// # Errors
     /// - Returns `InsufficientAllowance` if the current allowance is smaller than `amount`.
@@ -280,4 +309,36 @@ pub trait PSP22Internal {
         spender: &AccountId,
         amount: &Balance,
     ) -> Result<(), PSP22Error>;
+
+    /// Returns true if allowances equal to `Balance::MAX` are infinite and are not decreased when spent.
+    ///
+    /// The generated implementation returns `PSP22Storage::infinite_allowance_enabled`
+    /// and is used both by the generated `_decrease_allowance_from_to` and by `decrease_allowance`.
+    fn _infinite_allowance_enabled(&self) -> bool {
+        false
+    }
+}
+
+pub type PSP22ReceiverRef = contract_ref!(PSP22Receiver, DefaultEnvironment);
+
+/// PSP22Receiver is an interface for any contract that wants to support safe transfers from PSP22 token smart contracts.
+#[ink::trait_definition]
+pub trait PSP22Receiver {
+    /// Ensures that the smart contract allows reception of PSP22 token(s).
+    /// Returns `Ok(())` if the contract allows the reception of the token(s)
+    /// and Error `TransferRejected(String))` otherwise.
+    ///
+    /// This method will get called on every transfer to check whether the recipient in `transfer`
+    /// or `transfer_from` is a contract, and if it is, does it accept tokens.
+    /// This is done to prevent contracts from locking tokens forever.
+    ///
+    /// Returns `PSP22ReceiverError` if the contract does not accept the tokens.
+    #[ink(message)]
+    fn before_received(
+        &mut self,
+        operator: AccountId,
+        from: AccountId,
+        value: Balance,
+        data: Vec<u8>,
+    ) -> Result<(), PSP22ReceiverError>;
 }
@@ -12,8 +12,14 @@ pub type PSP34BurnableRef = contract_ref!(PSP34Burnable, DefaultEnvironment);
 pub trait PSP34Burnable {
     /// Destroys token with id equal to `id` from `account`
     ///
-    /// Caller must be approved to transfer tokens from `account`
-    /// or to transfer token with `id`
+    /// Caller must be `account`, be approved to transfer tokens from `account`
+    /// or be approved to transfer token with `id`.
+    ///
+    /// See [`PSP34Internal::_burn_from`] for burning without the approval check.
+    ///
+    /// # Errors
+    ///
+    /// Returns `NotApproved` if the caller is neither `account` nor approved.
     #[ink(message)]
     fn burn(&mut self, account: AccountId, id: Id) -> Result<(), PSP34Error>;
 }
@@ -1,15 +1,22 @@
 // Copyright (c) 2024 C Forge. All Rights Reserved.
 // SPDX-License-Identifier: MIT
 use ink::primitives::AccountId;
+use pendzl::traits::DefaultEnv;
 
 use crate::token::psp34::{Id, PSP34Error, PSP34Internal};
 
-pub trait PSP34BurnableDefaultImpl: PSP34Internal {
+pub trait PSP34BurnableDefaultImpl: PSP34Internal + DefaultEnv {
     fn burn_default_impl(
         &mut self,
         account: AccountId,
         id: Id,
     ) -> Result<(), PSP34Error> {
+        let caller = Self::env().caller();
+        if caller != account
+            && !self._allowance(&account, &caller, &Some(id.clone()))
+        {
+            return Err(PSP34Error::NotApproved);
+        }
         self._burn_from(&account, &id)
     }
 }
@@ -0,0 +1,77 @@
+// Copyright (c) 2012-2022 Supercolony. All Rights Reserved.
+// Copyright (c) 2023 Brushfam. All Rights Reserved.
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use crate::token::psp34::{Id, PSP34Error};
+use ink::{contract_ref, env::DefaultEnvironment, primitives::AccountId};
+
+pub type PSP34EnumerableRef =
+    contract_ref!(PSP34Enumerable, DefaultEnvironment);
+
+/// trait extending PSP34 with enumeration of tokens
+#[ink::trait_definition]
+pub trait PSP34Enumerable {
+    /// Returns a token `Id` owned by `owner` at a given `index` of its token list.
+    /// Use along with `balance_of` to enumerate all of `owner`'s tokens.
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    #[ink(message)]
+    fn owners_token_by_index(&self, owner: AccountId, index: u128) -> Option<Id>;
+
+    /// Returns a token `Id` at a given `index` of all the tokens stored by the contract.
+    /// Use along with `total_supply` to enumerate all tokens.
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    #[ink(message)]
+    fn token_by_index(&self, index: u128) -> Option<Id>;
+}
+
+/// trait that must be implemented by exactly one storage field of a contract storage
+/// so the Pendzl PSP34EnumerableInternal and PSP34Enumerable implementation can be derived.
+pub trait PSP34EnumerableStorage {
+    /// Retrieves the token `Id` owned by `owner` at `index`.
+    fn owners_token_by_index(
+        &self,
+        owner: &AccountId,
+        index: &u128,
+    ) -> Option<Id>;
+
+    /// Retrieves the token `Id` at `index`.
+    fn token_by_index(&self, index: &u128) -> Option<Id>;
+
+    /// Adds `id` to the token list of `owner`.
+    fn add_owner_token(
+        &mut self,
+        owner: &AccountId,
+        id: &Id,
+    ) -> Result<(), PSP34Error>;
+
+    /// Removes `id` from the token list of `owner`. Does nothing if it is not there.
+    fn remove_owner_token(
+        &mut self,
+        owner: &AccountId,
+        id: &Id,
+    ) -> Result<(), PSP34Error>;
+
+    /// Adds `id` to the list of all tokens.
+    fn add_token(&mut self, id: &Id) -> Result<(), PSP34Error>;
+
+    /// Removes `id` from the list of all tokens. Does nothing if it is not there.
+    fn remove_token(&mut self, id: &Id) -> Result<(), PSP34Error>;
+}
+
+/// trait that is derived by Pendzl PSP34Enumerable implementation macro assuming StorageFieldGetter<PSP34EnumerableStorage> is implemented
+///
+/// functions of this trait are recomended to use while writing ink::messages
+pub trait PSP34EnumerableInternal {
+    /// Updates the token lists after token `id` was moved `from` `to`.
+    /// Depending if `from` is None and `to` is none operation corresponds to transfer, mint, burn.
+    ///
+    /// Called by the generated `PSP34Internal::_update` after the ownership is updated.
+    fn _update_enumeration(
+        &mut self,
+        from: &Option<&AccountId>,
+        to: &Option<&AccountId>,
+        id: &Id,
+    ) -> Result<(), PSP34Error>;
+}
@@ -0,0 +1,9 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+include!("enumerable_trait.rs");
+
+#[cfg(feature = "psp34_enumerable_impl")]
+mod implementation;
+
+#[cfg(feature = "psp34_enumerable_impl")]
+pub use implementation::*;
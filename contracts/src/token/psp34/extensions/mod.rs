@@ -2,6 +2,8 @@
 // SPDX-License-Identifier: MIT
 #[cfg(feature = "psp34_burnable")]
 pub mod burnable;
+#[cfg(feature = "psp34_enumerable")]
+pub mod enumerable;
 #[cfg(feature = "psp34_metadata")]
 pub mod metadata;
 #[cfg(feature = "psp34_mintable")]
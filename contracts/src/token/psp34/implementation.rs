@@ -2,9 +2,16 @@
 // SPDX-License-Identifier: MIT
 
 use crate::token::psp34::{
-    Approval, Id, PSP34Error, PSP34Internal, PSP34Storage, Transfer,
+    Approval, Id, PSP34Error, PSP34Internal, PSP34Receiver, PSP34ReceiverRef,
+    PSP34Storage, Transfer,
+};
+use ink::{
+    codegen::TraitCallBuilder,
+    env::CallFlags,
+    prelude::{string::String, vec::Vec},
+    primitives::AccountId,
+    storage::Mapping,
 };
-use ink::{prelude::vec::Vec, primitives::AccountId, storage::Mapping};
 use pendzl::math::errors::MathError;
 use pendzl::traits::{DefaultEnv, StorageFieldGetter};
 
@@ -248,9 +255,10 @@ where
         from: &AccountId,
         to: &AccountId,
         id: &Id,
-        _data: &Vec<u8>,
+        data: &Vec<u8>,
     ) -> Result<(), PSP34Error> {
-        self._update_default_impl(&Some(from), &Some(to), id)
+        self._update_default_impl(&Some(from), &Some(to), id)?;
+        self._do_safe_transfer_check_default_impl(&Some(from), to, id, data)
     }
 
     fn _mint_to_default_impl(
@@ -258,7 +266,44 @@ where
         to: &AccountId,
         id: &Id,
     ) -> Result<(), PSP34Error> {
-        self._update_default_impl(&None, &Some(to), id)
+        self._update_default_impl(&None, &Some(to), id)?;
+        self._do_safe_transfer_check_default_impl(&None, to, id, &Vec::new())
+    }
+
+    fn _do_safe_transfer_check_default_impl(
+        &mut self,
+        from: &Option<&AccountId>,
+        to: &AccountId,
+        id: &Id,
+        data: &Vec<u8>,
+    ) -> Result<(), PSP34Error> {
+        // the caller is on the call stack without allowing reentry, e.g. an operator pulling in tokens
+        if !Self::env().is_contract(to) || *to == Self::env().caller() {
+            return Ok(());
+        }
+
+        let mut receiver: PSP34ReceiverRef = (*to).into();
+        let result = receiver
+            .call_mut()
+            .before_received(
+                Self::env().caller(),
+                from.copied(),
+                id.clone(),
+                data.clone(),
+            )
+            .call_v1()
+            .call_flags(CallFlags::ALLOW_REENTRY)
+            .try_invoke();
+
+        match result {
+            Ok(Ok(Ok(()))) => Ok(()),
+            Ok(Ok(Err(e))) => Err(e.into()),
+            // the recipient doesn't implement PSP34Receiver
+            Ok(Err(_)) => Ok(()),
+            Err(_) => Err(PSP34Error::SafeTransferCheckFailed(String::from(
+                "Error during call to receiver",
+            ))),
+        }
     }
 
     fn _burn_from_default_impl(
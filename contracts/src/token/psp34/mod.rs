@@ -15,6 +15,8 @@ mod extensions;
 
 #[cfg(feature = "psp34_burnable")]
 pub use extensions::burnable;
+#[cfg(feature = "psp34_enumerable")]
+pub use extensions::enumerable;
 #[cfg(feature = "psp34_metadata")]
 pub use extensions::metadata;
 #[cfg(feature = "psp34_mintable")]
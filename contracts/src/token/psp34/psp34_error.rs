@@ -22,6 +22,24 @@ pub enum PSP34Error {
     SafeTransferCheckFailed(String),
 }
 
+/// The PSP34Receiver error type. Returned by a recipient contract that rejects the token.
+#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum PSP34ReceiverError {
+    /// Returned if transfer is rejected.
+    TransferRejected(String),
+}
+
+impl From<PSP34ReceiverError> for PSP34Error {
+    fn from(error: PSP34ReceiverError) -> Self {
+        match error {
+            PSP34ReceiverError::TransferRejected(message) => {
+                PSP34Error::SafeTransferCheckFailed(message)
+            }
+        }
+    }
+}
+
 #[cfg(feature = "ownable")]
 use crate::access::ownable::OwnableError;
 #[cfg(feature = "ownable")]
@@ -2,7 +2,10 @@
 // Copyright (c) 2023 Brushfam. All Rights Reserved.
 // Copyright (c) 2024 C Forge. All Rights Reserved.
 // SPDX-License-Identifier: MIT
-use ink::{prelude::vec::Vec, primitives::AccountId};
+use ink::{
+    contract_ref, env::DefaultEnvironment, prelude::vec::Vec,
+    primitives::AccountId,
+};
 
 /// # PSP-34: Token standard
 /// https://github.com/inkdevhub/standards/blob/master/PSPs/psp-34.md
@@ -10,8 +13,10 @@ use ink::{prelude::vec::Vec, primitives::AccountId};
 /// !!! Note
 /// Pendzl implementation allows to use zero address as a valid address
 /// and doen't revert ZeroAddress errors.
-/// Pendzl implementation doesn't check if the recipient is a contract
-/// and doesn't revert SafeTransferCheckFailed
+/// Pendzl implementation calls `PSP34Receiver::before_received` on contract
+/// recipients of `transfer` and `_mint_to` and reverts with SafeTransferCheckFailed
+/// if the recipient rejects the token. Recipients that don't implement `PSP34Receiver` are accepted.
+/// The recipient is not called when it is the caller, e.g. an operator transferring a token to itself.
 /// Pendzl implementation returns 'TokenNotExists' error if token doesn't exist on approve.
 #[ink::trait_definition]
 pub trait PSP34 {
@@ -184,7 +189,7 @@ pub trait PSP34Internal {
         id: &Id,
     ) -> Result<(), PSP34Error>;
 
-    /// Internal function to transfer a token.
+    /// Internal function to transfer a token and notify `to` via `_do_safe_transfer_check`.
     /// Emits a `Transfer` event on success.
     /// # Errors
     /// - Various errors as defined in `PSP34Error`.
@@ -196,7 +201,7 @@ pub trait PSP34Internal {
         data: &Vec<u8>,
     ) -> Result<(), PSP34Error>;
 
-    /// Internal function to mint a new token.
+    /// Internal function to mint a new token and notify `to` via `_do_safe_transfer_check`.
     /// # Errors
     /// - Various errors as defined in `PSP34Error`.
     fn _mint_to(&mut self, to: &AccountId, id: &Id) -> Result<(), PSP34Error>;
@@ -209,4 +214,42 @@ pub trait PSP34Internal {
         from: &AccountId,
         id: &Id,
     ) -> Result<(), PSP34Error>;
+
+    /// Calls `PSP34Receiver::before_received` on `to` if it is a contract other than the caller.
+    /// `from` is None when the token is being minted.
+    ///
+    /// The caller is skipped as calling it back would trap unless it allowed reentry.
+    /// Recipients that don't implement `PSP34Receiver` are accepted.
+    ///
+    /// # Errors
+    /// Returns `SafeTransferCheckFailed` if `to` rejects the token or the call traps.
+    fn _do_safe_transfer_check(
+        &mut self,
+        from: &Option<&AccountId>,
+        to: &AccountId,
+        id: &Id,
+        data: &Vec<u8>,
+    ) -> Result<(), PSP34Error>;
+}
+
+pub type PSP34ReceiverRef = contract_ref!(PSP34Receiver, DefaultEnvironment);
+
+/// PSP34Receiver is an interface for any contract that wants to support safe transfers from PSP34 token smart contracts.
+#[ink::trait_definition]
+pub trait PSP34Receiver {
+    /// Ensures that the smart contract allows reception of PSP34 token.
+    /// Returns `Ok(())` if the contract allows the reception of the token
+    /// and Error `TransferRejected(String))` otherwise.
+    ///
+    /// This method will get called on every transfer or mint to a contract.
+    /// `from` is None when the token is being minted.
+    /// This is done to prevent contracts from locking tokens forever.
+    #[ink(message)]
+    fn before_received(
+        &mut self,
+        operator: AccountId,
+        from: Option<AccountId>,
+        id: Id,
+        data: Vec<u8>,
+    ) -> Result<(), PSP34ReceiverError>;
 }
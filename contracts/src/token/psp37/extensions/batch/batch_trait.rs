@@ -0,0 +1,53 @@
+// Copyright (c) 2012-2022 Supercolony. All Rights Reserved.
+// Copyright (c) 2023 Brushfam. All Rights Reserved.
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use crate::token::psp37::{Balance, Id, PSP37Error};
+use ink::{
+    contract_ref, env::DefaultEnvironment, prelude::vec::Vec,
+    primitives::AccountId,
+};
+
+pub type PSP37BatchRef = contract_ref!(PSP37Batch, DefaultEnvironment);
+
+/// trait extending PSP37 with batch transfer functionality
+#[ink::trait_definition]
+pub trait PSP37Batch {
+    /// Transfers `ids_amounts` tokens from the caller to `to`.
+    ///
+    /// On success a `TransferBatch` event is emitted
+    /// (`Transfer` if `ids_amounts` contains a single entry).
+    ///
+    /// # Errors
+    ///
+    /// Returns `InsufficientBalance` error if there are not enough tokens on
+    /// the caller's account balance.
+    /// Returns `SafeTransferCheckFailed` error if `to` doesn't accept transfer.
+    #[ink(message)]
+    fn batch_transfer(
+        &mut self,
+        to: AccountId,
+        ids_amounts: Vec<(Id, Balance)>,
+        data: Vec<u8>,
+    ) -> Result<(), PSP37Error>;
+
+    /// Transfers `ids_amounts` tokens on the behalf of `from` to the account `to`.
+    ///
+    /// On success a `TransferBatch` event is emitted
+    /// (`Transfer` if `ids_amounts` contains a single entry).
+    ///
+    /// # Errors
+    ///
+    /// Returns `NotAllowed` error if the caller doesn't have enough allowance for any of the tokens.
+    /// Returns `InsufficientBalance` error if there are not enough tokens on
+    /// the account balance of `from`.
+    /// Returns `SafeTransferCheckFailed` error if `to` doesn't accept transfer.
+    #[ink(message)]
+    fn batch_transfer_from(
+        &mut self,
+        from: AccountId,
+        to: AccountId,
+        ids_amounts: Vec<(Id, Balance)>,
+        data: Vec<u8>,
+    ) -> Result<(), PSP37Error>;
+}
@@ -0,0 +1,34 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use ink::{prelude::vec::Vec, primitives::AccountId};
+use pendzl::traits::DefaultEnv;
+
+use crate::token::psp37::{Balance, Id, PSP37Error, PSP37Internal};
+
+pub trait PSP37BatchDefaultImpl: PSP37Internal + DefaultEnv {
+    fn batch_transfer_default_impl(
+        &mut self,
+        to: AccountId,
+        ids_amounts: Vec<(Id, Balance)>,
+        data: Vec<u8>,
+    ) -> Result<(), PSP37Error> {
+        let from = Self::env().caller();
+        self._transfer(&from, &to, &ids_amounts, &data)
+    }
+
+    fn batch_transfer_from_default_impl(
+        &mut self,
+        from: AccountId,
+        to: AccountId,
+        ids_amounts: Vec<(Id, Balance)>,
+        data: Vec<u8>,
+    ) -> Result<(), PSP37Error> {
+        let caller = Self::env().caller();
+        if caller != from {
+            for (id, value) in ids_amounts.iter() {
+                self._decrease_allowance(&from, &caller, id, value)?;
+            }
+        }
+        self._transfer(&from, &to, &ids_amounts, &data)
+    }
+}
@@ -0,0 +1,9 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+include!("batch_trait.rs");
+
+#[cfg(feature = "psp37_batch_impl")]
+mod implementation;
+
+#[cfg(feature = "psp37_batch_impl")]
+pub use implementation::*;
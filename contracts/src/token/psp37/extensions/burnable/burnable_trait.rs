@@ -0,0 +1,30 @@
+// Copyright (c) 2012-2022 Supercolony. All Rights Reserved.
+// Copyright (c) 2023 Brushfam. All Rights Reserved.
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use crate::token::psp37::{Balance, Id, PSP37Error};
+use ink::{
+    contract_ref, env::DefaultEnvironment, prelude::vec::Vec,
+    primitives::AccountId,
+};
+
+pub type PSP37BurnableRef = contract_ref!(PSP37Burnable, DefaultEnvironment);
+
+/// trait extending PSP37 with burn functionality
+#[ink::trait_definition]
+pub trait PSP37Burnable {
+    /// Destroys `ids_amounts` tokens from `from`.
+    ///
+    /// Caller must be `from` or have enough allowance for every burned token.
+    ///
+    /// # Errors
+    ///
+    /// Returns `NotAllowed` error if the caller doesn't have enough allowance.
+    /// Returns `InsufficientBalance` error if `from` doesn't have enough balance.
+    #[ink(message)]
+    fn burn(
+        &mut self,
+        from: AccountId,
+        ids_amounts: Vec<(Id, Balance)>,
+    ) -> Result<(), PSP37Error>;
+}
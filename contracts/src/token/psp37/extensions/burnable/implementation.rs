@@ -0,0 +1,22 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use ink::{prelude::vec::Vec, primitives::AccountId};
+use pendzl::traits::DefaultEnv;
+
+use crate::token::psp37::{Balance, Id, PSP37Error, PSP37Internal};
+
+pub trait PSP37BurnableDefaultImpl: PSP37Internal + DefaultEnv {
+    fn burn_default_impl(
+        &mut self,
+        from: AccountId,
+        ids_amounts: Vec<(Id, Balance)>,
+    ) -> Result<(), PSP37Error> {
+        let caller = Self::env().caller();
+        if caller != from {
+            for (id, value) in ids_amounts.iter() {
+                self._decrease_allowance(&from, &caller, id, value)?;
+            }
+        }
+        self._burn_from(&from, &ids_amounts)
+    }
+}
@@ -0,0 +1,79 @@
+// Copyright (c) 2012-2022 Supercolony. All Rights Reserved.
+// Copyright (c) 2023 Brushfam. All Rights Reserved.
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use crate::token::psp37::{Balance, Id, PSP37Error};
+use ink::{
+    contract_ref, env::DefaultEnvironment, prelude::vec::Vec,
+    primitives::AccountId,
+};
+
+pub type PSP37EnumerableRef =
+    contract_ref!(PSP37Enumerable, DefaultEnvironment);
+
+/// trait extending PSP37 with enumeration of token types
+#[ink::trait_definition]
+pub trait PSP37Enumerable {
+    /// Returns the token type Id owned by `owner` at a given `index` of its token list.
+    /// Use along with `balance_of(owner, None)` to enumerate all of `owner`'s token types.
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    #[ink(message)]
+    fn owners_token_by_index(&self, owner: AccountId, index: u128) -> Option<Id>;
+
+    /// Returns the token type Id at a given `index` of all the token types in the supply.
+    /// Use along with `total_supply(None)` to enumerate all token types.
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    #[ink(message)]
+    fn token_by_index(&self, index: u128) -> Option<Id>;
+}
+
+/// trait that must be implemented by exactly one storage field of a contract storage
+/// so the Pendzl PSP37EnumerableInternal and PSP37Enumerable implementation can be derived.
+pub trait PSP37EnumerableStorage {
+    /// Retrieves the token type Id owned by `owner` at `index`.
+    fn owners_token_by_index(
+        &self,
+        owner: &AccountId,
+        index: &u128,
+    ) -> Option<Id>;
+
+    /// Retrieves the token type Id at `index`.
+    fn token_by_index(&self, index: &u128) -> Option<Id>;
+
+    /// Adds `id` to the token list of `owner`. Does nothing if it is already there.
+    fn add_owner_token(
+        &mut self,
+        owner: &AccountId,
+        id: &Id,
+    ) -> Result<(), PSP37Error>;
+
+    /// Removes `id` from the token list of `owner`. Does nothing if it is not there.
+    fn remove_owner_token(
+        &mut self,
+        owner: &AccountId,
+        id: &Id,
+    ) -> Result<(), PSP37Error>;
+
+    /// Adds `id` to the list of all token types. Does nothing if it is already there.
+    fn add_token(&mut self, id: &Id) -> Result<(), PSP37Error>;
+
+    /// Removes `id` from the list of all token types. Does nothing if it is not there.
+    fn remove_token(&mut self, id: &Id) -> Result<(), PSP37Error>;
+}
+
+/// trait that is derived by Pendzl PSP37Enumerable implementation macro assuming StorageFieldGetter<PSP37EnumerableStorage> is implemented
+///
+/// functions of this trait are recomended to use while writing ink::messages
+pub trait PSP37EnumerableInternal {
+    /// Updates the token lists after `ids_amounts` were moved `from` `to`.
+    ///
+    /// Called by the generated `PSP37Internal::_update` after balances are updated.
+    fn _update_enumeration(
+        &mut self,
+        from: &Option<&AccountId>,
+        to: &Option<&AccountId>,
+        ids_amounts: &Vec<(Id, Balance)>,
+    ) -> Result<(), PSP37Error>;
+}
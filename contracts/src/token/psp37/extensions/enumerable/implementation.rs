@@ -0,0 +1,168 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use ink::{prelude::vec::Vec, primitives::AccountId, storage::Mapping};
+use pendzl::math::errors::MathError;
+use pendzl::traits::StorageFieldGetter;
+
+use super::PSP37EnumerableStorage;
+use crate::token::psp37::{Balance, Id, PSP37Error, PSP37Internal};
+
+#[derive(Default, Debug)]
+#[pendzl::storage_item]
+pub struct PSP37EnumerableData {
+    pub owned_tokens: Mapping<(AccountId, u128), Id>,
+    pub owned_tokens_index: Mapping<(AccountId, Id), u128>,
+    pub owned_tokens_count: Mapping<AccountId, u128>,
+    pub all_tokens: Mapping<u128, Id>,
+    pub all_tokens_index: Mapping<Id, u128>,
+    #[lazy]
+    pub all_tokens_count: u128,
+}
+
+impl PSP37EnumerableStorage for PSP37EnumerableData {
+    fn owners_token_by_index(
+        &self,
+        owner: &AccountId,
+        index: &u128,
+    ) -> Option<Id> {
+        self.owned_tokens.get((*owner, *index))
+    }
+
+    fn token_by_index(&self, index: &u128) -> Option<Id> {
+        self.all_tokens.get(index)
+    }
+
+    fn add_owner_token(
+        &mut self,
+        owner: &AccountId,
+        id: &Id,
+    ) -> Result<(), PSP37Error> {
+        if self.owned_tokens_index.contains((*owner, id.clone())) {
+            return Ok(());
+        }
+        let count = self.owned_tokens_count.get(owner).unwrap_or_default();
+        self.owned_tokens.insert((*owner, count), id);
+        self.owned_tokens_index.insert((*owner, id.clone()), &count);
+        self.owned_tokens_count
+            .insert(owner, &(count.checked_add(1).ok_or(MathError::Overflow)?));
+        Ok(())
+    }
+
+    fn remove_owner_token(
+        &mut self,
+        owner: &AccountId,
+        id: &Id,
+    ) -> Result<(), PSP37Error> {
+        let index = match self.owned_tokens_index.get((*owner, id.clone())) {
+            Some(index) => index,
+            None => return Ok(()),
+        };
+        let last_index = self
+            .owned_tokens_count
+            .get(owner)
+            .unwrap_or_default()
+            .checked_sub(1)
+            .ok_or(MathError::Underflow)?;
+
+        if index != last_index {
+            let last_id = self
+                .owned_tokens
+                .get((*owner, last_index))
+                .ok_or(MathError::Underflow)?;
+            self.owned_tokens.insert((*owner, index), &last_id);
+            self.owned_tokens_index.insert((*owner, last_id), &index);
+        }
+        self.owned_tokens.remove((*owner, last_index));
+        self.owned_tokens_index.remove((*owner, id.clone()));
+        self.owned_tokens_count.insert(owner, &last_index);
+        Ok(())
+    }
+
+    fn add_token(&mut self, id: &Id) -> Result<(), PSP37Error> {
+        if self.all_tokens_index.contains(id) {
+            return Ok(());
+        }
+        let count = self.all_tokens_count.get_or_default();
+        self.all_tokens.insert(count, id);
+        self.all_tokens_index.insert(id, &count);
+        self.all_tokens_count
+            .set(&(count.checked_add(1).ok_or(MathError::Overflow)?));
+        Ok(())
+    }
+
+    fn remove_token(&mut self, id: &Id) -> Result<(), PSP37Error> {
+        let index = match self.all_tokens_index.get(id) {
+            Some(index) => index,
+            None => return Ok(()),
+        };
+        let last_index = self
+            .all_tokens_count
+            .get_or_default()
+            .checked_sub(1)
+            .ok_or(MathError::Underflow)?;
+
+        if index != last_index {
+            let last_id = self
+                .all_tokens
+                .get(last_index)
+                .ok_or(MathError::Underflow)?;
+            self.all_tokens.insert(index, &last_id);
+            self.all_tokens_index.insert(&last_id, &index);
+        }
+        self.all_tokens.remove(last_index);
+        self.all_tokens_index.remove(id);
+        self.all_tokens_count.set(&last_index);
+        Ok(())
+    }
+}
+
+pub trait PSP37EnumerableDefaultImpl:
+    StorageFieldGetter<PSP37EnumerableData>
+where
+    PSP37EnumerableData: PSP37EnumerableStorage,
+{
+    fn owners_token_by_index_default_impl(
+        &self,
+        owner: AccountId,
+        index: u128,
+    ) -> Option<Id> {
+        self.data().owners_token_by_index(&owner, &index)
+    }
+
+    fn token_by_index_default_impl(&self, index: u128) -> Option<Id> {
+        self.data().token_by_index(&index)
+    }
+}
+
+pub trait PSP37EnumerableInternalDefaultImpl:
+    StorageFieldGetter<PSP37EnumerableData> + PSP37Internal
+where
+    PSP37EnumerableData: PSP37EnumerableStorage,
+{
+    fn _update_enumeration_default_impl(
+        &mut self,
+        from: &Option<&AccountId>,
+        to: &Option<&AccountId>,
+        ids_amounts: &Vec<(Id, Balance)>,
+    ) -> Result<(), PSP37Error> {
+        for (id, _) in ids_amounts.iter() {
+            let token = Some(id.clone());
+            if let Some(from) = from {
+                if self._balance_of(from, &token) == 0 {
+                    self.data().remove_owner_token(from, id)?;
+                }
+            }
+            if let Some(to) = to {
+                if self._balance_of(to, &token) > 0 {
+                    self.data().add_owner_token(to, id)?;
+                }
+            }
+            if self._total_supply(&token) > 0 {
+                self.data().add_token(id)?;
+            } else {
+                self.data().remove_token(id)?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,9 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+include!("enumerable_trait.rs");
+
+#[cfg(feature = "psp37_enumerable_impl")]
+mod implementation;
+
+#[cfg(feature = "psp37_enumerable_impl")]
+pub use implementation::*;
@@ -0,0 +1,47 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use crate::token::psp37::Id;
+use ink::{prelude::string::String, storage::Mapping};
+use pendzl::traits::StorageFieldGetter;
+
+use super::{AttributeSet, PSP37MetadataStorage};
+use ink::prelude::string::ToString;
+
+#[derive(Default, Debug)]
+#[pendzl::storage_item]
+pub struct PSP37MetadataData {
+    pub attributes: Mapping<(Id, String), String>,
+}
+
+impl PSP37MetadataStorage for PSP37MetadataData {
+    fn set_attribute(&mut self, id: &Id, key: &str, value: &str) {
+        self.attributes
+            .insert((id.clone(), key.to_string()), &value.to_string());
+    }
+}
+
+pub trait PSP37MetadataDefaultImpl:
+    StorageFieldGetter<PSP37MetadataData>
+{
+    fn get_attribute_default_impl(
+        &self,
+        id: Id,
+        key: String,
+    ) -> Option<String> {
+        self.data().attributes.get((id, key))
+    }
+}
+
+pub trait PSP37MetadataInternalDefaultImpl:
+    StorageFieldGetter<PSP37MetadataData>
+{
+    fn _set_attribute_default_impl(&mut self, id: &Id, key: &str, value: &str) {
+        self.data().set_attribute(id, key, value);
+
+        Self::env().emit_event(AttributeSet {
+            id: id.clone(),
+            key: key.to_string(),
+            data: value.to_string(),
+        })
+    }
+}
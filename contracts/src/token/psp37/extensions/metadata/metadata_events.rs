@@ -0,0 +1,9 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+#[ink::event]
+pub struct AttributeSet {
+    #[ink(topic)]
+    id: Id,
+    key: String,
+    data: String,
+}
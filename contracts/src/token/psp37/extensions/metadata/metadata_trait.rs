@@ -0,0 +1,27 @@
+// Copyright (c) 2012-2022 Supercolony. All Rights Reserved.
+// Copyright (c) 2023 Brushfam. All Rights Reserved.
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use crate::token::psp37::Id;
+use ink::{contract_ref, env::DefaultEnvironment, prelude::string::String};
+pub type PSP37MetadataRef = contract_ref!(PSP37Metadata, DefaultEnvironment);
+
+/// trait extending PSP37 with metadata functionality
+#[ink::trait_definition]
+pub trait PSP37Metadata {
+    /// Returns the attribute of `id` for the given `key`.
+    #[ink(message)]
+    fn get_attribute(&self, id: Id, key: String) -> Option<String>;
+}
+/// trait that must be implemented by exactly one storage field of a contract storage
+/// so the Pendzl PSP37MetadataInternal and PSP37Metadata implementation can be derived.
+pub trait PSP37MetadataStorage {
+    fn set_attribute(&mut self, id: &Id, key: &str, value: &str);
+}
+
+/// trait that is derived by Pendzl PSP37Metadata implementation macro assuming StorageFieldGetter<PSP37MetadataStorage> is implemented
+///
+/// functions of this trait are recomended to use while writing ink::messages
+pub trait PSP37MetadataInternal {
+    fn _set_attribute(&mut self, id: &Id, key: &str, value: &str);
+}
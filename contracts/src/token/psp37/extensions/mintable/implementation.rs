@@ -0,0 +1,15 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use ink::{prelude::vec::Vec, primitives::AccountId};
+
+use crate::token::psp37::{Balance, Id, PSP37Error, PSP37Internal};
+
+pub trait PSP37MintableDefaultImpl: PSP37Internal {
+    fn mint_default_impl(
+        &mut self,
+        to: AccountId,
+        ids_amounts: Vec<(Id, Balance)>,
+    ) -> Result<(), PSP37Error> {
+        self._mint_to(&to, &ids_amounts)
+    }
+}
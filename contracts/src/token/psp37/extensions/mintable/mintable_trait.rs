@@ -0,0 +1,25 @@
+// Copyright (c) 2012-2022 Supercolony. All Rights Reserved.
+// Copyright (c) 2023 Brushfam. All Rights Reserved.
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use crate::token::psp37::{Balance, Id, PSP37Error};
+use ink::{
+    contract_ref, env::DefaultEnvironment, prelude::vec::Vec,
+    primitives::AccountId,
+};
+
+pub type PSP37MintableRef = contract_ref!(PSP37Mintable, DefaultEnvironment);
+
+/// trait extending PSP37 with mint functionality
+#[ink::trait_definition]
+pub trait PSP37Mintable {
+    /// Mints `ids_amounts` tokens to `to`.
+    ///
+    /// See [`PSP37Internal::_mint_to`].
+    #[ink(message)]
+    fn mint(
+        &mut self,
+        to: AccountId,
+        ids_amounts: Vec<(Id, Balance)>,
+    ) -> Result<(), PSP37Error>;
+}
@@ -0,0 +1,9 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+include!("mintable_trait.rs");
+
+#[cfg(feature = "psp37_mintable_impl")]
+mod implementation;
+
+#[cfg(feature = "psp37_mintable_impl")]
+pub use implementation::*;
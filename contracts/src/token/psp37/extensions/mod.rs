@@ -0,0 +1,12 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+#[cfg(feature = "psp37_batch")]
+pub mod batch;
+#[cfg(feature = "psp37_burnable")]
+pub mod burnable;
+#[cfg(feature = "psp37_enumerable")]
+pub mod enumerable;
+#[cfg(feature = "psp37_metadata")]
+pub mod metadata;
+#[cfg(feature = "psp37_mintable")]
+pub mod mintable;
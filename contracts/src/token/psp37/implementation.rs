@@ -0,0 +1,415 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+
+use crate::token::psp37::{
+    Approval, Balance, Id, PSP37Error, PSP37Internal, PSP37Receiver,
+    PSP37ReceiverRef, PSP37Storage, Transfer, TransferBatch,
+};
+use ink::{
+    codegen::TraitCallBuilder,
+    env::CallFlags,
+    prelude::{string::String, vec, vec::Vec},
+    primitives::AccountId,
+    storage::Mapping,
+};
+use pendzl::math::errors::MathError;
+use pendzl::traits::{DefaultEnv, StorageFieldGetter};
+
+#[derive(Default, Debug)]
+#[pendzl::storage_item]
+pub struct PSP37Data {
+    /// balances of (owner, Some(id)); (owner, None) holds the number of token types owned.
+    pub balances: Mapping<(AccountId, Option<Id>), Balance>,
+    /// supply of Some(id); None holds the number of token types in the supply.
+    pub supply: Mapping<Option<Id>, Balance>,
+    /// allowances of (owner, operator, Some(id)); (owner, operator, None) is set to Balance::MAX if approved for all.
+    pub allowances: Mapping<(AccountId, AccountId, Option<Id>), Balance>,
+}
+
+impl PSP37Storage for PSP37Data {
+    fn balance_of(&self, owner: &AccountId, id: &Option<&Id>) -> Balance {
+        self.balances.get((*owner, id.cloned())).unwrap_or_default()
+    }
+
+    fn total_supply(&self, id: &Option<&Id>) -> Balance {
+        self.supply.get(id.cloned()).unwrap_or_default()
+    }
+
+    fn allowance(
+        &self,
+        owner: &AccountId,
+        operator: &AccountId,
+        id: &Option<&Id>,
+    ) -> Balance {
+        self.allowances
+            .get((*owner, *operator, id.cloned()))
+            .unwrap_or_default()
+    }
+
+    fn set_allowance(
+        &mut self,
+        owner: &AccountId,
+        operator: &AccountId,
+        id: &Option<&Id>,
+        value: &Balance,
+    ) {
+        if *value == 0 {
+            self.allowances.remove((*owner, *operator, id.cloned()));
+        } else {
+            self.allowances
+                .insert((*owner, *operator, id.cloned()), value);
+        }
+    }
+
+    fn increase_balance_of(
+        &mut self,
+        owner: &AccountId,
+        id: &Id,
+        amount: &Balance,
+    ) -> Result<(), PSP37Error> {
+        if *amount == 0 {
+            return Ok(());
+        }
+        let balance = self.balance_of(owner, &Some(id));
+        if balance == 0 {
+            let owned_ids = self.balance_of(owner, &None);
+            self.balances.insert(
+                &(*owner, None),
+                &(owned_ids.checked_add(1).ok_or(MathError::Overflow)?),
+            );
+        }
+        self.balances.insert(
+            &(*owner, Some(id.clone())),
+            &(balance.checked_add(*amount).ok_or(MathError::Overflow)?),
+        );
+        Ok(())
+    }
+
+    fn decrease_balance_of(
+        &mut self,
+        owner: &AccountId,
+        id: &Id,
+        amount: &Balance,
+    ) -> Result<(), PSP37Error> {
+        if *amount == 0 {
+            return Ok(());
+        }
+        let new_balance = self
+            .balance_of(owner, &Some(id))
+            .checked_sub(*amount)
+            .ok_or(PSP37Error::InsufficientBalance)?;
+        if new_balance == 0 {
+            self.balances.remove((*owner, Some(id.clone())));
+            let owned_ids = self.balance_of(owner, &None);
+            self.balances.insert(
+                &(*owner, None),
+                &(owned_ids.checked_sub(1).ok_or(MathError::Underflow)?),
+            );
+        } else {
+            self.balances
+                .insert((*owner, Some(id.clone())), &new_balance);
+        }
+        Ok(())
+    }
+
+    fn increase_total_supply(
+        &mut self,
+        id: &Id,
+        amount: &Balance,
+    ) -> Result<(), PSP37Error> {
+        if *amount == 0 {
+            return Ok(());
+        }
+        let supply = self.total_supply(&Some(id));
+        if supply == 0 {
+            let ids = self.total_supply(&None);
+            self.supply.insert(
+                None::<Id>,
+                &(ids.checked_add(1).ok_or(MathError::Overflow)?),
+            );
+        }
+        self.supply.insert(
+            Some(id.clone()),
+            &(supply.checked_add(*amount).ok_or(MathError::Overflow)?),
+        );
+        Ok(())
+    }
+
+    fn decrease_total_supply(
+        &mut self,
+        id: &Id,
+        amount: &Balance,
+    ) -> Result<(), PSP37Error> {
+        if *amount == 0 {
+            return Ok(());
+        }
+        let new_supply = self
+            .total_supply(&Some(id))
+            .checked_sub(*amount)
+            .ok_or(MathError::Underflow)?;
+        if new_supply == 0 {
+            self.supply.remove(Some(id.clone()));
+            let ids = self.total_supply(&None);
+            self.supply.insert(
+                None::<Id>,
+                &(ids.checked_sub(1).ok_or(MathError::Underflow)?),
+            );
+        } else {
+            self.supply.insert(Some(id.clone()), &new_supply);
+        }
+        Ok(())
+    }
+}
+
+pub trait PSP37DefaultImpl: PSP37Internal + DefaultEnv {
+    fn balance_of_default_impl(
+        &self,
+        owner: AccountId,
+        id: Option<Id>,
+    ) -> Balance {
+        self._balance_of(&owner, &id)
+    }
+
+    fn total_supply_default_impl(&self, id: Option<Id>) -> Balance {
+        self._total_supply(&id)
+    }
+
+    fn allowance_default_impl(
+        &self,
+        owner: AccountId,
+        operator: AccountId,
+        id: Option<Id>,
+    ) -> Balance {
+        self._allowance(&owner, &operator, &id)
+    }
+
+    fn approve_default_impl(
+        &mut self,
+        operator: AccountId,
+        id: Option<Id>,
+        value: Balance,
+    ) -> Result<(), PSP37Error> {
+        let owner = Self::env().caller();
+        self._approve(&owner, &operator, &id, &value)
+    }
+
+    fn transfer_default_impl(
+        &mut self,
+        to: AccountId,
+        id: Id,
+        value: Balance,
+        data: Vec<u8>,
+    ) -> Result<(), PSP37Error> {
+        let from = Self::env().caller();
+        self._transfer(&from, &to, &vec![(id, value)], &data)
+    }
+
+    fn transfer_from_default_impl(
+        &mut self,
+        from: AccountId,
+        to: AccountId,
+        id: Id,
+        value: Balance,
+        data: Vec<u8>,
+    ) -> Result<(), PSP37Error> {
+        let caller = Self::env().caller();
+        if caller != from {
+            self._decrease_allowance(&from, &caller, &id, &value)?;
+        }
+        self._transfer(&from, &to, &vec![(id, value)], &data)
+    }
+}
+
+pub trait PSP37InternalDefaultImpl: StorageFieldGetter<PSP37Data>
+where
+    PSP37Data: PSP37Storage,
+{
+    fn _balance_of_default_impl(
+        &self,
+        owner: &AccountId,
+        id: &Option<Id>,
+    ) -> Balance {
+        self.data().balance_of(owner, &id.as_ref())
+    }
+
+    fn _total_supply_default_impl(&self, id: &Option<Id>) -> Balance {
+        self.data().total_supply(&id.as_ref())
+    }
+
+    fn _allowance_default_impl(
+        &self,
+        owner: &AccountId,
+        operator: &AccountId,
+        id: &Option<Id>,
+    ) -> Balance {
+        let approved_for_all = self.data().allowance(owner, operator, &None);
+        if approved_for_all > 0 || id.is_none() {
+            return approved_for_all;
+        }
+        self.data().allowance(owner, operator, &id.as_ref())
+    }
+
+    fn _approve_default_impl(
+        &mut self,
+        owner: &AccountId,
+        operator: &AccountId,
+        id: &Option<Id>,
+        value: &Balance,
+    ) -> Result<(), PSP37Error> {
+        if owner == operator {
+            return Err(PSP37Error::SelfApprove);
+        }
+        let value = match id {
+            None if *value > 0 => Balance::MAX,
+            _ => *value,
+        };
+        self.data()
+            .set_allowance(owner, operator, &id.as_ref(), &value);
+
+        Self::env().emit_event(Approval {
+            owner: *owner,
+            operator: *operator,
+            id: id.clone(),
+            value,
+        });
+        Ok(())
+    }
+
+    fn _decrease_allowance_default_impl(
+        &mut self,
+        owner: &AccountId,
+        operator: &AccountId,
+        id: &Id,
+        value: &Balance,
+    ) -> Result<(), PSP37Error> {
+        if self.data().allowance(owner, operator, &None) > 0 {
+            return Ok(());
+        }
+        let new_allowance = self
+            .data()
+            .allowance(owner, operator, &Some(id))
+            .checked_sub(*value)
+            .ok_or(PSP37Error::NotAllowed)?;
+        self.data()
+            .set_allowance(owner, operator, &Some(id), &new_allowance);
+
+        Self::env().emit_event(Approval {
+            owner: *owner,
+            operator: *operator,
+            id: Some(id.clone()),
+            value: new_allowance,
+        });
+        Ok(())
+    }
+
+    fn _update_default_impl(
+        &mut self,
+        from: &Option<&AccountId>,
+        to: &Option<&AccountId>,
+        ids_amounts: &Vec<(Id, Balance)>,
+    ) -> Result<(), PSP37Error> {
+        for (id, amount) in ids_amounts.iter() {
+            if let Some(from) = from {
+                self.data().decrease_balance_of(from, id, amount)?;
+            } else {
+                self.data().increase_total_supply(id, amount)?;
+            }
+
+            if let Some(to) = to {
+                self.data().increase_balance_of(to, id, amount)?;
+            } else {
+                self.data().decrease_total_supply(id, amount)?;
+            }
+        }
+
+        if let [(id, value)] = ids_amounts.as_slice() {
+            Self::env().emit_event(Transfer {
+                from: from.copied(),
+                to: to.copied(),
+                id: id.clone(),
+                value: *value,
+            });
+        } else {
+            Self::env().emit_event(TransferBatch {
+                from: from.copied(),
+                to: to.copied(),
+                ids_amounts: ids_amounts.clone(),
+            });
+        }
+        Ok(())
+    }
+
+    fn _transfer_default_impl(
+        &mut self,
+        from: &AccountId,
+        to: &AccountId,
+        ids_amounts: &Vec<(Id, Balance)>,
+        data: &Vec<u8>,
+    ) -> Result<(), PSP37Error> {
+        self._update_default_impl(&Some(from), &Some(to), ids_amounts)?;
+        self._do_safe_transfer_check_default_impl(
+            &Some(from),
+            to,
+            ids_amounts,
+            data,
+        )
+    }
+
+    fn _mint_to_default_impl(
+        &mut self,
+        to: &AccountId,
+        ids_amounts: &Vec<(Id, Balance)>,
+    ) -> Result<(), PSP37Error> {
+        self._update_default_impl(&None, &Some(to), ids_amounts)?;
+        self._do_safe_transfer_check_default_impl(
+            &None,
+            to,
+            ids_amounts,
+            &Vec::new(),
+        )
+    }
+
+    fn _burn_from_default_impl(
+        &mut self,
+        from: &AccountId,
+        ids_amounts: &Vec<(Id, Balance)>,
+    ) -> Result<(), PSP37Error> {
+        self._update_default_impl(&Some(from), &None, ids_amounts)
+    }
+
+    fn _do_safe_transfer_check_default_impl(
+        &mut self,
+        from: &Option<&AccountId>,
+        to: &AccountId,
+        ids_amounts: &Vec<(Id, Balance)>,
+        data: &Vec<u8>,
+    ) -> Result<(), PSP37Error> {
+        // the caller is on the call stack without allowing reentry, e.g. an operator pulling in tokens
+        if !Self::env().is_contract(to) || *to == Self::env().caller() {
+            return Ok(());
+        }
+
+        let mut receiver: PSP37ReceiverRef = (*to).into();
+        let result = receiver
+            .call_mut()
+            .before_received(
+                Self::env().caller(),
+                from.copied(),
+                ids_amounts.clone(),
+                data.clone(),
+            )
+            .call_v1()
+            .call_flags(CallFlags::ALLOW_REENTRY)
+            .try_invoke();
+
+        match result {
+            Ok(Ok(Ok(()))) => Ok(()),
+            Ok(Ok(Err(e))) => Err(e.into()),
+            // the recipient doesn't implement PSP37Receiver
+            Ok(Err(_)) => Ok(()),
+            Err(_) => Err(PSP37Error::SafeTransferCheckFailed(String::from(
+                "Error during call to receiver",
+            ))),
+        }
+    }
+}
@@ -0,0 +1,25 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+include!("psp37_types.rs");
+include!("psp37_error.rs");
+include!("psp37_events.rs");
+include!("psp37_trait.rs");
+
+#[cfg(feature = "psp37_impl")]
+mod implementation;
+
+#[cfg(feature = "psp37_impl")]
+pub use implementation::*;
+
+mod extensions;
+
+#[cfg(feature = "psp37_batch")]
+pub use extensions::batch;
+#[cfg(feature = "psp37_burnable")]
+pub use extensions::burnable;
+#[cfg(feature = "psp37_enumerable")]
+pub use extensions::enumerable;
+#[cfg(feature = "psp37_metadata")]
+pub use extensions::metadata;
+#[cfg(feature = "psp37_mintable")]
+pub use extensions::mintable;
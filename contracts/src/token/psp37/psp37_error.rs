@@ -0,0 +1,107 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+
+use pendzl::math::errors::MathError;
+use pendzl::traits::String;
+
+/// The PSP37 error type. Contract will throw one of this errors.
+#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum PSP37Error {
+    /// Custom error type for cases if writer of traits added own restrictions
+    Custom(String),
+    /// Returned if not enough balance to fulfill a request is available.
+    InsufficientBalance,
+    /// Returned if the caller doesn't have enough allowance.
+    NotAllowed,
+    /// Returned if owner approves self
+    SelfApprove,
+    /// Returned if safe transfer check fails
+    SafeTransferCheckFailed(String),
+}
+
+/// The PSP37Receiver error type. Returned by a recipient contract that rejects the tokens.
+#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum PSP37ReceiverError {
+    /// Returned if transfer is rejected.
+    TransferRejected(String),
+}
+
+impl From<PSP37ReceiverError> for PSP37Error {
+    fn from(error: PSP37ReceiverError) -> Self {
+        match error {
+            PSP37ReceiverError::TransferRejected(message) => {
+                PSP37Error::SafeTransferCheckFailed(message)
+            }
+        }
+    }
+}
+
+/// Compatibility with pendzl::math::errors::MathError.
+impl From<MathError> for PSP37Error {
+    fn from(err: MathError) -> Self {
+        match err {
+            MathError::Overflow => {
+                PSP37Error::Custom(String::from("M::Overflow"))
+            }
+            MathError::Underflow => {
+                PSP37Error::Custom(String::from("M::Underflow"))
+            }
+            MathError::DivByZero => {
+                PSP37Error::Custom(String::from("M::DivByZero"))
+            }
+        }
+    }
+}
+
+/// Compatibility with Ownable Errors.
+#[cfg(feature = "ownable")]
+use crate::access::ownable::OwnableError;
+#[cfg(feature = "ownable")]
+impl From<OwnableError> for PSP37Error {
+    fn from(ownable: OwnableError) -> Self {
+        match ownable {
+            OwnableError::CallerIsNotOwner => {
+                PSP37Error::Custom(String::from("O::CallerIsNotOwner"))
+            }
+        }
+    }
+}
+
+/// Compatibility with AccessControl Errors.
+#[cfg(feature = "access_control")]
+use crate::access::access_control::AccessControlError;
+#[cfg(feature = "access_control")]
+impl From<AccessControlError> for PSP37Error {
+    fn from(access: AccessControlError) -> Self {
+        match access {
+            AccessControlError::MissingRole => {
+                PSP37Error::Custom(String::from("AC::MissingRole"))
+            }
+            AccessControlError::RoleRedundant => {
+                PSP37Error::Custom(String::from("AC::RoleRedundant"))
+            }
+            AccessControlError::InvalidCaller => {
+                PSP37Error::Custom(String::from("AC::InvalidCaller"))
+            }
+        }
+    }
+}
+
+/// Compatibility with Pausable Errors.
+#[cfg(feature = "pausable")]
+use crate::security::pausable::PausableError;
+#[cfg(feature = "pausable")]
+impl From<PausableError> for PSP37Error {
+    fn from(pausable: PausableError) -> Self {
+        match pausable {
+            PausableError::Paused => {
+                PSP37Error::Custom(String::from("P::Paused"))
+            }
+            PausableError::NotPaused => {
+                PSP37Error::Custom(String::from("P::NotPaused"))
+            }
+        }
+    }
+}
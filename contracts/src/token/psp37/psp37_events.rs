@@ -0,0 +1,39 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+
+/// Emitted when a single token type is transferred.
+#[ink::event]
+pub struct Transfer {
+    /// The account from which the tokens are transferred. `None` for minting.
+    pub from: Option<AccountId>,
+    /// The account to which the tokens are transferred. `None` for burning.
+    pub to: Option<AccountId>,
+    /// The Id of the token type being transferred.
+    pub id: Id,
+    /// The amount of tokens being transferred.
+    pub value: Balance,
+}
+
+/// Emitted when multiple token types are transferred at once.
+#[ink::event]
+pub struct TransferBatch {
+    /// The account from which the tokens are transferred. `None` for minting.
+    pub from: Option<AccountId>,
+    /// The account to which the tokens are transferred. `None` for burning.
+    pub to: Option<AccountId>,
+    /// The Ids of the token types and amounts being transferred.
+    pub ids_amounts: Vec<(Id, Balance)>,
+}
+
+/// Emitted when an allowance is set.
+#[ink::event]
+pub struct Approval {
+    /// The account granting the allowance.
+    pub owner: AccountId,
+    /// The account being approved.
+    pub operator: AccountId,
+    /// The Id of the token type for specific approval. `None` for approval of all token types.
+    pub id: Option<Id>,
+    /// The new allowance.
+    pub value: Balance,
+}
@@ -0,0 +1,307 @@
+// Copyright (c) 2012-2022 Supercolony. All Rights Reserved.
+// Copyright (c) 2023 Brushfam. All Rights Reserved.
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use ink::{
+    contract_ref, env::DefaultEnvironment, prelude::vec::Vec,
+    primitives::AccountId,
+};
+pub type PSP37Ref = contract_ref!(PSP37, DefaultEnvironment);
+
+pub use pendzl::traits::Balance;
+
+/// # PSP-37: Multi Token standard
+/// https://github.com/inkdevhub/standards/blob/master/PSPs/psp-37.md
+///
+/// !!! Note
+/// Pendzl implementation allows to use zero address as a valid address
+/// and doen't revert ZeroAddress errors.
+/// Pendzl implementation calls `PSP37Receiver::before_received` on contract
+/// recipients of transfers and mints and reverts with SafeTransferCheckFailed
+/// if the recipient rejects the tokens. Recipients that don't implement `PSP37Receiver` are accepted.
+/// The recipient is not called when it is the caller, e.g. an operator transferring tokens to itself.
+#[ink::trait_definition]
+pub trait PSP37 {
+    /// Returns the amount of tokens of token type `id` owned by `owner`.
+    ///
+    /// If `id` is `None` returns the number of different token types owned by `owner`.
+    #[ink(message)]
+    fn balance_of(&self, owner: AccountId, id: Option<Id>) -> Balance;
+
+    /// Returns the total amount of token type `id` in the supply.
+    ///
+    /// If `id` is `None` returns the number of different token types in the supply.
+    #[ink(message)]
+    fn total_supply(&self, id: Option<Id>) -> Balance;
+
+    /// Returns amount of `id` token of `owner` that `operator` can withdraw.
+    ///
+    /// If `id` is `None` returns `Balance::MAX` if `operator` is approved for all
+    /// tokens of `owner` and 0 otherwise.
+    #[ink(message)]
+    fn allowance(
+        &self,
+        owner: AccountId,
+        operator: AccountId,
+        id: Option<Id>,
+    ) -> Balance;
+
+    /// Allows `operator` to withdraw the `id` token from the caller's account
+    /// multiple times, up to the `value` amount.
+    /// If this function is called again it overwrites the current allowance with `value`.
+    /// If `id` is `None` approves (`value` > 0) or disapproves (`value` == 0)
+    /// the operator for all tokens of the caller.
+    ///
+    /// An `Approval` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SelfApprove` error if it is self approve.
+    #[ink(message)]
+    fn approve(
+        &mut self,
+        operator: AccountId,
+        id: Option<Id>,
+        value: Balance,
+    ) -> Result<(), PSP37Error>;
+
+    /// Transfers `value` of `id` token from the caller to `to`.
+    ///
+    /// On success a `Transfer` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InsufficientBalance` error if there are not enough tokens on
+    /// the caller's account balance.
+    /// Returns `SafeTransferCheckFailed` error if `to` doesn't accept transfer.
+    #[ink(message)]
+    fn transfer(
+        &mut self,
+        to: AccountId,
+        id: Id,
+        value: Balance,
+        data: Vec<u8>,
+    ) -> Result<(), PSP37Error>;
+
+    /// Transfers `value` of `id` token on the behalf of `from` to the account `to`.
+    ///
+    /// On success a `Transfer` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns `NotAllowed` error if the caller doesn't have enough allowance.
+    /// Returns `InsufficientBalance` error if there are not enough tokens on
+    /// the account balance of `from`.
+    /// Returns `SafeTransferCheckFailed` error if `to` doesn't accept transfer.
+    #[ink(message)]
+    fn transfer_from(
+        &mut self,
+        from: AccountId,
+        to: AccountId,
+        id: Id,
+        value: Balance,
+        data: Vec<u8>,
+    ) -> Result<(), PSP37Error>;
+}
+
+/// trait that must be implemented by exactly one storage field of a contract storage
+/// so the Pendzl PSP37Internal and PSP37 implementation can be derived.
+pub trait PSP37Storage {
+    /// Retrieves the balance of `id` token of `owner`.
+    /// If `id` is `None` retrieves the number of different token types owned by `owner`.
+    fn balance_of(&self, owner: &AccountId, id: &Option<&Id>) -> Balance;
+
+    /// Retrieves the total supply of `id` token.
+    /// If `id` is `None` retrieves the number of different token types in the supply.
+    fn total_supply(&self, id: &Option<&Id>) -> Balance;
+
+    /// Retrieves the allowance of `operator` for `id` token of `owner`.
+    /// If `id` is `None` retrieves the allowance for all token types.
+    fn allowance(
+        &self,
+        owner: &AccountId,
+        operator: &AccountId,
+        id: &Option<&Id>,
+    ) -> Balance;
+
+    /// Sets the allowance of `operator` for `id` token of `owner` to `value`.
+    fn set_allowance(
+        &mut self,
+        owner: &AccountId,
+        operator: &AccountId,
+        id: &Option<&Id>,
+        value: &Balance,
+    );
+
+    /// Increases the balance of `id` token of `owner` by `amount`.
+    fn increase_balance_of(
+        &mut self,
+        owner: &AccountId,
+        id: &Id,
+        amount: &Balance,
+    ) -> Result<(), PSP37Error>;
+
+    /// Decreases the balance of `id` token of `owner` by `amount`.
+    ///
+    /// # Errors
+    /// Returns `InsufficientBalance` if `owner` doesn't have enough balance.
+    fn decrease_balance_of(
+        &mut self,
+        owner: &AccountId,
+        id: &Id,
+        amount: &Balance,
+    ) -> Result<(), PSP37Error>;
+
+    /// Increases the total supply of `id` token by `amount`.
+    fn increase_total_supply(
+        &mut self,
+        id: &Id,
+        amount: &Balance,
+    ) -> Result<(), PSP37Error>;
+
+    /// Decreases the total supply of `id` token by `amount`.
+    fn decrease_total_supply(
+        &mut self,
+        id: &Id,
+        amount: &Balance,
+    ) -> Result<(), PSP37Error>;
+}
+
+/// trait that is derived by Pendzl PSP37 implementation macro assuming StorageFieldGetter<PSP37Storage> is implemented
+///
+/// functions of this trait are recomended to use while writing ink::messages
+pub trait PSP37Internal {
+    /// Retrieves the balance of `id` token of `owner`.
+    /// If `id` is `None` retrieves the number of different token types owned by `owner`.
+    fn _balance_of(&self, owner: &AccountId, id: &Option<Id>) -> Balance;
+
+    /// Retrieves the total supply of `id` token.
+    /// If `id` is `None` retrieves the number of different token types in the supply.
+    fn _total_supply(&self, id: &Option<Id>) -> Balance;
+
+    /// Retrieves the allowance of `operator` for `id` token of `owner`.
+    /// Returns `Balance::MAX` if `operator` is approved for all tokens of `owner`.
+    fn _allowance(
+        &self,
+        owner: &AccountId,
+        operator: &AccountId,
+        id: &Option<Id>,
+    ) -> Balance;
+
+    /// Sets the allowance of `operator` for `id` token of `owner` to `value`.
+    /// If `id` is `None` approves (`value` > 0) or disapproves (`value` == 0)
+    /// `operator` for all tokens of `owner`.
+    ///
+    /// On success emits `Approval` event.
+    ///
+    /// # Errors
+    /// Returns `SelfApprove` if `owner` is `operator`.
+    fn _approve(
+        &mut self,
+        owner: &AccountId,
+        operator: &AccountId,
+        id: &Option<Id>,
+        value: &Balance,
+    ) -> Result<(), PSP37Error>;
+
+    /// Decreases the allowance of `operator` for `id` token of `owner` by `value`.
+    /// Does nothing if `operator` is approved for all tokens of `owner`.
+    ///
+    /// On success emits `Approval` event.
+    ///
+    /// # Errors
+    /// Returns `NotAllowed` if the allowance is lower than `value`.
+    fn _decrease_allowance(
+        &mut self,
+        owner: &AccountId,
+        operator: &AccountId,
+        id: &Id,
+        value: &Balance,
+    ) -> Result<(), PSP37Error>;
+
+    /// Moves `ids_amounts` tokens `from` `to`.
+    /// Depending if `from` is None and `to` is none operation corresponds to transfer, mint, burn.
+    ///
+    /// On success emits `Transfer` event if a single token type is moved
+    /// and `TransferBatch` event otherwise.
+    ///
+    /// # Errors
+    /// Returns `InsufficientBalance` if `from` doesn't have enough balance.
+    fn _update(
+        &mut self,
+        from: &Option<&AccountId>,
+        to: &Option<&AccountId>,
+        ids_amounts: &Vec<(Id, Balance)>,
+    ) -> Result<(), PSP37Error>;
+
+    /// Transfers `ids_amounts` tokens `from` `to` and notifies `to` via `_do_safe_transfer_check`.
+    ///
+    /// # Errors
+    /// Returns `InsufficientBalance` if `from` doesn't have enough balance.
+    /// Returns `SafeTransferCheckFailed` if `to` rejects the tokens.
+    fn _transfer(
+        &mut self,
+        from: &AccountId,
+        to: &AccountId,
+        ids_amounts: &Vec<(Id, Balance)>,
+        data: &Vec<u8>,
+    ) -> Result<(), PSP37Error>;
+
+    /// Mints `ids_amounts` tokens `to` and notifies `to` via `_do_safe_transfer_check`.
+    ///
+    /// # Errors
+    /// Returns `SafeTransferCheckFailed` if `to` rejects the tokens.
+    fn _mint_to(
+        &mut self,
+        to: &AccountId,
+        ids_amounts: &Vec<(Id, Balance)>,
+    ) -> Result<(), PSP37Error>;
+
+    /// Burns `ids_amounts` tokens `from`.
+    ///
+    /// # Errors
+    /// Returns `InsufficientBalance` if `from` doesn't have enough balance.
+    fn _burn_from(
+        &mut self,
+        from: &AccountId,
+        ids_amounts: &Vec<(Id, Balance)>,
+    ) -> Result<(), PSP37Error>;
+
+    /// Calls `PSP37Receiver::before_received` on `to` if it is a contract other than the caller.
+    /// `from` is None when the tokens are being minted.
+    ///
+    /// The caller is skipped as calling it back would trap unless it allowed reentry.
+    /// Recipients that don't implement `PSP37Receiver` are accepted.
+    ///
+    /// # Errors
+    /// Returns `SafeTransferCheckFailed` if `to` rejects the tokens or the call traps.
+    fn _do_safe_transfer_check(
+        &mut self,
+        from: &Option<&AccountId>,
+        to: &AccountId,
+        ids_amounts: &Vec<(Id, Balance)>,
+        data: &Vec<u8>,
+    ) -> Result<(), PSP37Error>;
+}
+
+pub type PSP37ReceiverRef = contract_ref!(PSP37Receiver, DefaultEnvironment);
+
+/// PSP37Receiver is an interface for any contract that wants to support safe transfers from PSP37 token smart contracts.
+#[ink::trait_definition]
+pub trait PSP37Receiver {
+    /// Ensures that the smart contract allows reception of PSP37 tokens.
+    /// Returns `Ok(())` if the contract allows the reception of the tokens
+    /// and Error `TransferRejected(String))` otherwise.
+    ///
+    /// This method will get called on every transfer or mint to a contract.
+    /// `from` is None when the tokens are being minted.
+    /// This is done to prevent contracts from locking tokens forever.
+    #[ink(message)]
+    fn before_received(
+        &mut self,
+        operator: AccountId,
+        from: Option<AccountId>,
+        ids_amounts: Vec<(Id, Balance)>,
+        data: Vec<u8>,
+    ) -> Result<(), PSP37ReceiverError>;
+}
@@ -0,0 +1,249 @@
+// SPDX-License-Identifier: MIT
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+/// Contract Summary:
+/// A PSP22 contract with batch extension.
+// ########################################################
+// inject PSP22 trait's default implementation (PSP22DefaultImpl & PSP22InternalDefaultImpl)
+// and PSP22Batch trait's default implementation (PSP22BatchDefaultImpl & PSP22BatchInternalDefaultImpl)
+// which reduces the amount of boilerplate code required to implement trait messages drastically
+#[pendzl::implementation(PSP22, PSP22Batch)]
+#[ink::contract]
+pub mod my_psp22_batch {
+    use pendzl::contracts::psp22::*;
+    #[ink(storage)]
+    #[derive(Default, StorageFieldGetter)]
+    pub struct Contract {
+        #[storage_field]
+        psp22: PSP22Data,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new(total_supply: Balance) -> Self {
+            let mut instance = Self::default();
+
+            instance
+                ._mint_to(&Self::env().caller(), &total_supply)
+                .expect("Should mint");
+
+            instance
+        }
+    }
+
+    #[cfg(all(test, feature = "e2e-tests"))]
+    pub mod tests {
+        #[rustfmt::skip]
+        use super::*;
+        #[rustfmt::skip]
+        use ink_e2e::ContractsBackend;
+        use ink_e2e::account_id;
+        use ink_e2e::AccountKeyring::{Alice, Bob, Charlie, Dave};
+        use test_helpers::balance_of;
+
+        type E2EResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+        #[ink_e2e::test]
+        async fn batch_transfer_works(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(1000);
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_batch",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let batch_tx = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.batch_transfer(
+                        vec![
+                            (account_id(Bob), 100),
+                            (account_id(Charlie), 200),
+                        ],
+                        vec![],
+                    ),
+                )
+                .submit()
+                .await
+                .expect("batch_transfer failed")
+                .return_value();
+
+            assert_eq!(batch_tx, Ok(()));
+            assert!(matches!(balance_of!(client, contract, Alice), 700));
+            assert!(matches!(balance_of!(client, contract, Bob), 100));
+            assert!(matches!(balance_of!(client, contract, Charlie), 200));
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn batch_transfer_reports_failing_index(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(1000);
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_batch",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let batch_tx = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.batch_transfer(
+                        vec![
+                            (account_id(Bob), 600),
+                            (account_id(Charlie), 300),
+                            (account_id(Dave), 200),
+                        ],
+                        vec![],
+                    ),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(
+                batch_tx,
+                Err(PSP22Error::BatchTransferFailed {
+                    index: 2,
+                    reason: "InsufficientBalance".to_string()
+                })
+            );
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn batch_transfer_from_checks_summed_allowance(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(1000);
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_batch",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.approve(account_id(Bob), 300),
+                )
+                .submit()
+                .await
+                .expect("approve failed");
+
+            let batch_tx = client
+                .call(
+                    &ink_e2e::bob(),
+                    &contract.batch_transfer_from(
+                        account_id(Alice),
+                        vec![
+                            (account_id(Charlie), 200),
+                            (account_id(Dave), 101),
+                        ],
+                    ),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(batch_tx, Err(PSP22Error::InsufficientAllowance));
+
+            let batch_tx = client
+                .call(
+                    &ink_e2e::bob(),
+                    &contract.batch_transfer_from(
+                        account_id(Alice),
+                        vec![
+                            (account_id(Charlie), 200),
+                            (account_id(Dave), 100),
+                        ],
+                    ),
+                )
+                .submit()
+                .await
+                .expect("batch_transfer_from failed")
+                .return_value();
+
+            assert_eq!(batch_tx, Ok(()));
+            assert!(matches!(balance_of!(client, contract, Alice), 700));
+            assert!(matches!(balance_of!(client, contract, Charlie), 200));
+            assert!(matches!(balance_of!(client, contract, Dave), 100));
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn batch_approve_works(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(1000);
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_batch",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let batch_tx = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.batch_approve(vec![
+                        (account_id(Bob), 100),
+                        (account_id(Charlie), 200),
+                    ]),
+                )
+                .submit()
+                .await
+                .expect("batch_approve failed")
+                .return_value();
+
+            assert_eq!(batch_tx, Ok(()));
+
+            let bob_allowance = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.allowance(account_id(Alice), account_id(Bob)),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+            let charlie_allowance = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.allowance(account_id(Alice), account_id(Charlie)),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(bob_allowance, 100);
+            assert_eq!(charlie_allowance, 200);
+
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,221 @@
+// SPDX-License-Identifier: MIT
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+/// Contract Summary:
+/// A PSP22 contract with bridgeable extension and access control module.
+/// A creator of the contract becomes a DEFAULT_ADMIN and BRIDGE_RELAYER.
+/// BRIDGE_RELAYER role is required to mint and burn bridged tokens.
+// ########################################################
+// inject PSP22 trait's default implementation (PSP22DefaultImpl & PSP22InternalDefaultImpl)
+// and PSP22Bridgeable trait's default implementation (PSP22BridgeableDefaultImpl & PSP22BridgeableInternalDefaultImpl)
+// and AccessControl trait's default implementation (AccessControlDefaultImpl & AccessControlInternalDefaultImpl)
+// which reduces the amount of boilerplate code required to implement trait messages drastically
+#[pendzl::implementation(PSP22, PSP22Bridgeable, AccessControl)]
+#[ink::contract]
+pub mod my_psp22_bridgeable {
+    use pendzl::contracts::psp22::*;
+    #[ink(storage)]
+    #[derive(Default, StorageFieldGetter)]
+    pub struct Contract {
+        #[storage_field]
+        psp22: PSP22Data,
+        // PSP22BridgeableData is a struct that implements PSP22BridgeableStorage - required by PSP22BridgeableInternalDefaultImpl trait
+        #[storage_field]
+        bridgeable: PSP22BridgeableData,
+        #[storage_field]
+        access: AccessControlData,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            let mut instance = Self::default();
+
+            let caller = Self::env().caller();
+            instance
+                ._grant_role(Self::_default_admin(), Some(caller))
+                .expect("Should grant DEFAULT_ADMIN role");
+            instance
+                ._grant_role(BRIDGE_RELAYER, Some(caller))
+                .expect("Should grant BRIDGE_RELAYER role");
+
+            instance
+        }
+    }
+
+    #[cfg(all(test, feature = "e2e-tests"))]
+    pub mod tests {
+        #[rustfmt::skip]
+        use super::*;
+        #[rustfmt::skip]
+        use ink_e2e::ContractsBackend;
+        use ink_e2e::account_id;
+        use ink_e2e::AccountKeyring::{Alice, Bob};
+        use test_helpers::balance_of;
+
+        type E2EResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+        #[ink_e2e::test]
+        async fn relayer_can_bridge_mint_and_burn(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new();
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_bridgeable",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let mint_tx = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.bridge_mint(account_id(Alice), 1000, 1, 0),
+                )
+                .submit()
+                .await
+                .expect("bridge_mint failed")
+                .return_value();
+
+            assert_eq!(mint_tx, Ok(()));
+            assert!(matches!(balance_of!(client, contract, Alice), 1000));
+
+            let is_nonce_used = client
+                .call(&ink_e2e::alice(), &contract.is_nonce_used(1, 0))
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert!(is_nonce_used);
+
+            let burn_tx = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.bridge_burn(
+                        account_id(Alice),
+                        400,
+                        2,
+                        vec![1, 2, 3],
+                    ),
+                )
+                .submit()
+                .await
+                .expect("bridge_burn failed")
+                .return_value();
+
+            assert_eq!(burn_tx, Ok(0));
+            assert!(matches!(balance_of!(client, contract, Alice), 600));
+
+            let outbound_nonce = client
+                .call(&ink_e2e::alice(), &contract.outbound_nonce())
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(outbound_nonce, 1);
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn cannot_reuse_nonce(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new();
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_bridgeable",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.bridge_mint(account_id(Alice), 1000, 1, 7),
+                )
+                .submit()
+                .await
+                .expect("bridge_mint failed");
+
+            let mint_tx = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.bridge_mint(account_id(Alice), 1000, 1, 7),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(mint_tx, Err(PSP22Error::BridgeNonceUsed));
+
+            let mint_tx = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.bridge_mint(account_id(Alice), 1000, 2, 7),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(mint_tx, Ok(()));
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn only_relayer_can_bridge(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new();
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_bridgeable",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let mint_tx = client
+                .call(
+                    &ink_e2e::bob(),
+                    &contract.bridge_mint(account_id(Bob), 1000, 1, 0),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(
+                mint_tx,
+                Err(PSP22Error::Custom("AC::MissingRole".to_string()))
+            );
+
+            let burn_tx = client
+                .call(
+                    &ink_e2e::bob(),
+                    &contract.bridge_burn(account_id(Bob), 0, 2, vec![]),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(
+                burn_tx,
+                Err(PSP22Error::Custom("AC::MissingRole".to_string()))
+            );
+
+            Ok(())
+        }
+    }
+}
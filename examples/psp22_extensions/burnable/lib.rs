@@ -3,7 +3,8 @@
 
 /// Contract Summary:
 /// A PSP22 contract.
-/// Anyone can burn tokens from anyone's account.
+/// Holders can burn their tokens, spenders can burn tokens up to their allowance.
+/// Anyone can burn tokens from anyone's account with `burn_from_many`.
 // ########################################################
 // inject PSP22 trait's default implementation (PSP22DefaultImpl & PSP22InternalDefaultImpl)
 // and PSP22Burnable trait's default implementation (PSP22BurnableDefaultImpl)
@@ -115,7 +116,7 @@ pub mod my_psp22_burnable {
         }
 
         #[ink_e2e::test]
-        async fn can_burn_without_allowance(
+        async fn cannot_burn_without_allowance(
             mut client: ink_e2e::Client<C, E>,
         ) -> E2EResult<()> {
             let mut constructor = ContractRef::new(100);
@@ -135,15 +136,15 @@ pub mod my_psp22_burnable {
 
             let result = client
                 .call(&ink_e2e::bob(), &contract.burn(account_id(Alice), 10))
-                .submit()
+                .dry_run()
                 .await?
                 .return_value();
 
-            assert!(matches!(result, Ok(())));
+            assert_eq!(result, Err(PSP22Error::InsufficientAllowance));
 
             let balance_of_alice = balance_of!(client, contract, Alice);
 
-            assert!(matches!(balance_of_alice, 90));
+            assert!(matches!(balance_of_alice, 100));
 
             Ok(())
         }
@@ -222,6 +223,14 @@ pub mod my_psp22_burnable {
 
             assert!(matches!(balance_of_bob, 10));
 
+            let result = client
+                .call(&ink_e2e::bob(), &contract.approve(account_id(Alice), 10))
+                .submit()
+                .await?
+                .return_value();
+
+            assert!(matches!(result, Ok(())));
+
             let result = client
                 .call(&ink_e2e::alice(), &contract.burn(account_id(Bob), 10))
                 .submit()
@@ -0,0 +1,134 @@
+// SPDX-License-Identifier: MIT
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+/// Contract Summary:
+/// A PSP22 contract with mintable and capped extensions - anyone can mint tokens up to the cap.
+// ########################################################
+// inject PSP22 trait's default implementation (PSP22DefaultImpl & PSP22InternalDefaultImpl)
+// and PSP22Mintable & PSP22Capped traits' default implementations
+// which reduces the amount of boilerplate code required to implement trait messages drastically
+#[pendzl::implementation(PSP22, PSP22Mintable, PSP22Capped)]
+#[ink::contract]
+pub mod my_psp22_capped {
+    use pendzl::contracts::psp22::*;
+    #[ink(storage)]
+    #[derive(Default, StorageFieldGetter)]
+    pub struct Contract {
+        #[storage_field]
+        psp22: PSP22Data,
+        // PSP22CappedData is a struct that implements PSP22CappedStorage - required by PSP22CappedInternalDefaultImpl trait
+        #[storage_field]
+        capped: PSP22CappedData,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new(initial_supply: Balance, cap: Balance) -> Self {
+            let mut instance = Self::default();
+
+            // the cap has to be set before minting, otherwise every mint exceeds it
+            instance._set_cap(&cap).expect("Should set cap");
+            instance
+                ._mint_to(&Self::env().caller(), &initial_supply)
+                .expect("Should mint");
+
+            instance
+        }
+    }
+
+    #[cfg(all(test, feature = "e2e-tests"))]
+    pub mod tests {
+        #[rustfmt::skip]
+        use super::*;
+        #[rustfmt::skip]
+        use ink_e2e::ContractsBackend;
+        use ink_e2e::account_id;
+        use ink_e2e::AccountKeyring::{Alice, Bob};
+        use test_helpers::balance_of;
+
+        type E2EResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+        #[ink_e2e::test]
+        async fn returns_cap(client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(1000, 2000);
+            let contract = client
+                .instantiate(
+                    "my_psp22_capped",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let cap = client
+                .call(&ink_e2e::alice(), &contract.cap())
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(cap, 2000);
+            assert!(matches!(balance_of!(client, contract, Alice), 1000));
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn can_mint_up_to_cap(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(1000, 2000);
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_capped",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let mint_tx = client
+                .call(&ink_e2e::alice(), &contract.mint(account_id(Bob), 1000))
+                .submit()
+                .await
+                .expect("mint failed")
+                .return_value();
+
+            assert_eq!(mint_tx, Ok(()));
+            assert!(matches!(balance_of!(client, contract, Bob), 1000));
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn cannot_mint_above_cap(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(1000, 2000);
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_capped",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let mint_tx = client
+                .call(&ink_e2e::alice(), &contract.mint(account_id(Bob), 1001))
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(mint_tx, Err(PSP22Error::CapExceeded));
+            assert!(matches!(balance_of!(client, contract, Bob), 0));
+
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,208 @@
+// SPDX-License-Identifier: MIT
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+/// Contract Summary:
+/// A PSP22 contract with compliance extension and ownable module.
+/// Only the owner is allowed to freeze accounts, manage the allow list and change the compliance mode.
+// ########################################################
+// inject PSP22 trait's default implementation (PSP22DefaultImpl & PSP22InternalDefaultImpl)
+// and PSP22Compliance trait's default implementation (PSP22ComplianceOwnableDefaultImpl & PSP22ComplianceInternalDefaultImpl)
+// and Ownable trait's default implementation (OwnableDefaultImpl & OwnableInternalDefaultImpl)
+// which reduces the amount of boilerplate code required to implement trait messages drastically
+#[pendzl::implementation(PSP22, PSP22Compliance, Ownable)]
+#[ink::contract]
+pub mod my_psp22_compliance {
+    use pendzl::contracts::psp22::*;
+    #[ink(storage)]
+    #[derive(Default, StorageFieldGetter)]
+    pub struct Contract {
+        #[storage_field]
+        psp22: PSP22Data,
+        // PSP22ComplianceData is a struct that implements PSP22ComplianceStorage - required by PSP22ComplianceInternalDefaultImpl trait
+        #[storage_field]
+        compliance: PSP22ComplianceData,
+        #[storage_field]
+        ownable: OwnableData,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new(total_supply: Balance) -> Self {
+            let mut instance = Self::default();
+
+            instance._update_owner(&Some(Self::env().caller()));
+            instance
+                ._mint_to(&Self::env().caller(), &total_supply)
+                .expect("Should mint");
+
+            instance
+        }
+    }
+
+    #[cfg(all(test, feature = "e2e-tests"))]
+    pub mod tests {
+        #[rustfmt::skip]
+        use super::*;
+        #[rustfmt::skip]
+        use ink_e2e::ContractsBackend;
+        use ink_e2e::account_id;
+        use ink_e2e::AccountKeyring::{Alice, Bob};
+        use test_helpers::balance_of;
+
+        type E2EResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+        #[ink_e2e::test]
+        async fn frozen_account_cannot_send_or_receive(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(1000);
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_compliance",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let freeze_tx = client
+                .call(&ink_e2e::alice(), &contract.freeze(account_id(Bob)))
+                .submit()
+                .await
+                .expect("freeze failed")
+                .return_value();
+
+            assert_eq!(freeze_tx, Ok(()));
+
+            let transfer_tx = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.transfer(account_id(Bob), 100, vec![]),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(transfer_tx, Err(PSP22Error::AccountFrozen));
+
+            let unfreeze_tx = client
+                .call(&ink_e2e::alice(), &contract.unfreeze(account_id(Bob)))
+                .submit()
+                .await
+                .expect("unfreeze failed")
+                .return_value();
+
+            assert_eq!(unfreeze_tx, Ok(()));
+
+            let transfer_tx = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.transfer(account_id(Bob), 100, vec![]),
+                )
+                .submit()
+                .await
+                .expect("transfer failed")
+                .return_value();
+
+            assert_eq!(transfer_tx, Ok(()));
+            assert!(matches!(balance_of!(client, contract, Bob), 100));
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn allow_list_mode_requires_allowed_accounts(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(1000);
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_compliance",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.set_compliance_mode(ComplianceMode::AllowList),
+                )
+                .submit()
+                .await
+                .expect("set_compliance_mode failed");
+
+            let transfer_tx = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.transfer(account_id(Bob), 100, vec![]),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(transfer_tx, Err(PSP22Error::AccountNotAllowed));
+
+            for account in [account_id(Alice), account_id(Bob)] {
+                client
+                    .call(
+                        &ink_e2e::alice(),
+                        &contract.set_allowed(account, true),
+                    )
+                    .submit()
+                    .await
+                    .expect("set_allowed failed");
+            }
+
+            let transfer_tx = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.transfer(account_id(Bob), 100, vec![]),
+                )
+                .submit()
+                .await
+                .expect("transfer failed")
+                .return_value();
+
+            assert_eq!(transfer_tx, Ok(()));
+            assert!(matches!(balance_of!(client, contract, Bob), 100));
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn only_owner_can_freeze(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(1000);
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_compliance",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let freeze_tx = client
+                .call(&ink_e2e::bob(), &contract.freeze(account_id(Alice)))
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(
+                freeze_tx,
+                Err(PSP22Error::Custom("O::CallerIsNotOwner".to_string()))
+            );
+
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,191 @@
+// SPDX-License-Identifier: MIT
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+/// Contract Summary:
+/// A PSP22 contract with expiring allowance extension.
+// ########################################################
+// inject PSP22 trait's default implementation (PSP22DefaultImpl & PSP22InternalDefaultImpl)
+// and PSP22ExpiringAllowance trait's default implementation (PSP22ExpiringAllowanceDefaultImpl & PSP22ExpiringAllowanceInternalDefaultImpl)
+// which reduces the amount of boilerplate code required to implement trait messages drastically
+#[pendzl::implementation(PSP22, PSP22ExpiringAllowance)]
+#[ink::contract]
+pub mod my_psp22_expiring_allowance {
+    use pendzl::contracts::psp22::*;
+    #[ink(storage)]
+    #[derive(Default, StorageFieldGetter)]
+    pub struct Contract {
+        #[storage_field]
+        psp22: PSP22Data,
+        // PSP22ExpiringAllowanceData is a struct that implements PSP22ExpiringAllowanceStorage - required by PSP22ExpiringAllowanceInternalDefaultImpl trait
+        #[storage_field]
+        expiring_allowance: PSP22ExpiringAllowanceData,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new(total_supply: Balance) -> Self {
+            let mut instance = Self::default();
+
+            instance
+                ._mint_to(&Self::env().caller(), &total_supply)
+                .expect("Should mint");
+
+            instance
+        }
+    }
+
+    #[cfg(all(test, feature = "e2e-tests"))]
+    pub mod tests {
+        #[rustfmt::skip]
+        use super::*;
+        #[rustfmt::skip]
+        use ink_e2e::ContractsBackend;
+        use ink_e2e::account_id;
+        use ink_e2e::AccountKeyring::{Alice, Bob, Charlie};
+        use test_helpers::balance_of;
+
+        type E2EResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+        const FAR_FUTURE: Timestamp = u64::MAX;
+
+        #[ink_e2e::test]
+        async fn approve_until_can_be_spent_before_expiry(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(1000);
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_expiring_allowance",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let approve_tx = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.approve_until(account_id(Bob), 300, FAR_FUTURE),
+                )
+                .submit()
+                .await
+                .expect("approve_until failed")
+                .return_value();
+
+            assert_eq!(approve_tx, Ok(()));
+
+            let expiry = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract
+                        .allowance_expiry(account_id(Alice), account_id(Bob)),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(expiry, Some(FAR_FUTURE));
+
+            let transfer_tx = client
+                .call(
+                    &ink_e2e::bob(),
+                    &contract.transfer_from(
+                        account_id(Alice),
+                        account_id(Charlie),
+                        300,
+                        vec![],
+                    ),
+                )
+                .submit()
+                .await
+                .expect("transfer_from failed")
+                .return_value();
+
+            assert_eq!(transfer_tx, Ok(()));
+            assert!(matches!(balance_of!(client, contract, Charlie), 300));
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn cannot_approve_until_the_past(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(1000);
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_expiring_allowance",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let approve_tx = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.approve_until(account_id(Bob), 300, 0),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(approve_tx, Err(PSP22Error::InvalidAllowanceExpiry));
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn approve_removes_expiry(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(1000);
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_expiring_allowance",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.approve_until(account_id(Bob), 300, FAR_FUTURE),
+                )
+                .submit()
+                .await
+                .expect("approve_until failed");
+
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.approve(account_id(Bob), 500),
+                )
+                .submit()
+                .await
+                .expect("approve failed");
+
+            let expiry = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract
+                        .allowance_expiry(account_id(Alice), account_id(Bob)),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(expiry, None);
+
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,203 @@
+// SPDX-License-Identifier: MIT
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+/// Contract Summary:
+/// A PSP22 contract with lockable extension and ownable module.
+/// Only the owner is allowed to lock balances.
+// ########################################################
+// inject PSP22 trait's default implementation (PSP22DefaultImpl & PSP22InternalDefaultImpl)
+// and PSP22Lockable trait's default implementation (PSP22LockableDefaultImpl & PSP22LockableInternalDefaultImpl)
+// and Ownable trait's default implementation (OwnableDefaultImpl & OwnableInternalDefaultImpl)
+// which reduces the amount of boilerplate code required to implement trait messages drastically
+#[pendzl::implementation(PSP22, PSP22Lockable, Ownable)]
+#[ink::contract]
+pub mod my_psp22_lockable {
+    use pendzl::contracts::psp22::*;
+    #[ink(storage)]
+    #[derive(Default, StorageFieldGetter)]
+    pub struct Contract {
+        #[storage_field]
+        psp22: PSP22Data,
+        // PSP22LockableData is a struct that implements PSP22LockableStorage - required by PSP22LockableInternalDefaultImpl trait
+        #[storage_field]
+        lockable: PSP22LockableData,
+        #[storage_field]
+        ownable: OwnableData,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new(total_supply: Balance) -> Self {
+            let mut instance = Self::default();
+
+            instance._update_owner(&Some(Self::env().caller()));
+            instance
+                ._mint_to(&Self::env().caller(), &total_supply)
+                .expect("Should mint");
+
+            instance
+        }
+
+        #[ink(message)]
+        pub fn lock(
+            &mut self,
+            account: AccountId,
+            amount: Balance,
+            until: Timestamp,
+        ) -> Result<(), PSP22Error> {
+            self._only_owner()?;
+            self._lock(&account, &amount, &until)
+        }
+    }
+
+    #[cfg(all(test, feature = "e2e-tests"))]
+    pub mod tests {
+        #[rustfmt::skip]
+        use super::*;
+        #[rustfmt::skip]
+        use ink_e2e::ContractsBackend;
+        use ink_e2e::account_id;
+        use ink_e2e::AccountKeyring::{Alice, Bob};
+        use test_helpers::balance_of;
+
+        type E2EResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+        const FAR_FUTURE: Timestamp = u64::MAX;
+
+        #[ink_e2e::test]
+        async fn locked_balance_can_not_be_transferred(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(1000);
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_lockable",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let lock_tx = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.lock(account_id(Alice), 600, FAR_FUTURE),
+                )
+                .submit()
+                .await
+                .expect("lock failed")
+                .return_value();
+
+            assert_eq!(lock_tx, Ok(()));
+
+            let locked = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.locked_balance_of(account_id(Alice)),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+            let unlocked = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.unlocked_balance_of(account_id(Alice)),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(locked, 600);
+            assert_eq!(unlocked, 400);
+
+            let transfer_tx = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.transfer(account_id(Bob), 401, vec![]),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(transfer_tx, Err(PSP22Error::LockedBalance));
+
+            let transfer_tx = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.transfer(account_id(Bob), 400, vec![]),
+                )
+                .submit()
+                .await
+                .expect("transfer failed")
+                .return_value();
+
+            assert_eq!(transfer_tx, Ok(()));
+            assert!(matches!(balance_of!(client, contract, Alice), 600));
+            assert!(matches!(balance_of!(client, contract, Bob), 400));
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn cannot_lock_in_the_past(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(1000);
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_lockable",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let lock_tx = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.lock(account_id(Alice), 600, 0),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(lock_tx, Err(PSP22Error::InvalidLock));
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn cannot_lock_more_than_unlocked_balance(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(1000);
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_lockable",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let lock_tx = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.lock(account_id(Alice), 1001, FAR_FUTURE),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(lock_tx, Err(PSP22Error::InsufficientBalance));
+
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,210 @@
+// SPDX-License-Identifier: MIT
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+/// Contract Summary:
+/// A PSP22 contract with mintable and minter allowance extensions and access control module.
+/// A creator of the contract becomes a DEFAULT_ADMIN and MINTER_ADMIN.
+/// MINTER_ADMIN configures minters, each minter can mint up to its allowance.
+// ########################################################
+// inject PSP22 trait's default implementation (PSP22DefaultImpl & PSP22InternalDefaultImpl)
+// and PSP22Mintable trait's default implementation (PSP22MintableDefaultImpl)
+// and PSP22MinterAllowance trait's default implementation (PSP22MinterAllowanceDefaultImpl & PSP22MinterAllowanceInternalDefaultImpl)
+// and AccessControl trait's default implementation (AccessControlDefaultImpl & AccessControlInternalDefaultImpl)
+// which reduces the amount of boilerplate code required to implement trait messages drastically
+#[pendzl::implementation(
+    PSP22,
+    PSP22Mintable,
+    PSP22MinterAllowance,
+    AccessControl
+)]
+#[ink::contract]
+pub mod my_psp22_minter_allowance {
+    use pendzl::contracts::psp22::*;
+    #[ink(storage)]
+    #[derive(Default, StorageFieldGetter)]
+    pub struct Contract {
+        #[storage_field]
+        psp22: PSP22Data,
+        // PSP22MinterAllowanceData is a struct that implements PSP22MinterAllowanceStorage - required by PSP22MinterAllowanceInternalDefaultImpl trait
+        #[storage_field]
+        minter_allowance: PSP22MinterAllowanceData,
+        #[storage_field]
+        access: AccessControlData,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            let mut instance = Self::default();
+
+            let caller = Self::env().caller();
+            instance
+                ._grant_role(Self::_default_admin(), Some(caller))
+                .expect("Should grant DEFAULT_ADMIN role");
+            instance
+                ._grant_role(MINTER_ADMIN, Some(caller))
+                .expect("Should grant MINTER_ADMIN role");
+
+            instance
+        }
+    }
+
+    #[cfg(all(test, feature = "e2e-tests"))]
+    pub mod tests {
+        #[rustfmt::skip]
+        use super::*;
+        #[rustfmt::skip]
+        use ink_e2e::ContractsBackend;
+        use ink_e2e::account_id;
+        use ink_e2e::AccountKeyring::{Bob, Charlie};
+        use test_helpers::balance_of;
+
+        type E2EResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+        #[ink_e2e::test]
+        async fn minter_can_mint_up_to_allowance(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new();
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_minter_allowance",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let configure_tx = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.configure_minter(account_id(Bob), 1000),
+                )
+                .submit()
+                .await
+                .expect("configure_minter failed")
+                .return_value();
+
+            assert_eq!(configure_tx, Ok(()));
+
+            let mint_tx = client
+                .call(&ink_e2e::bob(), &contract.mint(account_id(Charlie), 600))
+                .submit()
+                .await
+                .expect("mint failed")
+                .return_value();
+
+            assert_eq!(mint_tx, Ok(()));
+            assert!(matches!(balance_of!(client, contract, Charlie), 600));
+
+            let minter_allowance = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.minter_allowance(account_id(Bob)),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(minter_allowance, 400);
+
+            let mint_tx = client
+                .call(&ink_e2e::bob(), &contract.mint(account_id(Charlie), 401))
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(mint_tx, Err(PSP22Error::MinterAllowanceExceeded));
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn removed_minter_cannot_mint(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new();
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_minter_allowance",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.configure_minter(account_id(Bob), 1000),
+                )
+                .submit()
+                .await
+                .expect("configure_minter failed");
+
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.remove_minter(account_id(Bob)),
+                )
+                .submit()
+                .await
+                .expect("remove_minter failed");
+
+            let is_minter = client
+                .call(&ink_e2e::alice(), &contract.is_minter(account_id(Bob)))
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert!(!is_minter);
+
+            let mint_tx = client
+                .call(&ink_e2e::bob(), &contract.mint(account_id(Bob), 1))
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(mint_tx, Err(PSP22Error::MinterAllowanceExceeded));
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn only_minter_admin_can_configure_minters(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new();
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_minter_allowance",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let configure_tx = client
+                .call(
+                    &ink_e2e::bob(),
+                    &contract.configure_minter(account_id(Bob), 1000),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(
+                configure_tx,
+                Err(PSP22Error::Custom("AC::MissingRole".to_string()))
+            );
+
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,195 @@
+// SPDX-License-Identifier: MIT
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+/// Contract Summary:
+/// A PSP22 contract with permit extension - allowances can be granted via signed messages.
+// ########################################################
+// inject PSP22 trait's default implementation (PSP22DefaultImpl & PSP22InternalDefaultImpl)
+// and PSP22Permit trait's default implementation (PSP22PermitDefaultImpl & PSP22PermitInternalDefaultImpl)
+// which reduces the amount of boilerplate code required to implement trait messages drastically
+#[pendzl::implementation(PSP22, PSP22Permit)]
+#[ink::contract]
+pub mod my_psp22_permit {
+    use pendzl::contracts::psp22::*;
+    #[ink(storage)]
+    // derive explained below
+    #[derive(Default, StorageFieldGetter)]
+    pub struct Contract {
+        // apply the storage_field attribute so it's accessible via `self.data::<PSP22>()` (provided by StorageFieldGetter derive)
+        #[storage_field]
+        // PSP22Data is a struct that implements PSP22Storage - required by PSP22InternalDefaultImpl trait
+        // note it's not strictly required by PSP22 trait - just the default implementation
+        // name of the field is arbitrary
+        psp22: PSP22Data,
+        // PSP22PermitData keeps the per-owner nonces - required by PSP22PermitInternalDefaultImpl trait
+        #[storage_field]
+        permit: PSP22PermitData,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new(total_supply: Balance) -> Self {
+            let mut instance = Self::default();
+
+            // mint total_supply to the caller using _mint_to from PSP22Internal (implemented by PSP22DefaultImpl)
+            instance
+                ._mint_to(&Self::env().caller(), &total_supply)
+                .expect("Should mint");
+
+            instance
+        }
+    }
+
+    #[cfg(all(test, feature = "e2e-tests"))]
+    pub mod tests {
+        #[rustfmt::skip]
+        use super::*;
+        #[rustfmt::skip]
+        use ink_e2e::ContractsBackend;
+        use ink_e2e::account_id;
+        use ink_e2e::AccountKeyring::{Alice, Bob};
+
+        type E2EResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+        #[ink_e2e::test]
+        async fn nonces_start_at_zero(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(1000);
+            let contract = client
+                .instantiate(
+                    "my_psp22_permit",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let nonce = client
+                .call(&ink_e2e::alice(), &contract.nonces(account_id(Alice)))
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(nonce, 0);
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn permit_with_valid_signature_sets_allowance(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(1000);
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_permit",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let domain_separator = client
+                .call(&ink_e2e::alice(), &contract.domain_separator())
+                .dry_run()
+                .await?
+                .return_value();
+
+            let value: Balance = 100;
+            let deadline: Timestamp = u64::MAX;
+            let mut message_hash = [0_u8; 32];
+            ink::env::hash_encoded::<ink::env::hash::Blake2x256, _>(
+                &(
+                    domain_separator,
+                    account_id(Alice),
+                    account_id(Bob),
+                    value,
+                    0_u64,
+                    deadline,
+                ),
+                &mut message_hash,
+            );
+            let signature = ink_e2e::alice().sign(&message_hash).0;
+
+            let result = client
+                .call(
+                    &ink_e2e::bob(),
+                    &contract.permit(
+                        account_id(Alice),
+                        account_id(Bob),
+                        value,
+                        deadline,
+                        PermitSignature::Sr25519(signature),
+                    ),
+                )
+                .submit()
+                .await
+                .expect("permit failed")
+                .return_value();
+
+            assert_eq!(result, Ok(()));
+
+            let allowance = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.allowance(account_id(Alice), account_id(Bob)),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(allowance, value);
+
+            let nonce = client
+                .call(&ink_e2e::alice(), &contract.nonces(account_id(Alice)))
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(nonce, 1);
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn permit_with_invalid_signature_fails(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(1000);
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_permit",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let result = client
+                .call(
+                    &ink_e2e::bob(),
+                    &contract.permit(
+                        account_id(Alice),
+                        account_id(Bob),
+                        100,
+                        u64::MAX,
+                        PermitSignature::Sr25519([0; 64]),
+                    ),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(result, Err(PSP22Error::PermitInvalidSignature));
+
+            Ok(())
+        }
+    }
+}
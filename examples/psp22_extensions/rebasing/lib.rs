@@ -0,0 +1,302 @@
+// SPDX-License-Identifier: MIT
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+/// Contract Summary:
+/// A PSP22 contract with rebasing extension and ownable module.
+/// Balances are kept as shares, only the owner is allowed to rebase the total supply.
+// ########################################################
+// inject PSP22 trait's default implementation (PSP22DefaultImpl & PSP22InternalDefaultImpl)
+// and PSP22Rebasing trait's default implementation (PSP22RebasingDefaultImpl & PSP22RebasingInternalDefaultImpl)
+// and Ownable trait's default implementation (OwnableDefaultImpl & OwnableInternalDefaultImpl)
+// which reduces the amount of boilerplate code required to implement trait messages drastically
+#[pendzl::implementation(PSP22, PSP22Rebasing, Ownable)]
+#[ink::contract]
+pub mod my_psp22_rebasing {
+    use pendzl::contracts::psp22::*;
+    #[ink(storage)]
+    #[derive(Default, StorageFieldGetter)]
+    pub struct Contract {
+        // PSP22RebasingData implements PSP22Storage over shares - it is used by PSP22InternalDefaultImpl instead of PSP22Data
+        #[storage_field]
+        psp22: PSP22RebasingData,
+        #[storage_field]
+        ownable: OwnableData,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new(total_supply: Balance) -> Self {
+            let mut instance = Self::default();
+
+            instance._update_owner(&Some(Self::env().caller()));
+            instance
+                ._mint_to(&Self::env().caller(), &total_supply)
+                .expect("Should mint");
+
+            instance
+        }
+
+        #[ink(message)]
+        pub fn rebase(
+            &mut self,
+            new_total_supply: Balance,
+        ) -> Result<(), PSP22Error> {
+            self._only_owner()?;
+            self._rebase(&new_total_supply)
+        }
+    }
+
+    #[cfg(all(test, feature = "e2e-tests"))]
+    pub mod tests {
+        #[rustfmt::skip]
+        use super::*;
+        #[rustfmt::skip]
+        use ink_e2e::ContractsBackend;
+        use ink_e2e::account_id;
+        use ink_e2e::AccountKeyring::{Alice, Bob};
+        use test_helpers::balance_of;
+
+        type E2EResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+        #[ink_e2e::test]
+        async fn rebase_changes_balances_proportionally(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(1000);
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_rebasing",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.transfer(account_id(Bob), 250, vec![]),
+                )
+                .submit()
+                .await
+                .expect("transfer failed");
+
+            let rebase_tx = client
+                .call(&ink_e2e::alice(), &contract.rebase(2000))
+                .submit()
+                .await
+                .expect("rebase failed")
+                .return_value();
+
+            assert_eq!(rebase_tx, Ok(()));
+            assert!(matches!(balance_of!(client, contract, Alice), 1500));
+            assert!(matches!(balance_of!(client, contract, Bob), 500));
+
+            let total_supply = client
+                .call(&ink_e2e::alice(), &contract.total_supply())
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(total_supply, 2000);
+
+            let shares = client
+                .call(&ink_e2e::alice(), &contract.shares_of(account_id(Bob)))
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(shares, 250);
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn transfers_move_shares_after_rebase(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(1000);
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_rebasing",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            client
+                .call(&ink_e2e::alice(), &contract.rebase(4000))
+                .submit()
+                .await
+                .expect("rebase failed");
+
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.transfer(account_id(Bob), 400, vec![]),
+                )
+                .submit()
+                .await
+                .expect("transfer failed");
+
+            assert!(matches!(balance_of!(client, contract, Alice), 3600));
+            assert!(matches!(balance_of!(client, contract, Bob), 400));
+
+            let shares = client
+                .call(&ink_e2e::alice(), &contract.shares_of(account_id(Bob)))
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(shares, 100);
+
+            let transfer_tx = client
+                .call(
+                    &ink_e2e::bob(),
+                    &contract.transfer(account_id(Alice), 401, vec![]),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(transfer_tx, Err(PSP22Error::InsufficientBalance));
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn transfers_round_in_favor_of_the_contract_after_rebase(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(1000);
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_rebasing",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            // 1.5 tokens per share
+            client
+                .call(&ink_e2e::alice(), &contract.rebase(1500))
+                .submit()
+                .await
+                .expect("rebase failed");
+
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.transfer(account_id(Bob), 100, vec![]),
+                )
+                .submit()
+                .await
+                .expect("transfer failed");
+
+            // 67 shares (100.5 tokens) are debited and 66 shares (99 tokens) are credited
+            assert!(matches!(balance_of!(client, contract, Alice), 1399));
+            assert!(matches!(balance_of!(client, contract, Bob), 99));
+
+            let alice_shares = client
+                .call(&ink_e2e::alice(), &contract.shares_of(account_id(Alice)))
+                .dry_run()
+                .await?
+                .return_value();
+            let bob_shares = client
+                .call(&ink_e2e::alice(), &contract.shares_of(account_id(Bob)))
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(alice_shares, 933);
+            assert_eq!(bob_shares, 66);
+
+            let total_supply = client
+                .call(&ink_e2e::alice(), &contract.total_supply())
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(total_supply, 1498);
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn rebase_to_zero_index_fails(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(1000);
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_rebasing",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let rebase_tx = client
+                .call(&ink_e2e::alice(), &contract.rebase(0))
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(rebase_tx, Err(PSP22Error::InvalidRebase));
+
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.transfer(account_id(Bob), 100, vec![]),
+                )
+                .submit()
+                .await
+                .expect("transfer failed");
+
+            assert!(matches!(balance_of!(client, contract, Alice), 900));
+            assert!(matches!(balance_of!(client, contract, Bob), 100));
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn only_owner_can_rebase(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(1000);
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_rebasing",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let rebase_tx = client
+                .call(&ink_e2e::bob(), &contract.rebase(2000))
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(
+                rebase_tx,
+                Err(PSP22Error::Custom("O::CallerIsNotOwner".to_string()))
+            );
+
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,162 @@
+// SPDX-License-Identifier: MIT
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+/// Contract Summary:
+/// A PSP22 contract with snapshot extension and ownable module.
+/// Only the owner is allowed to create snapshots.
+// ########################################################
+// inject PSP22 trait's default implementation (PSP22DefaultImpl & PSP22InternalDefaultImpl)
+// and PSP22Snapshot trait's default implementation (PSP22SnapshotDefaultImpl & PSP22SnapshotInternalDefaultImpl)
+// and Ownable trait's default implementation (OwnableDefaultImpl & OwnableInternalDefaultImpl)
+// which reduces the amount of boilerplate code required to implement trait messages drastically
+#[pendzl::implementation(PSP22, PSP22Snapshot, Ownable)]
+#[ink::contract]
+pub mod my_psp22_snapshot {
+    use pendzl::contracts::psp22::*;
+    #[ink(storage)]
+    #[derive(Default, StorageFieldGetter)]
+    pub struct Contract {
+        #[storage_field]
+        psp22: PSP22Data,
+        // PSP22SnapshotData is a struct that implements PSP22SnapshotStorage - required by PSP22SnapshotInternalDefaultImpl trait
+        #[storage_field]
+        snapshot: PSP22SnapshotData,
+        #[storage_field]
+        ownable: OwnableData,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new(total_supply: Balance) -> Self {
+            let mut instance = Self::default();
+
+            instance._update_owner(&Some(Self::env().caller()));
+            instance
+                ._mint_to(&Self::env().caller(), &total_supply)
+                .expect("Should mint");
+
+            instance
+        }
+    }
+
+    // override the default implementation of PSP22Snapshot trait's snapshot function (from PSP22SnapshotDefaultImpl)
+    // so only the owner can create snapshots
+    #[overrider(PSP22Snapshot)]
+    fn snapshot(&mut self) -> Result<u32, PSP22Error> {
+        self._only_owner()?;
+        self._snapshot()
+    }
+
+    #[cfg(all(test, feature = "e2e-tests"))]
+    pub mod tests {
+        #[rustfmt::skip]
+        use super::*;
+        #[rustfmt::skip]
+        use ink_e2e::ContractsBackend;
+        use ink_e2e::account_id;
+        use ink_e2e::AccountKeyring::{Alice, Bob};
+
+        type E2EResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+        #[ink_e2e::test]
+        async fn snapshot_records_balances(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(1000);
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_snapshot",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let snapshot_tx = client
+                .call(&ink_e2e::alice(), &contract.snapshot())
+                .submit()
+                .await
+                .expect("snapshot failed")
+                .return_value();
+            assert_eq!(snapshot_tx, Ok(1));
+
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.transfer(account_id(Bob), 400, vec![]),
+                )
+                .submit()
+                .await
+                .expect("transfer failed");
+
+            let alice_at = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.balance_of_at(account_id(Alice), 1),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+            let bob_at = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.balance_of_at(account_id(Bob), 1),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+            let total_supply_at = client
+                .call(&ink_e2e::alice(), &contract.total_supply_at(1))
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(alice_at, Ok(1000));
+            assert_eq!(bob_at, Ok(0));
+            assert_eq!(total_supply_at, Ok(1000));
+
+            let nonexistent = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.balance_of_at(account_id(Alice), 2),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(nonexistent, Err(PSP22Error::NonexistentSnapshot));
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn only_owner_can_snapshot(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(1000);
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_snapshot",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let snapshot_tx = client
+                .call(&ink_e2e::bob(), &contract.snapshot())
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(
+                snapshot_tx,
+                Err(PSP22Error::Custom("O::CallerIsNotOwner".to_string()))
+            );
+
+            Ok(())
+        }
+    }
+}
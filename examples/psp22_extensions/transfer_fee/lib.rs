@@ -0,0 +1,188 @@
+// SPDX-License-Identifier: MIT
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+/// Contract Summary:
+/// A PSP22 contract with transfer fee extension and ownable module.
+/// Only the owner is allowed to exempt accounts from the transfer fee.
+// ########################################################
+// inject PSP22 trait's default implementation (PSP22DefaultImpl & PSP22InternalDefaultImpl)
+// and PSP22TransferFee trait's default implementation (PSP22TransferFeeDefaultImpl & PSP22TransferFeeInternalDefaultImpl)
+// and Ownable trait's default implementation (OwnableDefaultImpl & OwnableInternalDefaultImpl)
+// which reduces the amount of boilerplate code required to implement trait messages drastically
+#[pendzl::implementation(PSP22, PSP22TransferFee, Ownable)]
+#[ink::contract]
+pub mod my_psp22_transfer_fee {
+    use pendzl::contracts::psp22::*;
+    #[ink(storage)]
+    #[derive(Default, StorageFieldGetter)]
+    pub struct Contract {
+        #[storage_field]
+        psp22: PSP22Data,
+        // PSP22TransferFeeData is a struct that implements PSP22TransferFeeStorage - required by PSP22TransferFeeInternalDefaultImpl trait
+        #[storage_field]
+        transfer_fee: PSP22TransferFeeData,
+        #[storage_field]
+        ownable: OwnableData,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new(
+            total_supply: Balance,
+            transfer_fee_bps: u16,
+            fee_recipient: AccountId,
+        ) -> Self {
+            let mut instance = Self::default();
+
+            instance.transfer_fee =
+                PSP22TransferFeeData::new(transfer_fee_bps, fee_recipient)
+                    .expect("Should set transfer fee");
+            instance._update_owner(&Some(Self::env().caller()));
+            instance
+                ._mint_to(&Self::env().caller(), &total_supply)
+                .expect("Should mint");
+
+            instance
+        }
+
+        #[ink(message)]
+        pub fn set_fee_exempt(
+            &mut self,
+            account: AccountId,
+            exempt: bool,
+        ) -> Result<(), PSP22Error> {
+            self._only_owner()?;
+            self._set_fee_exempt(&account, exempt);
+            Ok(())
+        }
+    }
+
+    #[cfg(all(test, feature = "e2e-tests"))]
+    pub mod tests {
+        #[rustfmt::skip]
+        use super::*;
+        #[rustfmt::skip]
+        use ink_e2e::ContractsBackend;
+        use ink_e2e::account_id;
+        use ink_e2e::AccountKeyring::{Alice, Bob, Charlie};
+        use test_helpers::balance_of;
+
+        type E2EResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+        #[ink_e2e::test]
+        async fn transfer_charges_fee(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            // 1% fee
+            let mut constructor =
+                ContractRef::new(10_000, 100, account_id(Charlie));
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_transfer_fee",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let transfer_tx = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.transfer(account_id(Bob), 1000, vec![]),
+                )
+                .submit()
+                .await
+                .expect("transfer failed")
+                .return_value();
+
+            assert_eq!(transfer_tx, Ok(()));
+            assert!(matches!(balance_of!(client, contract, Alice), 9000));
+            assert!(matches!(balance_of!(client, contract, Bob), 990));
+            assert!(matches!(balance_of!(client, contract, Charlie), 10));
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn exempt_account_is_not_charged(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor =
+                ContractRef::new(10_000, 100, account_id(Charlie));
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_transfer_fee",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let exempt_tx = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.set_fee_exempt(account_id(Alice), true),
+                )
+                .submit()
+                .await
+                .expect("set_fee_exempt failed")
+                .return_value();
+
+            assert_eq!(exempt_tx, Ok(()));
+
+            let transfer_tx = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.transfer(account_id(Bob), 1000, vec![]),
+                )
+                .submit()
+                .await
+                .expect("transfer failed")
+                .return_value();
+
+            assert_eq!(transfer_tx, Ok(()));
+            assert!(matches!(balance_of!(client, contract, Bob), 1000));
+            assert!(matches!(balance_of!(client, contract, Charlie), 0));
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn only_owner_can_exempt(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor =
+                ContractRef::new(10_000, 100, account_id(Charlie));
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_transfer_fee",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let exempt_tx = client
+                .call(
+                    &ink_e2e::bob(),
+                    &contract.set_fee_exempt(account_id(Bob), true),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(
+                exempt_tx,
+                Err(PSP22Error::Custom("O::CallerIsNotOwner".to_string()))
+            );
+
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,81 @@
+// SPDX-License-Identifier: MIT
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+/// Contract Summary:
+/// A PSP22 vault contract with asynchronous deposit and redeem requests.
+/// Requests are fulfilled by the owner once the assets are available and claimed by the controller.
+// ########################################################
+// inject PSP22 trait's default implementation (PSP22DefaultImpl & PSP22InternalDefaultImpl)
+// and PSP22Vault trait's default implementation (PSP22VaultDefaultImpl & PSP22VaultInternalDefaultImpl)
+// and PSP22VaultAsync trait's default implementation (PSP22VaultAsyncDefaultImpl & PSP22VaultAsyncInternalDefaultImpl)
+// and Ownable trait's default implementation (OwnableDefaultImpl & OwnableInternalDefaultImpl)
+// which reduces the amount of boilerplate code required to implement trait messages drastically
+// Note: PSP22VaultAsync disables synchronous PSP22Vault withdraw and redeem
+// and excludes the assets of pending deposits and claimable redeems from the total assets
+#[pendzl::implementation(PSP22, PSP22Vault, PSP22VaultAsync, Ownable)]
+#[ink::contract]
+pub mod my_psp22_vault_async {
+    #[ink(storage)]
+    // derive explained below
+    #[derive(Default, StorageFieldGetter)]
+    pub struct Contract {
+        // apply the storage_field attribute so it's accessible via `self.data::<PSP22>()` (provided by StorageFieldGetter derive)
+        #[storage_field]
+        // PSP22Data is a struct that implements PSP22Storage - required by PSP22InternalDefaultImpl trait
+        // note it's not strictly required by PSP22 trait - just the default implementation
+        // name of the field is arbitrary
+        psp22: PSP22Data,
+        // apply the storage_field attribute so it's accessible via `self.data::<PSP22Vault>()` (provided by StorageFieldGetter derive)
+        #[storage_field]
+        // PSP22VaultData is a struct that implements PSP22VaultStorage - required by PSP22VaultInternalDefaultImpl trait
+        // note it's not strictly required by PSP22Vault trait - just the default implementation
+        // name of the field is arbitrary
+        vault: PSP22VaultData,
+        // apply the storage_field attribute so it's accessible via `self.data::<PSP22VaultAsync>()` (provided by StorageFieldGetter derive)
+        #[storage_field]
+        // PSP22VaultAsyncData is a struct that implements PSP22VaultAsyncStorage - required by PSP22VaultAsyncInternalDefaultImpl trait
+        // note it's not strictly required by PSP22VaultAsync trait - just the default implementation
+        // name of the field is arbitrary
+        vault_async: PSP22VaultAsyncData,
+        // apply the storage_field attribute so it's accessible via `self.data::<Ownable>()` (provided by StorageFieldGetter derive)
+        #[storage_field]
+        // OwnableData is a struct that implements OwnableStorage - required by OwnableInternalDefaultImpl trait
+        // note it's not strictly required by Ownable trait - just the default implementation
+        // name of the field is arbitrary
+        ownable: OwnableData,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new(asset: AccountId) -> Self {
+            let mut instance = Self {
+                psp22: PSP22Data::default(),
+                vault: PSP22VaultData::new(asset, None),
+                vault_async: PSP22VaultAsyncData::default(),
+                ownable: OwnableData::default(),
+            };
+            instance._update_owner(&Some(Self::env().caller()));
+            instance
+        }
+
+        #[ink(message)]
+        pub fn fulfill_deposit(
+            &mut self,
+            controller: AccountId,
+            assets: Balance,
+        ) -> Result<Balance, PSP22Error> {
+            self._only_owner()?;
+            self._fulfill_deposit(&controller, &assets)
+        }
+
+        #[ink(message)]
+        pub fn fulfill_redeem(
+            &mut self,
+            controller: AccountId,
+            shares: Balance,
+        ) -> Result<Balance, PSP22Error> {
+            self._only_owner()?;
+            self._fulfill_redeem(&controller, &shares)
+        }
+    }
+}
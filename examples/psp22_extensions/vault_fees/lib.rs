@@ -0,0 +1,119 @@
+// SPDX-License-Identifier: MIT
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+/// Contract Summary:
+/// A PSP22 vault contract charging entry, exit, management and performance fees.
+/// Fees are configured by the owner and paid to the fee recipient.
+// ########################################################
+// inject PSP22 trait's default implementation (PSP22DefaultImpl & PSP22InternalDefaultImpl)
+// and PSP22Vault trait's default implementation (PSP22VaultDefaultImpl & PSP22VaultInternalDefaultImpl)
+// and PSP22VaultFees trait's default implementation (PSP22VaultFeesDefaultImpl & PSP22VaultFeesInternalDefaultImpl)
+// and Ownable trait's default implementation (OwnableDefaultImpl & OwnableInternalDefaultImpl)
+// which reduces the amount of boilerplate code required to implement trait messages drastically
+// Note: PSP22VaultFees bakes the entry and exit fees into PSP22Vault previews
+// and accrues management and performance fees before every deposit and withdraw
+#[pendzl::implementation(PSP22, PSP22Vault, PSP22VaultFees, Ownable)]
+#[ink::contract]
+pub mod my_psp22_vault_fees {
+    #[ink(storage)]
+    // derive explained below
+    #[derive(Default, StorageFieldGetter)]
+    pub struct Contract {
+        // apply the storage_field attribute so it's accessible via `self.data::<PSP22>()` (provided by StorageFieldGetter derive)
+        #[storage_field]
+        // PSP22Data is a struct that implements PSP22Storage - required by PSP22InternalDefaultImpl trait
+        // note it's not strictly required by PSP22 trait - just the default implementation
+        // name of the field is arbitrary
+        psp22: PSP22Data,
+        // apply the storage_field attribute so it's accessible via `self.data::<PSP22Vault>()` (provided by StorageFieldGetter derive)
+        #[storage_field]
+        // PSP22VaultData is a struct that implements PSP22VaultStorage - required by PSP22VaultInternalDefaultImpl trait
+        // note it's not strictly required by PSP22Vault trait - just the default implementation
+        // name of the field is arbitrary
+        vault: PSP22VaultData,
+        // apply the storage_field attribute so it's accessible via `self.data::<PSP22VaultFees>()` (provided by StorageFieldGetter derive)
+        #[storage_field]
+        // PSP22VaultFeesData is a struct that implements PSP22VaultFeesStorage - required by PSP22VaultFeesInternalDefaultImpl trait
+        // note it's not strictly required by PSP22VaultFees trait - just the default implementation
+        // name of the field is arbitrary
+        fees: PSP22VaultFeesData,
+        // apply the storage_field attribute so it's accessible via `self.data::<Ownable>()` (provided by StorageFieldGetter derive)
+        #[storage_field]
+        // OwnableData is a struct that implements OwnableStorage - required by OwnableInternalDefaultImpl trait
+        // note it's not strictly required by Ownable trait - just the default implementation
+        // name of the field is arbitrary
+        ownable: OwnableData,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new(
+            asset: AccountId,
+            entry_fee_bps: u16,
+            exit_fee_bps: u16,
+            management_fee_bps: u16,
+            performance_fee_bps: u16,
+            fee_recipient: AccountId,
+        ) -> Result<Self, PSP22Error> {
+            let mut instance = Self {
+                psp22: PSP22Data::default(),
+                vault: PSP22VaultData::new(asset, None),
+                fees: PSP22VaultFeesData::new(
+                    entry_fee_bps,
+                    exit_fee_bps,
+                    management_fee_bps,
+                    performance_fee_bps,
+                    fee_recipient,
+                )?,
+                ownable: OwnableData::default(),
+            };
+            instance._update_owner(&Some(Self::env().caller()));
+            Ok(instance)
+        }
+
+        #[ink(message)]
+        pub fn set_entry_fee_bps(
+            &mut self,
+            fee_bps: u16,
+        ) -> Result<(), PSP22Error> {
+            self._only_owner()?;
+            self._set_entry_fee_bps(&fee_bps)
+        }
+
+        #[ink(message)]
+        pub fn set_exit_fee_bps(
+            &mut self,
+            fee_bps: u16,
+        ) -> Result<(), PSP22Error> {
+            self._only_owner()?;
+            self._set_exit_fee_bps(&fee_bps)
+        }
+
+        #[ink(message)]
+        pub fn set_management_fee_bps(
+            &mut self,
+            fee_bps: u16,
+        ) -> Result<(), PSP22Error> {
+            self._only_owner()?;
+            self._set_management_fee_bps(&fee_bps)
+        }
+
+        #[ink(message)]
+        pub fn set_performance_fee_bps(
+            &mut self,
+            fee_bps: u16,
+        ) -> Result<(), PSP22Error> {
+            self._only_owner()?;
+            self._set_performance_fee_bps(&fee_bps)
+        }
+
+        #[ink(message)]
+        pub fn set_fee_recipient(
+            &mut self,
+            fee_recipient: AccountId,
+        ) -> Result<(), PSP22Error> {
+            self._only_owner()?;
+            self._set_fee_recipient(&fee_recipient)
+        }
+    }
+}
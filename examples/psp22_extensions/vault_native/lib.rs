@@ -0,0 +1,208 @@
+// SPDX-License-Identifier: MIT
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+/// Contract Summary:
+/// A PSP22 vault contract whose underlying asset is the native currency.
+/// Shares are minted for the native currency transferred with deposit_native and mint_native
+/// and the native currency is sent back on withdraw and redeem.
+// ########################################################
+// inject PSP22 trait's default implementation (PSP22DefaultImpl & PSP22InternalDefaultImpl)
+// and PSP22Vault trait's default implementation (PSP22VaultDefaultImpl & PSP22VaultInternalDefaultImpl)
+// and PSP22VaultNative trait's default implementation (PSP22VaultNativeDefaultImpl & PSP22VaultNativeInternalDefaultImpl)
+// and PSP22Metadata trait's default implementation (PSP22MetadataDefaultImpl)
+// which reduces the amount of boilerplate code required to implement trait messages drastically
+// Note: PSP22VaultNative replaces the asset transfers and total assets of PSP22Vault with the native currency ones
+#[pendzl::implementation(PSP22, PSP22Vault, PSP22VaultNative, PSP22Metadata)]
+#[ink::contract]
+pub mod my_psp22_vault_native {
+    use ink::prelude::string::ToString;
+    use pendzl::contracts::psp22::*;
+    use pendzl::traits::String;
+    #[ink(storage)]
+    // derive explained below
+    #[derive(Default, StorageFieldGetter)]
+    pub struct Contract {
+        // apply the storage_field attribute so it's accessible via `self.data::<PSP22>()` (provided by StorageFieldGetter derive)
+        #[storage_field]
+        // PSP22Data is a struct that implements PSP22Storage - required by PSP22InternalDefaultImpl trait
+        // note it's not strictly required by PSP22 trait - just the default implementation
+        // name of the field is arbitrary
+        psp22: PSP22Data,
+        // apply the storage_field attribute so it's accessible via `self.data::<PSP22Vault>()` (provided by StorageFieldGetter derive)
+        #[storage_field]
+        // PSP22VaultData is a struct that implements PSP22VaultStorage - required by PSP22VaultInternalDefaultImpl trait
+        // note it's not strictly required by PSP22Vault trait - just the default implementation
+        // name of the field is arbitrary
+        vault: PSP22VaultData,
+        // apply the storage_field attribute so it's accessible via `self.data::<PSP22Metadata>()` (provided by StorageFieldGetter derive)
+        #[storage_field]
+        // PSP22MetadataData is a struct that implements PSP22MetadataStorage - required by PSP22MetadataInternalDefaultImpl trait
+        // note it's not strictly required by PSP22Metadata trait - just the default implementation
+        // name of the field is arbitrary
+        metadata: PSP22MetadataData,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new(underlying_decimals: u8) -> Self {
+            Self {
+                psp22: PSP22Data::default(),
+                // the native currency has no contract, NATIVE_ASSET_ID is returned by `asset`
+                vault: PSP22VaultData::new(
+                    NATIVE_ASSET_ID.into(),
+                    Some(underlying_decimals),
+                ),
+                metadata: PSP22MetadataData::new(
+                    Some("Name".to_string()),
+                    Some("Symbol".to_string()),
+                ),
+            }
+        }
+    }
+
+    #[cfg(all(test, feature = "e2e-tests"))]
+    pub mod tests {
+        #[rustfmt::skip]
+        use super::*;
+        #[rustfmt::skip]
+        use ink_e2e::ContractsBackend;
+        use ink_e2e::account_id;
+        use ink_e2e::AccountKeyring::{Alice, Bob};
+        use test_helpers::balance_of;
+
+        type E2EResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+        #[ink_e2e::test]
+        async fn deposit_native_mints_shares_for_transferred_value(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(12);
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_vault_native",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let deposit_tx = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.deposit_native(account_id(Alice)),
+                )
+                .value(1000)
+                .submit()
+                .await
+                .expect("deposit_native failed")
+                .return_value();
+
+            assert_eq!(deposit_tx, Ok(1000));
+            assert_eq!(balance_of!(client, contract, Alice), 1000);
+
+            let total_assets = client
+                .call(&ink_e2e::alice(), &contract.total_assets())
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(total_assets, 1000);
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn mint_native_fails_if_transferred_value_does_not_match(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(12);
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_vault_native",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let mint_tx = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.mint_native(1000, account_id(Alice)),
+                )
+                .value(999)
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(mint_tx, Err(PSP22Error::InvalidTransferredValue));
+
+            let deposit_tx = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.deposit(1000, account_id(Alice)),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(deposit_tx, Err(PSP22Error::InvalidTransferredValue));
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn redeem_sends_native_currency(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(12);
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_vault_native",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.deposit_native(account_id(Alice)),
+                )
+                .value(1000)
+                .submit()
+                .await
+                .expect("deposit_native failed");
+
+            let redeem_tx = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.redeem(400, account_id(Bob), account_id(Alice)),
+                )
+                .submit()
+                .await
+                .expect("redeem failed")
+                .return_value();
+
+            assert_eq!(redeem_tx, Ok(400));
+            assert_eq!(balance_of!(client, contract, Alice), 600);
+
+            let total_assets = client
+                .call(&ink_e2e::alice(), &contract.total_assets())
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(total_assets, 600);
+
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,55 @@
+// SPDX-License-Identifier: MIT
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+/// Contract Summary:
+/// A PSP22 vault contract with slippage protected deposit, mint, withdraw and redeem.
+/// Callers give the limit of shares or assets they accept and an optional deadline.
+// ########################################################
+// inject PSP22 trait's default implementation (PSP22DefaultImpl & PSP22InternalDefaultImpl)
+// and PSP22Vault trait's default implementation (PSP22VaultDefaultImpl & PSP22VaultInternalDefaultImpl)
+// and PSP22VaultSlippage trait's default implementation (PSP22VaultSlippageDefaultImpl & PSP22VaultSlippageInternalDefaultImpl)
+// and PSP22Metadata trait's default implementation (PSP22MetadataDefaultImpl)
+// which reduces the amount of boilerplate code required to implement trait messages drastically
+#[pendzl::implementation(PSP22, PSP22Vault, PSP22VaultSlippage, PSP22Metadata)]
+#[ink::contract]
+pub mod my_psp22_vault_slippage {
+    use ink::prelude::string::ToString;
+    use pendzl::traits::String;
+    #[ink(storage)]
+    // derive explained below
+    #[derive(Default, StorageFieldGetter)]
+    pub struct Contract {
+        // apply the storage_field attribute so it's accessible via `self.data::<PSP22>()` (provided by StorageFieldGetter derive)
+        #[storage_field]
+        // PSP22Data is a struct that implements PSP22Storage - required by PSP22InternalDefaultImpl trait
+        // note it's not strictly required by PSP22 trait - just the default implementation
+        // name of the field is arbitrary
+        psp22: PSP22Data,
+        // apply the storage_field attribute so it's accessible via `self.data::<PSP22Vault>()` (provided by StorageFieldGetter derive)
+        #[storage_field]
+        // PSP22VaultData is a struct that implements PSP22VaultStorage - required by PSP22VaultInternalDefaultImpl trait
+        // note it's not strictly required by PSP22Vault trait - just the default implementation
+        // name of the field is arbitrary
+        vault: PSP22VaultData,
+        // apply the storage_field attribute so it's accessible via `self.data::<PSP22Metadata>()` (provided by StorageFieldGetter derive)
+        #[storage_field]
+        // PSP22MetadataData is a struct that implements PSP22MetadataStorage - required by PSP22MetadataInternalDefaultImpl trait
+        // note it's not strictly required by PSP22Metadata trait - just the default implementation
+        // name of the field is arbitrary
+        metadata: PSP22MetadataData,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new(asset: AccountId) -> Self {
+            Self {
+                psp22: PSP22Data::default(),
+                vault: PSP22VaultData::new(asset, None),
+                metadata: PSP22MetadataData::new(
+                    Some("Name".to_string()),
+                    Some("Symbol".to_string()),
+                ),
+            }
+        }
+    }
+}
@@ -0,0 +1,189 @@
+// SPDX-License-Identifier: MIT
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+/// Contract Summary:
+/// A PSP22 contract with votes extension - token holders can delegate their voting power.
+// ########################################################
+// inject PSP22 trait's default implementation (PSP22DefaultImpl & PSP22InternalDefaultImpl)
+// and PSP22Votes trait's default implementation (PSP22VotesDefaultImpl & PSP22VotesInternalDefaultImpl)
+// which reduces the amount of boilerplate code required to implement trait messages drastically
+#[pendzl::implementation(PSP22, PSP22Votes)]
+#[ink::contract]
+pub mod my_psp22_votes {
+    use pendzl::contracts::psp22::*;
+    #[ink(storage)]
+    #[derive(Default, StorageFieldGetter)]
+    pub struct Contract {
+        #[storage_field]
+        psp22: PSP22Data,
+        // PSP22VotesData is a struct that implements PSP22VotesStorage - required by PSP22VotesInternalDefaultImpl trait
+        #[storage_field]
+        votes: PSP22VotesData,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new(total_supply: Balance) -> Self {
+            let mut instance = Self::default();
+
+            instance
+                ._mint_to(&Self::env().caller(), &total_supply)
+                .expect("Should mint");
+
+            instance
+        }
+    }
+
+    #[cfg(all(test, feature = "e2e-tests"))]
+    pub mod tests {
+        #[rustfmt::skip]
+        use super::*;
+        #[rustfmt::skip]
+        use ink_e2e::ContractsBackend;
+        use ink_e2e::account_id;
+        use ink_e2e::AccountKeyring::{Alice, Bob};
+
+        type E2EResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+        #[ink_e2e::test]
+        async fn delegating_gives_voting_power(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(1000);
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_votes",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let votes = client
+                .call(&ink_e2e::alice(), &contract.get_votes(account_id(Alice)))
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(votes, 0, "Undelegated balance has no voting power");
+
+            let delegate_tx = client
+                .call(&ink_e2e::alice(), &contract.delegate(account_id(Alice)))
+                .submit()
+                .await
+                .expect("delegate failed")
+                .return_value();
+            assert_eq!(delegate_tx, Ok(()));
+
+            let delegates = client
+                .call(&ink_e2e::alice(), &contract.delegates(account_id(Alice)))
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(delegates, Some(account_id(Alice)));
+
+            let votes = client
+                .call(&ink_e2e::alice(), &contract.get_votes(account_id(Alice)))
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(votes, 1000);
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn voting_power_moves_with_transfers(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(1000);
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_votes",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            client
+                .call(&ink_e2e::alice(), &contract.delegate(account_id(Alice)))
+                .submit()
+                .await
+                .expect("delegate failed");
+            client
+                .call(&ink_e2e::bob(), &contract.delegate(account_id(Bob)))
+                .submit()
+                .await
+                .expect("delegate failed");
+
+            let transfer_tx = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.transfer(account_id(Bob), 400, vec![]),
+                )
+                .submit()
+                .await
+                .expect("transfer failed")
+                .return_value();
+            assert_eq!(transfer_tx, Ok(()));
+
+            let alice_votes = client
+                .call(&ink_e2e::alice(), &contract.get_votes(account_id(Alice)))
+                .dry_run()
+                .await?
+                .return_value();
+            let bob_votes = client
+                .call(&ink_e2e::alice(), &contract.get_votes(account_id(Bob)))
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(alice_votes, 600);
+            assert_eq!(bob_votes, 400);
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn past_lookup_in_future_fails(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(1000);
+            let contract = client
+                .instantiate(
+                    "my_psp22_votes",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let past_votes = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.get_past_votes(account_id(Alice), u64::MAX),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(past_votes, Err(PSP22Error::FutureLookup));
+
+            let past_total_supply = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.get_past_total_supply(u64::MAX),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(past_total_supply, Err(PSP22Error::FutureLookup));
+
+            Ok(())
+        }
+    }
+}
@@ -2,7 +2,7 @@
 #![cfg_attr(not(feature = "std"), no_std, no_main)]
 
 /// Contract Summary:
-/// An PSP34 contract that allows anyone to burn any token.
+/// An PSP34 contract that allows token owners and approved operators to burn tokens.
 /// On construction create 3 tokens with ids 0, 1, 2 and mint them to the caller.
 // ########################################################
 // inject PSP34 trait's default implementation (PSP34DefaultImpl & PSP34InternalDefaultImpl)
@@ -50,7 +50,7 @@ pub mod my_psp34_burnable {
         #[rustfmt::skip]
         use super::*;
         #[rustfmt::skip]
-        use ink_e2e::AccountKeyring::Alice;
+        use ink_e2e::AccountKeyring::{Alice, Bob};
 
         use ink_e2e::account_id;
         use ink_e2e::ContractsBackend;
@@ -110,6 +110,18 @@ pub mod my_psp34_burnable {
 
             assert_eq!(balance_of!(client, contract, Alice), 3);
 
+            let result = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.approve(account_id(Bob), Some(Id::U8(0u8)), true),
+                )
+                .submit()
+                .await
+                .expect("call failed")
+                .return_value();
+
+            assert_eq!(result, Ok(()));
+
             let result = client
                 .call(
                     &ink_e2e::bob(),
@@ -125,5 +137,36 @@ pub mod my_psp34_burnable {
 
             Ok(())
         }
+
+        #[ink_e2e::test]
+        async fn burn_from_fails_without_approval(
+            mut client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new();
+            let mut contract = client
+                .instantiate(
+                    "my_psp34_burnable",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let result = client
+                .call(
+                    &ink_e2e::bob(),
+                    &contract.burn(account_id(Alice), Id::U8(0u8)),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(result, Err(PSP34Error::NotApproved));
+            assert_eq!(balance_of!(client, contract, Alice), 3);
+
+            Ok(())
+        }
     }
 }
@@ -0,0 +1,171 @@
+// SPDX-License-Identifier: MIT
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+/// Contract Summary:
+/// A PSP34 contract with mintable, burnable and enumerable modules.
+/// anyone can mint PSP34 tokens and the tokens can be listed by owner and by global index.
+// ########################################################
+// inject PSP34 trait's default implementation (PSP34DefaultImpl & PSP34InternalDefaultImpl)
+// and extensions' default implementations
+// which reduces the amount of boilerplate code required to implement trait messages drastically
+#[pendzl::implementation(PSP34, PSP34Mintable, PSP34Burnable, PSP34Enumerable)]
+#[ink::contract]
+pub mod my_psp34_enumerable {
+    use pendzl::contracts::psp34::*;
+    #[derive(Default, StorageFieldGetter)]
+    #[ink(storage)]
+    pub struct Contract {
+        #[storage_field]
+        psp34: PSP34Data,
+        #[storage_field]
+        enumerable: PSP34EnumerableData,
+    }
+
+    impl Contract {
+        /// The constructor
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+
+    #[cfg(all(test, feature = "e2e-tests"))]
+    pub mod tests {
+        #[rustfmt::skip]
+        use super::*;
+        #[rustfmt::skip]
+        use ink_e2e::AccountKeyring::{Alice, Bob};
+
+        use ink_e2e::account_id;
+        use ink_e2e::ContractsBackend;
+
+        type E2EResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+        #[ink_e2e::test]
+        async fn enumerable_follows_mint_transfer_and_burn(
+            mut client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new();
+            let mut contract = client
+                .instantiate(
+                    "my_psp34_enumerable",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            for id in [Id::U8(1), Id::U8(2), Id::U8(3)] {
+                let mint_result = client
+                    .call(
+                        &ink_e2e::alice(),
+                        &contract.mint(account_id(Alice), id),
+                    )
+                    .submit()
+                    .await?
+                    .return_value();
+                assert_eq!(mint_result, Ok(()));
+            }
+
+            for (index, id) in
+                [Id::U8(1), Id::U8(2), Id::U8(3)].into_iter().enumerate()
+            {
+                let owned = client
+                    .call(
+                        &ink_e2e::alice(),
+                        &contract.owners_token_by_index(
+                            account_id(Alice),
+                            index as u128,
+                        ),
+                    )
+                    .dry_run()
+                    .await?
+                    .return_value();
+                assert_eq!(owned, Some(id.clone()));
+                let token = client
+                    .call(
+                        &ink_e2e::alice(),
+                        &contract.token_by_index(index as u128),
+                    )
+                    .dry_run()
+                    .await?
+                    .return_value();
+                assert_eq!(token, Some(id));
+            }
+
+            let transfer_result = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.transfer(account_id(Bob), Id::U8(1), vec![]),
+                )
+                .submit()
+                .await?
+                .return_value();
+            assert_eq!(transfer_result, Ok(()));
+
+            let alice_first = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.owners_token_by_index(account_id(Alice), 0),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+            let alice_third = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.owners_token_by_index(account_id(Alice), 2),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+            let bob_first = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.owners_token_by_index(account_id(Bob), 0),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(alice_first, Some(Id::U8(3)));
+            assert_eq!(alice_third, None);
+            assert_eq!(bob_first, Some(Id::U8(1)));
+
+            let burn_result = client
+                .call(
+                    &ink_e2e::bob(),
+                    &contract.burn(account_id(Bob), Id::U8(1)),
+                )
+                .submit()
+                .await?
+                .return_value();
+            assert_eq!(burn_result, Ok(()));
+
+            let first_token = client
+                .call(&ink_e2e::alice(), &contract.token_by_index(0))
+                .dry_run()
+                .await?
+                .return_value();
+            let third_token = client
+                .call(&ink_e2e::alice(), &contract.token_by_index(2))
+                .dry_run()
+                .await?
+                .return_value();
+            let bob_first = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.owners_token_by_index(account_id(Bob), 0),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(first_token, Some(Id::U8(3)));
+            assert_eq!(third_token, None);
+            assert_eq!(bob_first, None);
+
+            Ok(())
+        }
+    }
+}
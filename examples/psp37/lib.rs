@@ -0,0 +1,237 @@
+// SPDX-License-Identifier: MIT
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+/// Contract summary:
+/// An PSP37 contract which allows everyone to mint any amount of any token type to themselves.
+// ########################################################
+// inject PSP37 trait's default implementation (PSP37DefaultImpl & PSP37InternalDefaultImpl)
+// which reduces the amount of boilerplate code required to implement trait messages drastically
+#[pendzl::implementation(PSP37)]
+#[ink::contract]
+pub mod my_psp37 {
+    use pendzl::contracts::psp37::*;
+    #[ink(storage)]
+    // derive explained below
+    #[derive(Default, StorageFieldGetter)]
+    pub struct Contract {
+        // apply the storage_field attribute so it's accessible via `self.data::<PSP37>()` (provided by StorageFieldGetter derive)
+        #[storage_field]
+        // PSP37Data is a struct that implements PSP37Storage - required by PSP37InternalDefaultImpl trait
+        // note it's not strictly required by PSP37 trait - just the default implementation
+        // name of the field is arbitrary
+        psp37: PSP37Data,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        #[ink(message)]
+        pub fn mint(
+            &mut self,
+            id: Id,
+            amount: Balance,
+        ) -> Result<(), PSP37Error> {
+            // use _mint_to to mint tokens to the caller from PSP37Internal (implemented by PSP37DefaultImpl)
+            self._mint_to(&Self::env().caller(), &vec![(id, amount)])
+        }
+    }
+
+    #[cfg(all(test, feature = "e2e-tests"))]
+    pub mod tests {
+        #[rustfmt::skip]
+        use super::*;
+        #[rustfmt::skip]
+        use ink_e2e::ContractsBackend;
+        use ink_e2e::account_id;
+        use ink_e2e::AccountKeyring::{Alice, Bob};
+
+        type E2EResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+        #[ink_e2e::test]
+        async fn mint_and_transfer_works(
+            mut client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new();
+            let mut contract = client
+                .instantiate("my_psp37", &ink_e2e::alice(), &mut constructor)
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let id = Id::U8(1);
+            let mint_result = client
+                .call(&ink_e2e::alice(), &contract.mint(id.clone(), 100))
+                .submit()
+                .await?
+                .return_value();
+            assert_eq!(mint_result, Ok(()));
+
+            let transfer_result = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.transfer(account_id(Bob), id.clone(), 40, vec![]),
+                )
+                .submit()
+                .await?
+                .return_value();
+            assert_eq!(transfer_result, Ok(()));
+
+            let alice_balance = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.balance_of(account_id(Alice), Some(id.clone())),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+            let bob_balance = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.balance_of(account_id(Bob), Some(id.clone())),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+            let total_supply = client
+                .call(&ink_e2e::alice(), &contract.total_supply(Some(id)))
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(alice_balance, 60);
+            assert_eq!(bob_balance, 40);
+            assert_eq!(total_supply, 100);
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn transfer_from_requires_allowance(
+            mut client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new();
+            let mut contract = client
+                .instantiate("my_psp37", &ink_e2e::alice(), &mut constructor)
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let id = Id::U8(1);
+            client
+                .call(&ink_e2e::alice(), &contract.mint(id.clone(), 100))
+                .submit()
+                .await?;
+
+            let transfer_from_result = client
+                .call(
+                    &ink_e2e::bob(),
+                    &contract.transfer_from(
+                        account_id(Alice),
+                        account_id(Bob),
+                        id.clone(),
+                        10,
+                        vec![],
+                    ),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(transfer_from_result, Err(PSP37Error::NotAllowed));
+
+            let approve_result = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.approve(account_id(Bob), None, 1),
+                )
+                .submit()
+                .await?
+                .return_value();
+            assert_eq!(approve_result, Ok(()));
+
+            let allowance = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.allowance(
+                        account_id(Alice),
+                        account_id(Bob),
+                        Some(id.clone()),
+                    ),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(allowance, Balance::MAX);
+
+            let transfer_from_result = client
+                .call(
+                    &ink_e2e::bob(),
+                    &contract.transfer_from(
+                        account_id(Alice),
+                        account_id(Bob),
+                        id,
+                        10,
+                        vec![],
+                    ),
+                )
+                .submit()
+                .await?
+                .return_value();
+            assert_eq!(transfer_from_result, Ok(()));
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn transfer_to_contract_without_receiver_works(
+            mut client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new();
+            let mut contract = client
+                .instantiate("my_psp37", &ink_e2e::alice(), &mut constructor)
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+            // a PSP37 contract doesn't implement PSP37Receiver
+            let recipient = client
+                .instantiate("my_psp37", &ink_e2e::bob(), &mut constructor)
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .account_id;
+
+            let id = Id::U8(1);
+            client
+                .call(&ink_e2e::alice(), &contract.mint(id.clone(), 100))
+                .submit()
+                .await?;
+
+            let transfer_result = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.transfer(recipient, id.clone(), 40, vec![]),
+                )
+                .submit()
+                .await?
+                .return_value();
+            assert_eq!(transfer_result, Ok(()));
+
+            let recipient_balance = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.balance_of(recipient, Some(id)),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(recipient_balance, 40);
+
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,169 @@
+// SPDX-License-Identifier: MIT
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+/// Contract Summary:
+/// A PSP37 contract with batch, mintable, burnable and enumerable modules.
+/// anyone can mint PSP37 tokens and the owned token types can be listed.
+// ########################################################
+// inject PSP37 trait's default implementation (PSP37DefaultImpl & PSP37InternalDefaultImpl)
+// and extensions' default implementations
+// which reduces the amount of boilerplate code required to implement trait messages drastically
+#[pendzl::implementation(
+    PSP37,
+    PSP37Batch,
+    PSP37Mintable,
+    PSP37Burnable,
+    PSP37Enumerable
+)]
+#[ink::contract]
+pub mod my_psp37_enumerable {
+    use pendzl::contracts::psp37::*;
+    #[derive(Default, StorageFieldGetter)]
+    #[ink(storage)]
+    pub struct Contract {
+        #[storage_field]
+        psp37: PSP37Data,
+        #[storage_field]
+        enumerable: PSP37EnumerableData,
+    }
+
+    impl Contract {
+        /// The constructor
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+
+    #[cfg(all(test, feature = "e2e-tests"))]
+    pub mod tests {
+        #[rustfmt::skip]
+        use super::*;
+        #[rustfmt::skip]
+        use ink_e2e::AccountKeyring::{Alice, Bob};
+
+        use ink_e2e::account_id;
+        use ink_e2e::ContractsBackend;
+
+        type E2EResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+        #[ink_e2e::test]
+        async fn enumerable_follows_balances(
+            mut client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new();
+            let mut contract = client
+                .instantiate(
+                    "my_psp37_enumerable",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let ids_amounts = vec![(Id::U8(1), 10), (Id::U8(2), 20)];
+            let mint_result = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.mint(account_id(Alice), ids_amounts),
+                )
+                .submit()
+                .await?
+                .return_value();
+            assert_eq!(mint_result, Ok(()));
+
+            for (index, id) in [Id::U8(1), Id::U8(2)].into_iter().enumerate() {
+                let owned = client
+                    .call(
+                        &ink_e2e::alice(),
+                        &contract.owners_token_by_index(
+                            account_id(Alice),
+                            index as u128,
+                        ),
+                    )
+                    .dry_run()
+                    .await?
+                    .return_value();
+                assert_eq!(owned, Some(id.clone()));
+                let token = client
+                    .call(
+                        &ink_e2e::alice(),
+                        &contract.token_by_index(index as u128),
+                    )
+                    .dry_run()
+                    .await?
+                    .return_value();
+                assert_eq!(token, Some(id));
+            }
+
+            let transfer_result = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.batch_transfer(
+                        account_id(Bob),
+                        vec![(Id::U8(1), 10)],
+                        vec![],
+                    ),
+                )
+                .submit()
+                .await?
+                .return_value();
+            assert_eq!(transfer_result, Ok(()));
+
+            let alice_first = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.owners_token_by_index(account_id(Alice), 0),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+            let alice_second = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.owners_token_by_index(account_id(Alice), 1),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+            let bob_first = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.owners_token_by_index(account_id(Bob), 0),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(alice_first, Some(Id::U8(2)));
+            assert_eq!(alice_second, None);
+            assert_eq!(bob_first, Some(Id::U8(1)));
+
+            let burn_result = client
+                .call(
+                    &ink_e2e::bob(),
+                    &contract.burn(account_id(Bob), vec![(Id::U8(1), 10)]),
+                )
+                .submit()
+                .await?
+                .return_value();
+            assert_eq!(burn_result, Ok(()));
+
+            let first_token = client
+                .call(&ink_e2e::alice(), &contract.token_by_index(0))
+                .dry_run()
+                .await?
+                .return_value();
+            let second_token = client
+                .call(&ink_e2e::alice(), &contract.token_by_index(1))
+                .dry_run()
+                .await?
+                .return_value();
+            assert_eq!(first_token, Some(Id::U8(2)));
+            assert_eq!(second_token, None);
+
+            Ok(())
+        }
+    }
+}
@@ -27,12 +27,27 @@ use syn::{Item, Path};
 /// - A `TokenStream` containing the modified module with injected implementations and imports.
 pub fn generate(attrs: TokenStream, ink_module: TokenStream) -> TokenStream {
     // map attribute args to provide default impls
-    let to_inject_default_impls_vec = syn::parse2::<AttributeArgs>(attrs)
+    let mut to_inject_default_impls_vec = syn::parse2::<AttributeArgs>(attrs)
         .expect("No traits to provide default impls for provided")
         .iter()
         .map(|method| method.to_token_stream().to_string().replace(' ', ""))
         .collect::<Vec<String>>();
 
+    // base standards and access modules are implemented first so extensions can extend or depend on them,
+    // extensions adjusting other extensions are implemented last
+    // and PSP22VaultAsync wraps the total assets after PSP22VaultNative replaced them,
+    // PSP22VaultAsync and PSP22VaultSlippage check for PSP22VaultNative after it was implemented
+    to_inject_default_impls_vec.sort_by_key(|name| match name.as_str() {
+        "PSP22" | "PSP34" | "PSP37" | "Ownable" | "AccessControl" => 0,
+        "PSP22Capped"
+        | "PSP22TransferFee"
+        | "PSP22MinterAllowance"
+        | "PSP22VaultFees"
+        | "PSP22VaultNative" => 2,
+        "PSP22VaultAsync" | "PSP22VaultSlippage" => 3,
+        _ => 1,
+    });
+
     let mut module = syn::parse2::<syn::ItemMod>(ink_module)
         .expect("Can't parse contract module");
     let (braces, items) = match module.clone().content {
@@ -70,10 +85,44 @@ pub fn generate(attrs: TokenStream, ink_module: TokenStream) -> TokenStream {
             "PSP22Mintable" => impl_psp22_mintable(&mut impl_args),
             "PSP22Vault" => impl_psp22_vault(&mut impl_args),
             "PSP22Metadata" => impl_psp22_metadata(&mut impl_args),
+            "PSP22Permit" => impl_psp22_permit(&mut impl_args),
+            "PSP22Capped" => impl_psp22_capped(&mut impl_args),
+            "PSP22Votes" => impl_psp22_votes(&mut impl_args),
+            "PSP22Snapshot" => impl_psp22_snapshot(&mut impl_args),
+            "PSP22FlashLender" => impl_psp22_flash_lender(&mut impl_args),
+            "PSP22Wrapper" => impl_psp22_wrapper(&mut impl_args),
+            "PSP22NativeWrapper" => {
+                impl_psp22_native_wrapper(&mut impl_args)
+            }
+            "PSP22TransferFee" => impl_psp22_transfer_fee(&mut impl_args),
+            "PSP22Compliance" => impl_psp22_compliance(&mut impl_args),
+            "PSP22Lockable" => impl_psp22_lockable(&mut impl_args),
+            "PSP22Bridgeable" => impl_psp22_bridgeable(&mut impl_args),
+            "PSP22MinterAllowance" => {
+                impl_psp22_minter_allowance(&mut impl_args)
+            }
+            "PSP22Rebasing" => impl_psp22_rebasing(&mut impl_args),
+            "PSP22Batch" => impl_psp22_batch(&mut impl_args),
+            "PSP22ExpiringAllowance" => {
+                impl_psp22_expiring_allowance(&mut impl_args)
+            }
+            "PSP22VaultFees" => impl_psp22_vault_fees(&mut impl_args),
+            "PSP22VaultNative" => impl_psp22_vault_native(&mut impl_args),
+            "PSP22VaultAsync" => impl_psp22_vault_async(&mut impl_args),
+            "PSP22VaultSlippage" => {
+                impl_psp22_vault_slippage(&mut impl_args)
+            }
             "PSP34" => impl_psp34(&mut impl_args),
             "PSP34Burnable" => impl_psp34_burnable(&mut impl_args),
+            "PSP34Enumerable" => impl_psp34_enumerable(&mut impl_args),
             "PSP34Metadata" => impl_psp34_metadata(&mut impl_args),
             "PSP34Mintable" => impl_psp34_mintable(&mut impl_args),
+            "PSP37" => impl_psp37(&mut impl_args),
+            "PSP37Batch" => impl_psp37_batch(&mut impl_args),
+            "PSP37Burnable" => impl_psp37_burnable(&mut impl_args),
+            "PSP37Enumerable" => impl_psp37_enumerable(&mut impl_args),
+            "PSP37Metadata" => impl_psp37_metadata(&mut impl_args),
+            "PSP37Mintable" => impl_psp37_mintable(&mut impl_args),
             "Ownable" => impl_ownable(&mut impl_args),
             "AccessControl" => impl_access_control(&mut impl_args),
             "Pausable" => impl_pausable(&mut impl_args),
@@ -137,12 +186,44 @@ fn cleanup_imports(imports: &mut HashMap<&str, syn::ItemUse>) {
         "PSP22Burnable",
         "PSP22Metadata",
         "PSP22Vault",
+        "PSP22Permit",
+        "PSP22Capped",
+        "PSP22Votes",
+        "PSP22Snapshot",
+        "PSP22FlashLender",
+        "PSP22Wrapper",
+        "PSP22NativeWrapper",
+        "PSP22TransferFee",
+        "PSP22Compliance",
+        "PSP22Lockable",
+        "PSP22Bridgeable",
+        "PSP22MinterAllowance",
+        "PSP22Rebasing",
+        "PSP22Batch",
+        "PSP22ExpiringAllowance",
+        "PSP22VaultFees",
+        "PSP22VaultNative",
+        "PSP22VaultAsync",
+        "PSP22VaultSlippage",
     ];
     check_and_remove_import("PSP22", psp22_default_impls, imports);
 
-    let psp34_default_impls =
-        vec!["PSP34Mintable", "PSP34Burnable", "PSP34Metadata"];
+    let psp34_default_impls = vec![
+        "PSP34Mintable",
+        "PSP34Burnable",
+        "PSP34Metadata",
+        "PSP34Enumerable",
+    ];
     check_and_remove_import("PSP34", psp34_default_impls, imports);
+
+    let psp37_default_impls = vec![
+        "PSP37Batch",
+        "PSP37Burnable",
+        "PSP37Enumerable",
+        "PSP37Metadata",
+        "PSP37Mintable",
+    ];
+    check_and_remove_import("PSP37", psp37_default_impls, imports);
 }
 
 /// Extracts and removes functions annotated with `#[overrider(trait_name)]` from a list of items,
@@ -114,8 +114,20 @@ pub(crate) fn impl_psp22(impl_args: &mut ImplArgs) {
                 from: &AccountId,
                 to: &AccountId,
                 amount: &Balance,
+                data: &Vec<u8>,
             ) -> Result<(), PSP22Error> {
-                pendzl::contracts::psp22::PSP22Internal::_update(self, Some(from), Some(to), amount)
+                pendzl::contracts::psp22::PSP22Internal::_update(self, Some(from), Some(to), amount)?;
+                pendzl::contracts::psp22::PSP22Internal::_do_safe_transfer_check(self, from, to, amount, data)
+            }
+
+            fn _do_safe_transfer_check(
+                &mut self,
+                from: &AccountId,
+                to: &AccountId,
+                amount: &Balance,
+                data: &Vec<u8>,
+            ) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::PSP22InternalDefaultImpl::_do_safe_transfer_check_default_impl(self, from, to, amount, data)
             }
 
             fn _mint_to(
@@ -149,7 +161,14 @@ pub(crate) fn impl_psp22(impl_args: &mut ImplArgs) {
                 spender: &AccountId,
                 amount: &Balance,
             ) -> Result<(), PSP22Error> {
-                pendzl::contracts::psp22::PSP22InternalDefaultImpl::_decrease_allowance_from_to_default_impl(self, owner, spender, amount)
+                // infinite allowances are not decreased when spent
+                if pendzl::contracts::psp22::PSP22Internal::_infinite_allowance_enabled(self)
+                    && pendzl::contracts::psp22::PSP22Internal::_allowance(self, owner, spender) == Balance::MAX
+                {
+                    Ok(())
+                } else {
+                    pendzl::contracts::psp22::PSP22InternalDefaultImpl::_decrease_allowance_from_to_default_impl(self, owner, spender, amount)
+                }
             }
 
             fn _increase_allowance_from_to(
@@ -161,6 +180,10 @@ pub(crate) fn impl_psp22(impl_args: &mut ImplArgs) {
                 pendzl::contracts::psp22::PSP22InternalDefaultImpl::_increase_allowance_from_to_default_impl(self, owner, spender, amount)
 
             }
+
+            fn _infinite_allowance_enabled(&self) -> bool {
+                pendzl::contracts::psp22::PSP22InternalDefaultImpl::_infinite_allowance_enabled_default_impl(self)
+            }
         }
     ))
     .expect(DEFAULT_PARSE_ERR_MSG);
@@ -418,6 +441,14 @@ pub(crate) fn impl_psp22_vault(impl_args: &mut ImplArgs) {
                 pendzl::contracts::psp22::vault::PSP22VaultInternalDefaultImpl::_preview_redeem_default_impl(self, shares)
             }
 
+            fn _receive_assets(&mut self, from: &AccountId, assets: &Balance) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::vault::PSP22VaultInternalDefaultImpl::_receive_assets_default_impl(self, from, assets)
+            }
+
+            fn _send_assets(&mut self, to: &AccountId, assets: &Balance) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::vault::PSP22VaultInternalDefaultImpl::_send_assets_default_impl(self, to, assets)
+            }
+
             fn _deposit(
                 &mut self,
                 caller: &AccountId,
@@ -438,6 +469,48 @@ pub(crate) fn impl_psp22_vault(impl_args: &mut ImplArgs) {
             ) -> Result<(), PSP22Error> {
                 pendzl::contracts::psp22::vault::PSP22VaultInternalDefaultImpl::_withdraw_default_impl(self, caller, receiver, owner, assets, shares)
             }
+
+            fn _deposit_checked(
+                &mut self,
+                caller: &AccountId,
+                receiver: &AccountId,
+                assets: &Balance,
+                min_shares: &Balance,
+            ) -> Result<Balance, PSP22Error> {
+                pendzl::contracts::psp22::vault::PSP22VaultInternalDefaultImpl::_deposit_checked_default_impl(self, caller, receiver, assets, min_shares)
+            }
+
+            fn _mint_checked(
+                &mut self,
+                caller: &AccountId,
+                receiver: &AccountId,
+                shares: &Balance,
+                max_assets: &Balance,
+            ) -> Result<Balance, PSP22Error> {
+                pendzl::contracts::psp22::vault::PSP22VaultInternalDefaultImpl::_mint_checked_default_impl(self, caller, receiver, shares, max_assets)
+            }
+
+            fn _withdraw_checked(
+                &mut self,
+                caller: &AccountId,
+                receiver: &AccountId,
+                owner: &AccountId,
+                assets: &Balance,
+                max_shares: &Balance,
+            ) -> Result<Balance, PSP22Error> {
+                pendzl::contracts::psp22::vault::PSP22VaultInternalDefaultImpl::_withdraw_checked_default_impl(self, caller, receiver, owner, assets, max_shares)
+            }
+
+            fn _redeem_checked(
+                &mut self,
+                caller: &AccountId,
+                receiver: &AccountId,
+                owner: &AccountId,
+                shares: &Balance,
+                min_assets: &Balance,
+            ) -> Result<Balance, PSP22Error> {
+                pendzl::contracts::psp22::vault::PSP22VaultInternalDefaultImpl::_redeem_checked_default_impl(self, caller, receiver, owner, shares, min_assets)
+            }
         }
     ))
     .expect(DEFAULT_PARSE_ERR_MSG);
@@ -565,6 +638,105 @@ pub(crate) fn impl_psp22_vault(impl_args: &mut ImplArgs) {
     impl_args.items.push(syn::Item::Impl(psp22_vault));
 }
 
+pub(crate) fn impl_psp22_permit(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::permit::PSP22PermitInternalDefaultImpl for #storage_struct_name {}
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::permit::PSP22PermitInternal for #storage_struct_name {
+            fn _nonces(&self, owner: &AccountId) -> u64 {
+                pendzl::contracts::psp22::permit::PSP22PermitInternalDefaultImpl::_nonces_default_impl(self, owner)
+            }
+
+            fn _use_nonce(&mut self, owner: &AccountId) -> Result<u64, PSP22Error> {
+                pendzl::contracts::psp22::permit::PSP22PermitInternalDefaultImpl::_use_nonce_default_impl(self, owner)
+            }
+
+            fn _domain_separator(&self) -> [u8; 32] {
+                pendzl::contracts::psp22::permit::PSP22PermitInternalDefaultImpl::_domain_separator_default_impl(self)
+            }
+
+            fn _verify_signature(
+                &self,
+                signer: &AccountId,
+                message_hash: &[u8; 32],
+                signature: &PermitSignature,
+            ) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::permit::PSP22PermitInternalDefaultImpl::_verify_signature_default_impl(self, signer, message_hash, signature)
+            }
+
+            fn _permit(
+                &mut self,
+                owner: &AccountId,
+                spender: &AccountId,
+                value: &Balance,
+                deadline: &Timestamp,
+                signature: &PermitSignature,
+            ) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::permit::PSP22PermitInternalDefaultImpl::_permit_default_impl(self, owner, spender, value, deadline, signature)
+            }
+        }
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let permit_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::permit::PSP22PermitDefaultImpl for #storage_struct_name {}
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let mut permit = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::permit::PSP22Permit for #storage_struct_name {
+            #[ink(message)]
+            fn permit(
+                &mut self,
+                owner: AccountId,
+                spender: AccountId,
+                value: Balance,
+                deadline: Timestamp,
+                signature: PermitSignature,
+            ) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::permit::PSP22PermitDefaultImpl::permit_default_impl(self, owner, spender, value, deadline, signature)
+            }
+
+            #[ink(message)]
+            fn nonces(&self, owner: AccountId) -> u64 {
+                pendzl::contracts::psp22::permit::PSP22PermitDefaultImpl::nonces_default_impl(self, owner)
+            }
+
+            #[ink(message)]
+            fn domain_separator(&self) -> [u8; 32] {
+                pendzl::contracts::psp22::permit::PSP22PermitDefaultImpl::domain_separator_default_impl(self)
+            }
+        }
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::psp22::permit::*;
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let import_data = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::psp22::permit::PSP22PermitData;
+    ))
+    .expect(DEFAULT_PARSE_IMPORT_ERR_MSG);
+
+    impl_args.imports.insert("PSP22Permit", import);
+    impl_args.imports.insert("PSP22PermitData", import_data);
+    impl_args.vec_import();
+
+    override_functions("PSP22PermitInternal", &mut internal, impl_args.map);
+    override_functions("PSP22Permit", &mut permit, impl_args.map);
+
+    impl_args.items.push(syn::Item::Impl(internal_default_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(permit_default_impl));
+    impl_args.items.push(syn::Item::Impl(permit));
+}
+
 pub(crate) fn impl_psp34(impl_args: &mut ImplArgs) {
     let storage_struct_name = impl_args.contract_name();
     let internal_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
@@ -605,17 +777,23 @@ pub(crate) fn impl_psp34(impl_args: &mut ImplArgs) {
             }
 
             fn _transfer(&mut self, from: &AccountId, to: &AccountId, id: &Id, data: &Vec<u8>) -> Result<(), PSP34Error> {
-                pendzl::contracts::psp34::PSP34Internal::_update(self, &Some(from), &Some(to), id)
+                pendzl::contracts::psp34::PSP34Internal::_update(self, &Some(from), &Some(to), id)?;
+                pendzl::contracts::psp34::PSP34Internal::_do_safe_transfer_check(self, &Some(from), to, id, data)
             }
 
             fn _mint_to(&mut self, to: &AccountId, id: &Id) -> Result<(), PSP34Error> {
-                pendzl::contracts::psp34::PSP34Internal::_update(self, &None, &Some(to), id)
+                pendzl::contracts::psp34::PSP34Internal::_update(self, &None, &Some(to), id)?;
+                pendzl::contracts::psp34::PSP34Internal::_do_safe_transfer_check(self, &None, to, id, &Vec::new())
             }
 
             fn _burn_from(&mut self, from: &AccountId, id: &Id) -> Result<(), PSP34Error> {
                 pendzl::contracts::psp34::PSP34Internal::_update(self, &Some(from), &None, id)
             }
 
+            fn _do_safe_transfer_check(&mut self, from: &Option<&AccountId>, to: &AccountId, id: &Id, data: &Vec<u8>) -> Result<(), PSP34Error> {
+                pendzl::contracts::psp34::PSP34InternalDefaultImpl::_do_safe_transfer_check_default_impl(self, from, to, id, data)
+            }
+
         }
     ))
     .expect(DEFAULT_PARSE_ERR_MSG);
@@ -807,262 +985,2804 @@ pub(crate) fn impl_psp34_metadata(impl_args: &mut ImplArgs) {
     impl_args.items.push(syn::Item::Impl(metadata));
 }
 
-pub(crate) fn impl_ownable(impl_args: &mut ImplArgs) {
+pub(crate) fn impl_psp22_capped(impl_args: &mut ImplArgs) {
     let storage_struct_name = impl_args.contract_name();
     let internal_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
-        impl pendzl::contracts::ownable::OwnableInternalDefaultImpl for #storage_struct_name {}
+        impl pendzl::contracts::psp22::capped::PSP22CappedInternalDefaultImpl for #storage_struct_name {}
     ))
     .expect(DEFAULT_PARSE_ERR_MSG);
 
     let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
-        impl pendzl::contracts::ownable::OwnableInternal for #storage_struct_name {
-            fn _owner(&self) -> Option<AccountId>{
-                pendzl::contracts::ownable::OwnableInternalDefaultImpl::_owner_default_impl(self)
+        impl pendzl::contracts::psp22::capped::PSP22CappedInternal for #storage_struct_name {
+            fn _cap(&self) -> Balance {
+                pendzl::contracts::psp22::capped::PSP22CappedInternalDefaultImpl::_cap_default_impl(self)
             }
-            fn _update_owner(&mut self, owner: &Option<AccountId>){
-                pendzl::contracts::ownable::OwnableInternalDefaultImpl::_update_owner_default_impl(self, owner);
 
+            fn _set_cap(&mut self, cap: &Balance) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::capped::PSP22CappedInternalDefaultImpl::_set_cap_default_impl(self, cap)
             }
-            fn _only_owner(&self) -> Result<(), OwnableError> {
-                pendzl::contracts::ownable::OwnableInternalDefaultImpl::_only_owner_default_impl(self)
+
+            fn _check_cap(
+                &self,
+                from: Option<&AccountId>,
+                to: Option<&AccountId>,
+                amount: &Balance,
+            ) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::capped::PSP22CappedInternalDefaultImpl::_check_cap_default_impl(self, from, to, amount)
             }
         }
     ))
     .expect(DEFAULT_PARSE_ERR_MSG);
 
-    let ownable_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
-        impl pendzl::contracts::ownable::OwnableDefaultImpl for #storage_struct_name {}
+    let capped_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::capped::PSP22CappedDefaultImpl for #storage_struct_name {}
     ))
     .expect(DEFAULT_PARSE_ERR_MSG);
 
-    let mut ownable = syn::parse2::<syn::ItemImpl>(quote!(
-        impl pendzl::contracts::ownable::Ownable for #storage_struct_name {
-            #[ink(message)]
-            fn owner(&self) -> Option<AccountId> {
-                pendzl::contracts::ownable::OwnableDefaultImpl::owner_default_impl(self)
-            }
-
-            #[ink(message)]
-            fn renounce_ownership(&mut self) -> Result<(), OwnableError> {
-                pendzl::contracts::ownable::OwnableDefaultImpl::renounce_ownership_default_impl(self)
-            }
-
+    let mut capped = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::capped::PSP22Capped for #storage_struct_name {
             #[ink(message)]
-            fn transfer_ownership(&mut self, new_owner: AccountId) -> Result<(), OwnableError> {
-                pendzl::contracts::ownable::OwnableDefaultImpl::transfer_ownership_default_impl(self, new_owner)
+            fn cap(&self) -> Balance {
+                pendzl::contracts::psp22::capped::PSP22CappedDefaultImpl::cap_default_impl(self)
             }
         }
     ))
     .expect(DEFAULT_PARSE_ERR_MSG);
 
     let import = syn::parse2::<syn::ItemUse>(quote!(
-        pub use pendzl::contracts::ownable::*;
+        pub use pendzl::contracts::psp22::capped::*;
     ))
     .expect(DEFAULT_PARSE_ERR_MSG);
 
     let import_data = syn::parse2::<syn::ItemUse>(quote!(
-        pub use pendzl::contracts::ownable::OwnableData;
+        pub use pendzl::contracts::psp22::capped::PSP22CappedData;
     ))
     .expect(DEFAULT_PARSE_IMPORT_ERR_MSG);
 
-    impl_args.imports.insert("Ownable", import);
-    impl_args.imports.insert("OwnableData", import_data);
+    impl_args.imports.insert("PSP22Capped", import);
+    impl_args.imports.insert("PSP22CappedData", import_data);
+    impl_args.vec_import();
 
-    override_functions("OwnableInternal", &mut internal, impl_args.map);
-    override_functions("Ownable", &mut ownable, impl_args.map);
+    override_functions("PSP22CappedInternal", &mut internal, impl_args.map);
+    override_functions("PSP22Capped", &mut capped, impl_args.map);
+
+    // every mint (PSP22Mintable, PSP22Vault, ...) goes through _update
+    extend_function(
+        impl_args.items,
+        "PSP22Internal",
+        "_update",
+        quote!(
+            pendzl::contracts::psp22::capped::PSP22CappedInternal::_check_cap(self, from, to, amount)?;
+        ),
+        quote!(),
+    );
+
+    // vault can not mint more shares than the cap allows
+    if let Some(max_mint) =
+        find_function(impl_args.items, "PSP22VaultInternal", "_max_mint")
+    {
+        let block = max_mint.block.clone();
+        max_mint.block = syn::parse2::<Block>(quote!({
+            let max_mint: Balance = #block;
+            let remaining = pendzl::contracts::psp22::capped::PSP22CappedInternal::_cap(self)
+                .saturating_sub(pendzl::contracts::psp22::PSP22Internal::_total_supply(self));
+            core::cmp::min(max_mint, remaining)
+        }))
+        .expect(DEFAULT_PARSE_ERR_MSG);
+    }
+    if let Some(max_deposit) =
+        find_function(impl_args.items, "PSP22VaultInternal", "_max_deposit")
+    {
+        let block = max_deposit.block.clone();
+        max_deposit.block = syn::parse2::<Block>(quote!({
+            let max_deposit: Balance = #block;
+            let remaining = pendzl::contracts::psp22::capped::PSP22CappedInternal::_cap(self)
+                .saturating_sub(pendzl::contracts::psp22::PSP22Internal::_total_supply(self));
+            let remaining_assets = pendzl::contracts::psp22::vault::PSP22VaultInternal::_convert_to_assets(
+                self,
+                &remaining,
+                pendzl::math::operations::Rounding::Down,
+            )
+            .unwrap_or(Balance::MAX);
+            core::cmp::min(max_deposit, remaining_assets)
+        }))
+        .expect(DEFAULT_PARSE_ERR_MSG);
+    }
 
     impl_args.items.push(syn::Item::Impl(internal_default_impl));
     impl_args.items.push(syn::Item::Impl(internal));
-    impl_args.items.push(syn::Item::Impl(ownable_default_impl));
-    impl_args.items.push(syn::Item::Impl(ownable));
+    impl_args.items.push(syn::Item::Impl(capped_default_impl));
+    impl_args.items.push(syn::Item::Impl(capped));
 }
 
-pub(crate) fn impl_access_control(impl_args: &mut ImplArgs) {
+pub(crate) fn impl_psp22_votes(impl_args: &mut ImplArgs) {
     let storage_struct_name = impl_args.contract_name();
     let internal_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
-        impl pendzl::contracts::access_control::AccessControlInternalDefaultImpl for #storage_struct_name {}
+        impl pendzl::contracts::psp22::votes::PSP22VotesInternalDefaultImpl for #storage_struct_name {}
     ))
     .expect(DEFAULT_PARSE_ERR_MSG);
 
     let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
-        impl pendzl::contracts::access_control::AccessControlInternal for #storage_struct_name {
-            fn _default_admin() -> RoleType {
-                <Self as pendzl::contracts::access_control::AccessControlInternalDefaultImpl>::_default_admin_default_impl()
+        impl pendzl::contracts::psp22::votes::PSP22VotesInternal for #storage_struct_name {
+            fn _delegates(&self, account: &AccountId) -> Option<AccountId> {
+                pendzl::contracts::psp22::votes::PSP22VotesInternalDefaultImpl::_delegates_default_impl(self, account)
             }
 
-            fn _has_role(&self, role: RoleType, account: Option<AccountId>) -> bool{
-                pendzl::contracts::access_control::AccessControlInternalDefaultImpl::_has_role_default_impl(self, role, account)
+            fn _get_votes(&self, account: &AccountId) -> Balance {
+                pendzl::contracts::psp22::votes::PSP22VotesInternalDefaultImpl::_get_votes_default_impl(self, account)
             }
 
-            fn _grant_role(&mut self, role: RoleType, account: Option<AccountId>) -> Result<(), AccessControlError> {
-                pendzl::contracts::access_control::AccessControlInternalDefaultImpl::_grant_role_default_impl(self, role, account)
+            fn _get_past_votes(&self, account: &AccountId, timestamp: &Timestamp) -> Result<Balance, PSP22Error> {
+                pendzl::contracts::psp22::votes::PSP22VotesInternalDefaultImpl::_get_past_votes_default_impl(self, account, timestamp)
             }
 
-            fn _do_revoke_role(&mut self, role: RoleType, account: Option<AccountId>)  -> Result<(), AccessControlError>  {
-                pendzl::contracts::access_control::AccessControlInternalDefaultImpl::_do_revoke_role_default_impl(self, role, account)
+            fn _get_past_total_supply(&self, timestamp: &Timestamp) -> Result<Balance, PSP22Error> {
+                pendzl::contracts::psp22::votes::PSP22VotesInternalDefaultImpl::_get_past_total_supply_default_impl(self, timestamp)
             }
 
-            fn _get_role_admin(&self, role: RoleType) -> RoleType {
-                pendzl::contracts::access_control::AccessControlInternalDefaultImpl::_get_role_admin_default_impl(self, role)
+            fn _delegate(&mut self, account: &AccountId, delegatee: &AccountId) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::votes::PSP22VotesInternalDefaultImpl::_delegate_default_impl(self, account, delegatee)
             }
 
-            fn _set_role_admin(&mut self, role: RoleType, new_admin: RoleType) {
-                pendzl::contracts::access_control::AccessControlInternalDefaultImpl::_set_role_admin_default_impl(self, role, new_admin);
+            fn _move_delegate_votes(
+                &mut self,
+                from: Option<&AccountId>,
+                to: Option<&AccountId>,
+                amount: &Balance,
+            ) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::votes::PSP22VotesInternalDefaultImpl::_move_delegate_votes_default_impl(self, from, to, amount)
             }
 
-            fn _ensure_has_role(&self, role: RoleType, account: Option<AccountId>) -> Result<(), AccessControlError> {
-                pendzl::contracts::access_control::AccessControlInternalDefaultImpl::_ensure_has_role_default_impl(self, role, account)
+            fn _update_votes(
+                &mut self,
+                from: Option<&AccountId>,
+                to: Option<&AccountId>,
+                amount: &Balance,
+            ) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::votes::PSP22VotesInternalDefaultImpl::_update_votes_default_impl(self, from, to, amount)
             }
-
         }
     ))
     .expect(DEFAULT_PARSE_ERR_MSG);
 
-    let access_control_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
-        impl pendzl::contracts::access_control::AccessControlDefaultImpl for #storage_struct_name {}
+    let votes_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::votes::PSP22VotesDefaultImpl for #storage_struct_name {}
     ))
     .expect(DEFAULT_PARSE_ERR_MSG);
 
-    let mut access_control = syn::parse2::<syn::ItemImpl>(quote!(
-        impl pendzl::contracts::access_control::AccessControl for #storage_struct_name {
-            #[ink(message)]
-            fn has_role(&self, role: RoleType, address: Option<AccountId>) -> bool {
-                pendzl::contracts::access_control::AccessControlDefaultImpl::has_role_default_impl(self, role, address)
-            }
-
+    let mut votes = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::votes::PSP22Votes for #storage_struct_name {
             #[ink(message)]
-            fn get_role_admin(&self, role: RoleType) -> RoleType {
-                pendzl::contracts::access_control::AccessControlDefaultImpl::get_role_admin_default_impl(self, role)
+            fn delegate(&mut self, delegatee: AccountId) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::votes::PSP22VotesDefaultImpl::delegate_default_impl(self, delegatee)
             }
 
             #[ink(message)]
-            fn grant_role(&mut self, role: RoleType, account: Option<AccountId>) -> Result<(), AccessControlError> {
-                pendzl::contracts::access_control::AccessControlDefaultImpl::grant_role_default_impl(self, role, account)
+            fn delegates(&self, account: AccountId) -> Option<AccountId> {
+                pendzl::contracts::psp22::votes::PSP22VotesDefaultImpl::delegates_default_impl(self, account)
             }
 
             #[ink(message)]
-            fn revoke_role(&mut self, role: RoleType, account: Option<AccountId>) -> Result<(), AccessControlError> {
-                pendzl::contracts::access_control::AccessControlDefaultImpl::revoke_role_default_impl(self, role, account)
+            fn get_votes(&self, account: AccountId) -> Balance {
+                pendzl::contracts::psp22::votes::PSP22VotesDefaultImpl::get_votes_default_impl(self, account)
             }
 
             #[ink(message)]
-            fn renounce_role(&mut self, role: RoleType, account: Option<AccountId>) -> Result<(), AccessControlError> {
-                pendzl::contracts::access_control::AccessControlDefaultImpl::renounce_role_default_impl(self, role, account)
+            fn get_past_votes(&self, account: AccountId, timestamp: Timestamp) -> Result<Balance, PSP22Error> {
+                pendzl::contracts::psp22::votes::PSP22VotesDefaultImpl::get_past_votes_default_impl(self, account, timestamp)
             }
 
             #[ink(message)]
-            fn set_role_admin(&mut self, role: RoleType, new_admin: RoleType) -> Result<(), AccessControlError> {
-                pendzl::contracts::access_control::AccessControlDefaultImpl::set_role_admin_default_impl(self, role, new_admin)
+            fn get_past_total_supply(&self, timestamp: Timestamp) -> Result<Balance, PSP22Error> {
+                pendzl::contracts::psp22::votes::PSP22VotesDefaultImpl::get_past_total_supply_default_impl(self, timestamp)
             }
         }
     ))
     .expect(DEFAULT_PARSE_ERR_MSG);
 
     let import = syn::parse2::<syn::ItemUse>(quote!(
-        pub use pendzl::contracts::access_control::*;
+        pub use pendzl::contracts::psp22::votes::*;
     ))
     .expect(DEFAULT_PARSE_ERR_MSG);
 
     let import_data = syn::parse2::<syn::ItemUse>(quote!(
-        pub use pendzl::contracts::access_control::AccessControlData;
+        pub use pendzl::contracts::psp22::votes::PSP22VotesData;
     ))
     .expect(DEFAULT_PARSE_IMPORT_ERR_MSG);
 
-    impl_args.imports.insert("AccessControl", import);
-    impl_args.imports.insert("AccessControlData", import_data);
+    impl_args.imports.insert("PSP22Votes", import);
+    impl_args.imports.insert("PSP22VotesData", import_data);
+    impl_args.vec_import();
 
-    override_functions("AccessControlInternal", &mut internal, impl_args.map);
-    override_functions("AccessControl", &mut access_control, impl_args.map);
+    override_functions("PSP22VotesInternal", &mut internal, impl_args.map);
+    override_functions("PSP22Votes", &mut votes, impl_args.map);
+
+    // voting units move with every transfer, mint and burn
+    extend_function(
+        impl_args.items,
+        "PSP22Internal",
+        "_update",
+        quote!(),
+        quote!(
+            pendzl::contracts::psp22::votes::PSP22VotesInternal::_update_votes(self, from, to, amount)?;
+        ),
+    );
 
     impl_args.items.push(syn::Item::Impl(internal_default_impl));
     impl_args.items.push(syn::Item::Impl(internal));
-    impl_args
-        .items
-        .push(syn::Item::Impl(access_control_default_impl));
-    impl_args.items.push(syn::Item::Impl(access_control));
+    impl_args.items.push(syn::Item::Impl(votes_default_impl));
+    impl_args.items.push(syn::Item::Impl(votes));
 }
 
-pub(crate) fn impl_pausable(impl_args: &mut ImplArgs) {
+pub(crate) fn impl_psp22_snapshot(impl_args: &mut ImplArgs) {
     let storage_struct_name = impl_args.contract_name();
     let internal_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
-        impl pendzl::contracts::pausable::PausableInternalDefaultImpl for #storage_struct_name {}
+        impl pendzl::contracts::psp22::snapshot::PSP22SnapshotInternalDefaultImpl for #storage_struct_name {}
     ))
     .expect(DEFAULT_PARSE_ERR_MSG);
 
     let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
-        impl pendzl::contracts::pausable::PausableInternal for #storage_struct_name {
-            fn _paused(&self) -> bool {
-                pendzl::contracts::pausable::PausableInternalDefaultImpl::_paused_default_impl(self)
+        impl pendzl::contracts::psp22::snapshot::PSP22SnapshotInternal for #storage_struct_name {
+            fn _snapshot(&mut self) -> Result<u32, PSP22Error> {
+                pendzl::contracts::psp22::snapshot::PSP22SnapshotInternalDefaultImpl::_snapshot_default_impl(self)
             }
 
-            fn _pause(&mut self) -> Result<(), PausableError> {
-                pendzl::contracts::pausable::PausableInternalDefaultImpl::_pause_default_impl(self)
+            fn _current_snapshot_id(&self) -> u32 {
+                pendzl::contracts::psp22::snapshot::PSP22SnapshotInternalDefaultImpl::_current_snapshot_id_default_impl(self)
             }
 
-            fn _unpause(&mut self) -> Result<(), PausableError> {
-                pendzl::contracts::pausable::PausableInternalDefaultImpl::_unpause_default_impl(self)
+            fn _balance_of_at(&self, account: &AccountId, snapshot_id: &u32) -> Result<Balance, PSP22Error> {
+                pendzl::contracts::psp22::snapshot::PSP22SnapshotInternalDefaultImpl::_balance_of_at_default_impl(self, account, snapshot_id)
             }
 
-            fn _ensure_paused(&self) -> Result<(), PausableError> {
-                pendzl::contracts::pausable::PausableInternalDefaultImpl::_ensure_paused_default_impl(self)
+            fn _total_supply_at(&self, snapshot_id: &u32) -> Result<Balance, PSP22Error> {
+                pendzl::contracts::psp22::snapshot::PSP22SnapshotInternalDefaultImpl::_total_supply_at_default_impl(self, snapshot_id)
             }
 
-            fn _ensure_not_paused(&self) -> Result<(), PausableError> {
-                pendzl::contracts::pausable::PausableInternalDefaultImpl::_ensure_not_paused_default_impl(self)
+            fn _update_snapshots(
+                &mut self,
+                from: Option<&AccountId>,
+                to: Option<&AccountId>,
+                amount: &Balance,
+            ) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::snapshot::PSP22SnapshotInternalDefaultImpl::_update_snapshots_default_impl(self, from, to, amount)
             }
         }
     ))
     .expect(DEFAULT_PARSE_ERR_MSG);
 
-    let pausable_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
-        impl  pendzl::contracts::pausable::PausableDefaultImpl for #storage_struct_name {}
+    let snapshot_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::snapshot::PSP22SnapshotDefaultImpl for #storage_struct_name {}
     ))
     .expect(DEFAULT_PARSE_ERR_MSG);
 
-    let mut pausable = syn::parse2::<syn::ItemImpl>(quote!(
-        impl  pendzl::contracts::pausable::Pausable for #storage_struct_name {
+    let mut snapshot = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::snapshot::PSP22Snapshot for #storage_struct_name {
             #[ink(message)]
-            fn paused(&self) -> bool {
-                pendzl::contracts::pausable::PausableDefaultImpl::paused_default_impl(self)
+            fn snapshot(&mut self) -> Result<u32, PSP22Error> {
+                pendzl::contracts::psp22::snapshot::PSP22SnapshotDefaultImpl::snapshot_default_impl(self)
+            }
+
+            #[ink(message)]
+            fn current_snapshot_id(&self) -> u32 {
+                pendzl::contracts::psp22::snapshot::PSP22SnapshotDefaultImpl::current_snapshot_id_default_impl(self)
+            }
+
+            #[ink(message)]
+            fn balance_of_at(&self, account: AccountId, snapshot_id: u32) -> Result<Balance, PSP22Error> {
+                pendzl::contracts::psp22::snapshot::PSP22SnapshotDefaultImpl::balance_of_at_default_impl(self, account, snapshot_id)
+            }
+
+            #[ink(message)]
+            fn total_supply_at(&self, snapshot_id: u32) -> Result<Balance, PSP22Error> {
+                pendzl::contracts::psp22::snapshot::PSP22SnapshotDefaultImpl::total_supply_at_default_impl(self, snapshot_id)
             }
         }
     ))
     .expect(DEFAULT_PARSE_ERR_MSG);
 
     let import = syn::parse2::<syn::ItemUse>(quote!(
-        pub use pendzl::contracts::pausable::*;
+        pub use pendzl::contracts::psp22::snapshot::*;
     ))
-    .expect(DEFAULT_PARSE_IMPORT_ERR_MSG);
+    .expect(DEFAULT_PARSE_ERR_MSG);
 
     let import_data = syn::parse2::<syn::ItemUse>(quote!(
-        pub use pendzl::contracts::pausable::PausableData;
+        pub use pendzl::contracts::psp22::snapshot::PSP22SnapshotData;
     ))
     .expect(DEFAULT_PARSE_IMPORT_ERR_MSG);
-    impl_args.imports.insert("Pausable", import);
-    impl_args.imports.insert("PausableData", import_data);
 
-    override_functions("PausableInternal", &mut internal, impl_args.map);
-    override_functions("Pausable", &mut pausable, impl_args.map);
+    impl_args.imports.insert("PSP22Snapshot", import);
+    impl_args.imports.insert("PSP22SnapshotData", import_data);
+    impl_args.vec_import();
+
+    override_functions("PSP22SnapshotInternal", &mut internal, impl_args.map);
+    override_functions("PSP22Snapshot", &mut snapshot, impl_args.map);
+
+    // values are recorded before they change
+    extend_function(
+        impl_args.items,
+        "PSP22Internal",
+        "_update",
+        quote!(
+            pendzl::contracts::psp22::snapshot::PSP22SnapshotInternal::_update_snapshots(self, from, to, amount)?;
+        ),
+        quote!(),
+    );
 
     impl_args.items.push(syn::Item::Impl(internal_default_impl));
     impl_args.items.push(syn::Item::Impl(internal));
-    impl_args.items.push(syn::Item::Impl(pausable_default_impl));
-    impl_args.items.push(syn::Item::Impl(pausable));
+    impl_args.items.push(syn::Item::Impl(snapshot_default_impl));
+    impl_args.items.push(syn::Item::Impl(snapshot));
 }
-pub(crate) fn impl_vesting(impl_args: &mut ImplArgs) {
+
+pub(crate) fn impl_psp22_flash_lender(impl_args: &mut ImplArgs) {
     let storage_struct_name = impl_args.contract_name();
     let internal_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
-        impl pendzl::contracts::general_vest::GeneralVestInternalDefaultImpl for #storage_struct_name {}
+        impl pendzl::contracts::psp22::flash_lender::PSP22FlashLenderInternalDefaultImpl for #storage_struct_name {}
     ))
     .expect(DEFAULT_PARSE_ERR_MSG);
 
     let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
-        impl pendzl::contracts::general_vest::GeneralVestInternal for #storage_struct_name {
-            fn _create_vest(&mut self,
-                receiver: AccountId,
-                asset: Option<AccountId>,
-                amount: Balance,
-                schedule: VestingSchedule,
+        impl pendzl::contracts::psp22::flash_lender::PSP22FlashLenderInternal for #storage_struct_name {
+            fn _max_flash_loan(&self) -> Balance {
+                pendzl::contracts::psp22::flash_lender::PSP22FlashLenderInternalDefaultImpl::_max_flash_loan_default_impl(self)
+            }
+
+            fn _flash_fee(&self, amount: &Balance) -> Result<Balance, PSP22Error> {
+                pendzl::contracts::psp22::flash_lender::PSP22FlashLenderInternalDefaultImpl::_flash_fee_default_impl(self, amount)
+            }
+
+            fn _flash_loan(&mut self, receiver: &AccountId, amount: &Balance, data: &Vec<u8>) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::flash_lender::PSP22FlashLenderInternalDefaultImpl::_flash_loan_default_impl(self, receiver, amount, data)
+            }
+        }
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let flash_lender_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::flash_lender::PSP22FlashLenderDefaultImpl for #storage_struct_name {}
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let mut flash_lender = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::flash_lender::PSP22FlashLender for #storage_struct_name {
+            #[ink(message)]
+            fn max_flash_loan(&self) -> Balance {
+                pendzl::contracts::psp22::flash_lender::PSP22FlashLenderDefaultImpl::max_flash_loan_default_impl(self)
+            }
+
+            #[ink(message)]
+            fn flash_fee(&self, amount: Balance) -> Result<Balance, PSP22Error> {
+                pendzl::contracts::psp22::flash_lender::PSP22FlashLenderDefaultImpl::flash_fee_default_impl(self, amount)
+            }
+
+            #[ink(message)]
+            fn flash_loan(&mut self, receiver: AccountId, amount: Balance, data: Vec<u8>) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::flash_lender::PSP22FlashLenderDefaultImpl::flash_loan_default_impl(self, receiver, amount, data)
+            }
+        }
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::psp22::flash_lender::*;
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    impl_args.imports.insert("PSP22FlashLender", import);
+    impl_args.vec_import();
+
+    override_functions(
+        "PSP22FlashLenderInternal",
+        &mut internal,
+        impl_args.map,
+    );
+    override_functions("PSP22FlashLender", &mut flash_lender, impl_args.map);
+
+    impl_args.items.push(syn::Item::Impl(internal_default_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args
+        .items
+        .push(syn::Item::Impl(flash_lender_default_impl));
+    impl_args.items.push(syn::Item::Impl(flash_lender));
+}
+
+pub(crate) fn impl_psp22_wrapper(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::wrapper::PSP22WrapperInternalDefaultImpl for #storage_struct_name {}
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::wrapper::PSP22WrapperInternal for #storage_struct_name {
+            fn _underlying(&self) -> PSP22Ref {
+                pendzl::contracts::psp22::wrapper::PSP22WrapperInternalDefaultImpl::_underlying_default_impl(self)
+            }
+
+            fn _deposit_for(&mut self, caller: &AccountId, account: &AccountId, amount: &Balance) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::wrapper::PSP22WrapperInternalDefaultImpl::_deposit_for_default_impl(self, caller, account, amount)
+            }
+
+            fn _withdraw_to(&mut self, caller: &AccountId, account: &AccountId, amount: &Balance) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::wrapper::PSP22WrapperInternalDefaultImpl::_withdraw_to_default_impl(self, caller, account, amount)
+            }
+
+            fn _recover(&mut self, account: &AccountId) -> Result<Balance, PSP22Error> {
+                pendzl::contracts::psp22::wrapper::PSP22WrapperInternalDefaultImpl::_recover_default_impl(self, account)
+            }
+        }
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let wrapper_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::wrapper::PSP22WrapperDefaultImpl for #storage_struct_name {}
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let mut wrapper = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::wrapper::PSP22Wrapper for #storage_struct_name {
+            #[ink(message)]
+            fn underlying(&self) -> AccountId {
+                pendzl::contracts::psp22::wrapper::PSP22WrapperDefaultImpl::underlying_default_impl(self)
+            }
+
+            #[ink(message)]
+            fn deposit_for(&mut self, account: AccountId, amount: Balance) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::wrapper::PSP22WrapperDefaultImpl::deposit_for_default_impl(self, account, amount)
+            }
+
+            #[ink(message)]
+            fn withdraw_to(&mut self, account: AccountId, amount: Balance) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::wrapper::PSP22WrapperDefaultImpl::withdraw_to_default_impl(self, account, amount)
+            }
+        }
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::psp22::wrapper::*;
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let import_data = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::psp22::wrapper::PSP22WrapperData;
+    ))
+    .expect(DEFAULT_PARSE_IMPORT_ERR_MSG);
+
+    impl_args.imports.insert("PSP22Wrapper", import);
+    impl_args.imports.insert("PSP22WrapperData", import_data);
+    impl_args.vec_import();
+
+    override_functions("PSP22WrapperInternal", &mut internal, impl_args.map);
+    override_functions("PSP22Wrapper", &mut wrapper, impl_args.map);
+
+    impl_args.items.push(syn::Item::Impl(internal_default_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(wrapper_default_impl));
+    impl_args.items.push(syn::Item::Impl(wrapper));
+}
+
+pub(crate) fn impl_psp22_native_wrapper(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::native_wrapper::PSP22NativeWrapperInternalDefaultImpl for #storage_struct_name {}
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::native_wrapper::PSP22NativeWrapperInternal for #storage_struct_name {
+            fn _deposit(&mut self, account: &AccountId, amount: &Balance) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::native_wrapper::PSP22NativeWrapperInternalDefaultImpl::_deposit_default_impl(self, account, amount)
+            }
+
+            fn _withdraw(&mut self, account: &AccountId, amount: &Balance) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::native_wrapper::PSP22NativeWrapperInternalDefaultImpl::_withdraw_default_impl(self, account, amount)
+            }
+        }
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let native_wrapper_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::native_wrapper::PSP22NativeWrapperDefaultImpl for #storage_struct_name {}
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let mut native_wrapper = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::native_wrapper::PSP22NativeWrapper for #storage_struct_name {
+            #[ink(message, payable)]
+            fn deposit(&mut self) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::native_wrapper::PSP22NativeWrapperDefaultImpl::deposit_default_impl(self)
+            }
+
+            #[ink(message)]
+            fn withdraw(&mut self, amount: Balance) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::native_wrapper::PSP22NativeWrapperDefaultImpl::withdraw_default_impl(self, amount)
+            }
+        }
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::psp22::native_wrapper::*;
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    impl_args.imports.insert("PSP22NativeWrapper", import);
+    impl_args.vec_import();
+
+    override_functions(
+        "PSP22NativeWrapperInternal",
+        &mut internal,
+        impl_args.map,
+    );
+    override_functions(
+        "PSP22NativeWrapper",
+        &mut native_wrapper,
+        impl_args.map,
+    );
+
+    impl_args.items.push(syn::Item::Impl(internal_default_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args
+        .items
+        .push(syn::Item::Impl(native_wrapper_default_impl));
+    impl_args.items.push(syn::Item::Impl(native_wrapper));
+}
+
+pub(crate) fn impl_psp22_transfer_fee(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::transfer_fee::PSP22TransferFeeInternalDefaultImpl for #storage_struct_name {}
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::transfer_fee::PSP22TransferFeeInternal for #storage_struct_name {
+            fn _transfer_fee_bps(&self) -> u16 {
+                pendzl::contracts::psp22::transfer_fee::PSP22TransferFeeInternalDefaultImpl::_transfer_fee_bps_default_impl(self)
+            }
+
+            fn _set_transfer_fee_bps(&mut self, fee_bps: &u16) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::transfer_fee::PSP22TransferFeeInternalDefaultImpl::_set_transfer_fee_bps_default_impl(self, fee_bps)
+            }
+
+            fn _fee_recipient(&self) -> Option<AccountId> {
+                pendzl::contracts::psp22::transfer_fee::PSP22TransferFeeInternalDefaultImpl::_fee_recipient_default_impl(self)
+            }
+
+            fn _set_fee_recipient(&mut self, fee_recipient: &AccountId) {
+                pendzl::contracts::psp22::transfer_fee::PSP22TransferFeeInternalDefaultImpl::_set_fee_recipient_default_impl(self, fee_recipient)
+            }
+
+            fn _is_fee_exempt(&self, account: &AccountId) -> bool {
+                pendzl::contracts::psp22::transfer_fee::PSP22TransferFeeInternalDefaultImpl::_is_fee_exempt_default_impl(self, account)
+            }
+
+            fn _set_fee_exempt(&mut self, account: &AccountId, exempt: bool) {
+                pendzl::contracts::psp22::transfer_fee::PSP22TransferFeeInternalDefaultImpl::_set_fee_exempt_default_impl(self, account, exempt)
+            }
+
+            fn _transfer_fee(
+                &self,
+                from: Option<&AccountId>,
+                to: Option<&AccountId>,
+                amount: &Balance,
+            ) -> Result<Balance, PSP22Error> {
+                pendzl::contracts::psp22::transfer_fee::PSP22TransferFeeInternalDefaultImpl::_transfer_fee_default_impl(self, from, to, amount)
+            }
+        }
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let transfer_fee_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::transfer_fee::PSP22TransferFeeDefaultImpl for #storage_struct_name {}
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let mut transfer_fee = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::transfer_fee::PSP22TransferFee for #storage_struct_name {
+            #[ink(message)]
+            fn transfer_fee_bps(&self) -> u16 {
+                pendzl::contracts::psp22::transfer_fee::PSP22TransferFeeDefaultImpl::transfer_fee_bps_default_impl(self)
+            }
+
+            #[ink(message)]
+            fn fee_recipient(&self) -> Option<AccountId> {
+                pendzl::contracts::psp22::transfer_fee::PSP22TransferFeeDefaultImpl::fee_recipient_default_impl(self)
+            }
+
+            #[ink(message)]
+            fn is_fee_exempt(&self, account: AccountId) -> bool {
+                pendzl::contracts::psp22::transfer_fee::PSP22TransferFeeDefaultImpl::is_fee_exempt_default_impl(self, account)
+            }
+        }
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::psp22::transfer_fee::*;
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let import_data = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::psp22::transfer_fee::PSP22TransferFeeData;
+    ))
+    .expect(DEFAULT_PARSE_IMPORT_ERR_MSG);
+
+    impl_args.imports.insert("PSP22TransferFee", import);
+    impl_args
+        .imports
+        .insert("PSP22TransferFeeData", import_data);
+    impl_args.vec_import();
+
+    override_functions(
+        "PSP22TransferFeeInternal",
+        &mut internal,
+        impl_args.map,
+    );
+    override_functions("PSP22TransferFee", &mut transfer_fee, impl_args.map);
+
+    impl_args.items.push(syn::Item::Impl(internal_default_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args
+        .items
+        .push(syn::Item::Impl(transfer_fee_default_impl));
+    impl_args.items.push(syn::Item::Impl(transfer_fee));
+
+    // the fee is moved to the fee recipient by a separate update,
+    // the wrapped body (and hooks of other extensions) only see the amount after the fee
+    extend_function(
+        impl_args.items,
+        "PSP22Internal",
+        "_update",
+        quote!(
+            let fee = pendzl::contracts::psp22::transfer_fee::PSP22TransferFeeInternal::_transfer_fee(self, from, to, amount)?;
+            if fee > 0 {
+                if let Some(fee_recipient) = pendzl::contracts::psp22::transfer_fee::PSP22TransferFeeInternal::_fee_recipient(self) {
+                    pendzl::contracts::psp22::PSP22Internal::_update(self, from, Some(&fee_recipient), &fee)?;
+                }
+            }
+            let amount = &(*amount - fee);
+        ),
+        quote!(),
+    );
+
+    // the recipient is notified about the amount it received after the fee.
+    // overridden functions are kept as they are.
+    if !is_overridden(impl_args.map, "PSP22Internal", "_transfer") {
+        if let Some(transfer) =
+            find_function(impl_args.items, "PSP22Internal", "_transfer")
+        {
+            transfer.block = syn::parse2::<Block>(quote!({
+                let fee = pendzl::contracts::psp22::transfer_fee::PSP22TransferFeeInternal::_transfer_fee(self, Some(from), Some(to), amount)?;
+                pendzl::contracts::psp22::PSP22Internal::_update(self, Some(from), Some(to), amount)?;
+                pendzl::contracts::psp22::PSP22Internal::_do_safe_transfer_check(self, from, to, &(*amount - fee), data)
+            }))
+            .expect(DEFAULT_PARSE_ERR_MSG);
+        }
+    }
+}
+
+pub(crate) fn impl_psp22_compliance(impl_args: &mut ImplArgs) {
+    // compliance is managed through AccessControl roles if present, otherwise by the owner
+    let default_impl = if find_impl(impl_args.items, "AccessControl").is_some()
+    {
+        quote!(pendzl::contracts::psp22::compliance::PSP22ComplianceAccessControlDefaultImpl)
+    } else if find_impl(impl_args.items, "Ownable").is_some() {
+        quote!(pendzl::contracts::psp22::compliance::PSP22ComplianceOwnableDefaultImpl)
+    } else {
+        panic!(
+            "{}",
+            "PSP22Compliance is managed through AccessControl or Ownable, use `#[pendzl::implementation(PSP22, PSP22Compliance, Ownable)]`",
+        )
+    };
+
+    let storage_struct_name = impl_args.contract_name();
+    let internal_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::compliance::PSP22ComplianceInternalDefaultImpl for #storage_struct_name {}
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::compliance::PSP22ComplianceInternal for #storage_struct_name {
+            fn _compliance_mode(&self) -> ComplianceMode {
+                pendzl::contracts::psp22::compliance::PSP22ComplianceInternalDefaultImpl::_compliance_mode_default_impl(self)
+            }
+
+            fn _set_compliance_mode(&mut self, mode: &ComplianceMode) {
+                pendzl::contracts::psp22::compliance::PSP22ComplianceInternalDefaultImpl::_set_compliance_mode_default_impl(self, mode)
+            }
+
+            fn _is_frozen(&self, account: &AccountId) -> bool {
+                pendzl::contracts::psp22::compliance::PSP22ComplianceInternalDefaultImpl::_is_frozen_default_impl(self, account)
+            }
+
+            fn _freeze(&mut self, account: &AccountId) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::compliance::PSP22ComplianceInternalDefaultImpl::_freeze_default_impl(self, account)
+            }
+
+            fn _unfreeze(&mut self, account: &AccountId) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::compliance::PSP22ComplianceInternalDefaultImpl::_unfreeze_default_impl(self, account)
+            }
+
+            fn _is_allowed(&self, account: &AccountId) -> bool {
+                pendzl::contracts::psp22::compliance::PSP22ComplianceInternalDefaultImpl::_is_allowed_default_impl(self, account)
+            }
+
+            fn _set_allowed(&mut self, account: &AccountId, allowed: bool) {
+                pendzl::contracts::psp22::compliance::PSP22ComplianceInternalDefaultImpl::_set_allowed_default_impl(self, account, allowed)
+            }
+
+            fn _check_compliance(
+                &self,
+                from: Option<&AccountId>,
+                to: Option<&AccountId>,
+            ) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::compliance::PSP22ComplianceInternalDefaultImpl::_check_compliance_default_impl(self, from, to)
+            }
+        }
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let compliance_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl #default_impl for #storage_struct_name {}
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let mut compliance = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::compliance::PSP22Compliance for #storage_struct_name {
+            #[ink(message)]
+            fn compliance_mode(&self) -> ComplianceMode {
+                #default_impl::compliance_mode_default_impl(self)
+            }
+
+            #[ink(message)]
+            fn is_frozen(&self, account: AccountId) -> bool {
+                #default_impl::is_frozen_default_impl(self, account)
+            }
+
+            #[ink(message)]
+            fn is_allowed(&self, account: AccountId) -> bool {
+                #default_impl::is_allowed_default_impl(self, account)
+            }
+
+            #[ink(message)]
+            fn set_compliance_mode(&mut self, mode: ComplianceMode) -> Result<(), PSP22Error> {
+                #default_impl::set_compliance_mode_default_impl(self, mode)
+            }
+
+            #[ink(message)]
+            fn freeze(&mut self, account: AccountId) -> Result<(), PSP22Error> {
+                #default_impl::freeze_default_impl(self, account)
+            }
+
+            #[ink(message)]
+            fn unfreeze(&mut self, account: AccountId) -> Result<(), PSP22Error> {
+                #default_impl::unfreeze_default_impl(self, account)
+            }
+
+            #[ink(message)]
+            fn set_allowed(&mut self, account: AccountId, allowed: bool) -> Result<(), PSP22Error> {
+                #default_impl::set_allowed_default_impl(self, account, allowed)
+            }
+        }
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::psp22::compliance::*;
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let import_data = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::psp22::compliance::PSP22ComplianceData;
+    ))
+    .expect(DEFAULT_PARSE_IMPORT_ERR_MSG);
+
+    impl_args.imports.insert("PSP22Compliance", import);
+    impl_args.imports.insert("PSP22ComplianceData", import_data);
+    impl_args.vec_import();
+
+    override_functions("PSP22ComplianceInternal", &mut internal, impl_args.map);
+    override_functions("PSP22Compliance", &mut compliance, impl_args.map);
+
+    impl_args.items.push(syn::Item::Impl(internal_default_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args
+        .items
+        .push(syn::Item::Impl(compliance_default_impl));
+    impl_args.items.push(syn::Item::Impl(compliance));
+
+    extend_function(
+        impl_args.items,
+        "PSP22Internal",
+        "_update",
+        quote!(
+            pendzl::contracts::psp22::compliance::PSP22ComplianceInternal::_check_compliance(self, from, to)?;
+        ),
+        quote!(),
+    );
+}
+
+pub(crate) fn impl_psp22_lockable(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::lockable::PSP22LockableInternalDefaultImpl for #storage_struct_name {}
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::lockable::PSP22LockableInternal for #storage_struct_name {
+            fn _locked_balance_of(&self, account: &AccountId) -> Balance {
+                pendzl::contracts::psp22::lockable::PSP22LockableInternalDefaultImpl::_locked_balance_of_default_impl(self, account)
+            }
+
+            fn _unlocked_balance_of(&self, account: &AccountId) -> Balance {
+                pendzl::contracts::psp22::lockable::PSP22LockableInternalDefaultImpl::_unlocked_balance_of_default_impl(self, account)
+            }
+
+            fn _locks_of(&self, account: &AccountId) -> Vec<Lock> {
+                pendzl::contracts::psp22::lockable::PSP22LockableInternalDefaultImpl::_locks_of_default_impl(self, account)
+            }
+
+            fn _lock(&mut self, account: &AccountId, amount: &Balance, until: &Timestamp) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::lockable::PSP22LockableInternalDefaultImpl::_lock_default_impl(self, account, amount, until)
+            }
+
+            fn _unlock_expired(&mut self, account: &AccountId) -> Balance {
+                pendzl::contracts::psp22::lockable::PSP22LockableInternalDefaultImpl::_unlock_expired_default_impl(self, account)
+            }
+
+            fn _check_locked(&self, from: Option<&AccountId>, amount: &Balance) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::lockable::PSP22LockableInternalDefaultImpl::_check_locked_default_impl(self, from, amount)
+            }
+        }
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let lockable_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::lockable::PSP22LockableDefaultImpl for #storage_struct_name {}
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let mut lockable = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::lockable::PSP22Lockable for #storage_struct_name {
+            #[ink(message)]
+            fn locked_balance_of(&self, account: AccountId) -> Balance {
+                pendzl::contracts::psp22::lockable::PSP22LockableDefaultImpl::locked_balance_of_default_impl(self, account)
+            }
+
+            #[ink(message)]
+            fn unlocked_balance_of(&self, account: AccountId) -> Balance {
+                pendzl::contracts::psp22::lockable::PSP22LockableDefaultImpl::unlocked_balance_of_default_impl(self, account)
+            }
+
+            #[ink(message)]
+            fn locks_of(&self, account: AccountId) -> Vec<Lock> {
+                pendzl::contracts::psp22::lockable::PSP22LockableDefaultImpl::locks_of_default_impl(self, account)
+            }
+        }
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::psp22::lockable::*;
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let import_data = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::psp22::lockable::PSP22LockableData;
+    ))
+    .expect(DEFAULT_PARSE_IMPORT_ERR_MSG);
+
+    impl_args.imports.insert("PSP22Lockable", import);
+    impl_args.imports.insert("PSP22LockableData", import_data);
+    impl_args.vec_import();
+
+    override_functions("PSP22LockableInternal", &mut internal, impl_args.map);
+    override_functions("PSP22Lockable", &mut lockable, impl_args.map);
+
+    impl_args.items.push(syn::Item::Impl(internal_default_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(lockable_default_impl));
+    impl_args.items.push(syn::Item::Impl(lockable));
+
+    extend_function(
+        impl_args.items,
+        "PSP22Internal",
+        "_update",
+        quote!(
+            pendzl::contracts::psp22::lockable::PSP22LockableInternal::_check_locked(self, from, amount)?;
+        ),
+        quote!(),
+    );
+}
+
+pub(crate) fn impl_psp22_bridgeable(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::bridgeable::PSP22BridgeableInternalDefaultImpl for #storage_struct_name {}
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::bridgeable::PSP22BridgeableInternal for #storage_struct_name {
+            fn _bridge_mint(&mut self, to: &AccountId, amount: &Balance, source_chain: u32, nonce: u64) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::bridgeable::PSP22BridgeableInternalDefaultImpl::_bridge_mint_default_impl(self, to, amount, source_chain, nonce)
+            }
+
+            fn _bridge_burn(&mut self, from: &AccountId, amount: &Balance, dest_chain: u32, recipient: &Vec<u8>) -> Result<u64, PSP22Error> {
+                pendzl::contracts::psp22::bridgeable::PSP22BridgeableInternalDefaultImpl::_bridge_burn_default_impl(self, from, amount, dest_chain, recipient)
+            }
+
+            fn _is_nonce_used(&self, source_chain: u32, nonce: u64) -> bool {
+                pendzl::contracts::psp22::bridgeable::PSP22BridgeableInternalDefaultImpl::_is_nonce_used_default_impl(self, source_chain, nonce)
+            }
+
+            fn _outbound_nonce(&self) -> u64 {
+                pendzl::contracts::psp22::bridgeable::PSP22BridgeableInternalDefaultImpl::_outbound_nonce_default_impl(self)
+            }
+        }
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let bridgeable_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::bridgeable::PSP22BridgeableDefaultImpl for #storage_struct_name {}
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let mut bridgeable = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::bridgeable::PSP22Bridgeable for #storage_struct_name {
+            #[ink(message)]
+            fn bridge_mint(&mut self, to: AccountId, amount: Balance, source_chain: u32, nonce: u64) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::bridgeable::PSP22BridgeableDefaultImpl::bridge_mint_default_impl(self, to, amount, source_chain, nonce)
+            }
+
+            #[ink(message)]
+            fn bridge_burn(&mut self, from: AccountId, amount: Balance, dest_chain: u32, recipient: Vec<u8>) -> Result<u64, PSP22Error> {
+                pendzl::contracts::psp22::bridgeable::PSP22BridgeableDefaultImpl::bridge_burn_default_impl(self, from, amount, dest_chain, recipient)
+            }
+
+            #[ink(message)]
+            fn is_nonce_used(&self, source_chain: u32, nonce: u64) -> bool {
+                pendzl::contracts::psp22::bridgeable::PSP22BridgeableDefaultImpl::is_nonce_used_default_impl(self, source_chain, nonce)
+            }
+
+            #[ink(message)]
+            fn outbound_nonce(&self) -> u64 {
+                pendzl::contracts::psp22::bridgeable::PSP22BridgeableDefaultImpl::outbound_nonce_default_impl(self)
+            }
+        }
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::psp22::bridgeable::*;
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let import_data = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::psp22::bridgeable::PSP22BridgeableData;
+    ))
+    .expect(DEFAULT_PARSE_IMPORT_ERR_MSG);
+
+    impl_args.imports.insert("PSP22Bridgeable", import);
+    impl_args.imports.insert("PSP22BridgeableData", import_data);
+    impl_args.vec_import();
+
+    override_functions("PSP22BridgeableInternal", &mut internal, impl_args.map);
+    override_functions("PSP22Bridgeable", &mut bridgeable, impl_args.map);
+
+    impl_args.items.push(syn::Item::Impl(internal_default_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args
+        .items
+        .push(syn::Item::Impl(bridgeable_default_impl));
+    impl_args.items.push(syn::Item::Impl(bridgeable));
+}
+
+pub(crate) fn impl_psp22_minter_allowance(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::minter_allowance::PSP22MinterAllowanceInternalDefaultImpl for #storage_struct_name {}
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::minter_allowance::PSP22MinterAllowanceInternal for #storage_struct_name {
+            fn _minter_allowance(&self, minter: &AccountId) -> Balance {
+                pendzl::contracts::psp22::minter_allowance::PSP22MinterAllowanceInternalDefaultImpl::_minter_allowance_default_impl(self, minter)
+            }
+
+            fn _is_minter(&self, account: &AccountId) -> bool {
+                pendzl::contracts::psp22::minter_allowance::PSP22MinterAllowanceInternalDefaultImpl::_is_minter_default_impl(self, account)
+            }
+
+            fn _configure_minter(&mut self, minter: &AccountId, allowance: &Balance) {
+                pendzl::contracts::psp22::minter_allowance::PSP22MinterAllowanceInternalDefaultImpl::_configure_minter_default_impl(self, minter, allowance)
+            }
+
+            fn _remove_minter(&mut self, minter: &AccountId) {
+                pendzl::contracts::psp22::minter_allowance::PSP22MinterAllowanceInternalDefaultImpl::_remove_minter_default_impl(self, minter)
+            }
+
+            fn _spend_minter_allowance(&mut self, minter: &AccountId, amount: &Balance) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::minter_allowance::PSP22MinterAllowanceInternalDefaultImpl::_spend_minter_allowance_default_impl(self, minter, amount)
+            }
+        }
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let minter_allowance_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::minter_allowance::PSP22MinterAllowanceDefaultImpl for #storage_struct_name {}
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let mut minter_allowance = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::minter_allowance::PSP22MinterAllowance for #storage_struct_name {
+            #[ink(message)]
+            fn minter_allowance(&self, minter: AccountId) -> Balance {
+                pendzl::contracts::psp22::minter_allowance::PSP22MinterAllowanceDefaultImpl::minter_allowance_default_impl(self, minter)
+            }
+
+            #[ink(message)]
+            fn is_minter(&self, account: AccountId) -> bool {
+                pendzl::contracts::psp22::minter_allowance::PSP22MinterAllowanceDefaultImpl::is_minter_default_impl(self, account)
+            }
+
+            #[ink(message)]
+            fn configure_minter(&mut self, minter: AccountId, allowance: Balance) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::minter_allowance::PSP22MinterAllowanceDefaultImpl::configure_minter_default_impl(self, minter, allowance)
+            }
+
+            #[ink(message)]
+            fn remove_minter(&mut self, minter: AccountId) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::minter_allowance::PSP22MinterAllowanceDefaultImpl::remove_minter_default_impl(self, minter)
+            }
+        }
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::psp22::minter_allowance::*;
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let import_data = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::psp22::minter_allowance::PSP22MinterAllowanceData;
+    ))
+    .expect(DEFAULT_PARSE_IMPORT_ERR_MSG);
+
+    impl_args.imports.insert("PSP22MinterAllowance", import);
+    impl_args
+        .imports
+        .insert("PSP22MinterAllowanceData", import_data);
+    impl_args.vec_import();
+
+    override_functions(
+        "PSP22MinterAllowanceInternal",
+        &mut internal,
+        impl_args.map,
+    );
+    override_functions(
+        "PSP22MinterAllowance",
+        &mut minter_allowance,
+        impl_args.map,
+    );
+
+    impl_args.items.push(syn::Item::Impl(internal_default_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args
+        .items
+        .push(syn::Item::Impl(minter_allowance_default_impl));
+    impl_args.items.push(syn::Item::Impl(minter_allowance));
+
+    // only the mint message is limited, internal mints (constructor, bridge, vault, ...) are not
+    if find_function(impl_args.items, "PSP22Mintable", "mint").is_none() {
+        panic!(
+            "{}",
+            "PSP22MinterAllowance limits the PSP22Mintable mint message, use `#[pendzl::implementation(PSP22, PSP22Mintable, PSP22MinterAllowance)]`",
+        )
+    }
+    extend_function(
+        impl_args.items,
+        "PSP22Mintable",
+        "mint",
+        quote!(
+            pendzl::contracts::psp22::minter_allowance::PSP22MinterAllowanceInternal::_spend_minter_allowance(
+                self,
+                &Self::env().caller(),
+                &amount,
+            )?;
+        ),
+        quote!(),
+    );
+}
+
+pub(crate) fn impl_psp22_rebasing(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::rebasing::PSP22RebasingInternalDefaultImpl for #storage_struct_name {}
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::rebasing::PSP22RebasingInternal for #storage_struct_name {
+            fn _shares_of(&self, account: &AccountId) -> Balance {
+                pendzl::contracts::psp22::rebasing::PSP22RebasingInternalDefaultImpl::_shares_of_default_impl(self, account)
+            }
+
+            fn _total_shares(&self) -> Balance {
+                pendzl::contracts::psp22::rebasing::PSP22RebasingInternalDefaultImpl::_total_shares_default_impl(self)
+            }
+
+            fn _convert_to_shares(&self, amount: &Balance) -> Result<Balance, PSP22Error> {
+                pendzl::contracts::psp22::rebasing::PSP22RebasingInternalDefaultImpl::_convert_to_shares_default_impl(self, amount)
+            }
+
+            fn _convert_to_balance(&self, shares: &Balance) -> Result<Balance, PSP22Error> {
+                pendzl::contracts::psp22::rebasing::PSP22RebasingInternalDefaultImpl::_convert_to_balance_default_impl(self, shares)
+            }
+
+            fn _rebase(&mut self, new_total_supply: &Balance) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::rebasing::PSP22RebasingInternalDefaultImpl::_rebase_default_impl(self, new_total_supply)
+            }
+
+            fn _emit_transfer_shares(
+                &self,
+                from: Option<&AccountId>,
+                to: Option<&AccountId>,
+                amount: &Balance,
+            ) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::rebasing::PSP22RebasingInternalDefaultImpl::_emit_transfer_shares_default_impl(self, from, to, amount)
+            }
+        }
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let rebasing_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::rebasing::PSP22RebasingDefaultImpl for #storage_struct_name {}
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let mut rebasing = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::rebasing::PSP22Rebasing for #storage_struct_name {
+            #[ink(message)]
+            fn shares_of(&self, account: AccountId) -> Balance {
+                pendzl::contracts::psp22::rebasing::PSP22RebasingDefaultImpl::shares_of_default_impl(self, account)
+            }
+
+            #[ink(message)]
+            fn total_shares(&self) -> Balance {
+                pendzl::contracts::psp22::rebasing::PSP22RebasingDefaultImpl::total_shares_default_impl(self)
+            }
+
+            #[ink(message)]
+            fn convert_to_shares(&self, amount: Balance) -> Result<Balance, PSP22Error> {
+                pendzl::contracts::psp22::rebasing::PSP22RebasingDefaultImpl::convert_to_shares_default_impl(self, amount)
+            }
+
+            #[ink(message)]
+            fn convert_to_balance(&self, shares: Balance) -> Result<Balance, PSP22Error> {
+                pendzl::contracts::psp22::rebasing::PSP22RebasingDefaultImpl::convert_to_balance_default_impl(self, shares)
+            }
+        }
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::psp22::rebasing::*;
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let import_data = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::psp22::rebasing::PSP22RebasingData;
+    ))
+    .expect(DEFAULT_PARSE_IMPORT_ERR_MSG);
+
+    impl_args.imports.insert("PSP22Rebasing", import);
+    impl_args.imports.insert("PSP22RebasingData", import_data);
+    impl_args.vec_import();
+
+    override_functions("PSP22RebasingInternal", &mut internal, impl_args.map);
+    override_functions("PSP22Rebasing", &mut rebasing, impl_args.map);
+
+    impl_args.items.push(syn::Item::Impl(internal_default_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(rebasing_default_impl));
+    impl_args.items.push(syn::Item::Impl(rebasing));
+
+    // balances are kept as shares in PSP22RebasingData instead of PSP22Data
+    if let Some(psp22_internal_default_impl) =
+        find_impl(impl_args.items, "PSP22InternalDefaultImpl")
+    {
+        *psp22_internal_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+            impl pendzl::contracts::psp22::PSP22InternalDefaultImpl<pendzl::contracts::psp22::rebasing::PSP22RebasingData> for #storage_struct_name {}
+        ))
+        .expect(DEFAULT_PARSE_ERR_MSG);
+    }
+
+    extend_function(
+        impl_args.items,
+        "PSP22Internal",
+        "_update",
+        quote!(),
+        quote!(
+            pendzl::contracts::psp22::rebasing::PSP22RebasingInternal::_emit_transfer_shares(self, from, to, amount)?;
+        ),
+    );
+}
+
+pub(crate) fn impl_psp22_batch(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::batch::PSP22BatchInternalDefaultImpl for #storage_struct_name {}
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::batch::PSP22BatchInternal for #storage_struct_name {
+            fn _batch_transfer(
+                &mut self,
+                from: &AccountId,
+                transfers: &Vec<(AccountId, Balance)>,
+                data: &Vec<u8>,
+            ) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::batch::PSP22BatchInternalDefaultImpl::_batch_transfer_default_impl(self, from, transfers, data)
+            }
+        }
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let batch_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::batch::PSP22BatchDefaultImpl for #storage_struct_name {}
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let mut batch = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::batch::PSP22Batch for #storage_struct_name {
+            #[ink(message)]
+            fn batch_transfer(&mut self, transfers: Vec<(AccountId, Balance)>, data: Vec<u8>) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::batch::PSP22BatchDefaultImpl::batch_transfer_default_impl(self, transfers, data)
+            }
+
+            #[ink(message)]
+            fn batch_transfer_from(
+                &mut self,
+                from: AccountId,
+                transfers: Vec<(AccountId, Balance)>,
+            ) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::batch::PSP22BatchDefaultImpl::batch_transfer_from_default_impl(self, from, transfers)
+            }
+
+            #[ink(message)]
+            fn batch_approve(&mut self, approvals: Vec<(AccountId, Balance)>) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::batch::PSP22BatchDefaultImpl::batch_approve_default_impl(self, approvals)
+            }
+        }
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::psp22::batch::*;
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    impl_args.imports.insert("PSP22Batch", import);
+    impl_args.vec_import();
+
+    override_functions("PSP22BatchInternal", &mut internal, impl_args.map);
+    override_functions("PSP22Batch", &mut batch, impl_args.map);
+
+    impl_args.items.push(syn::Item::Impl(internal_default_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(batch_default_impl));
+    impl_args.items.push(syn::Item::Impl(batch));
+}
+
+pub(crate) fn impl_psp22_expiring_allowance(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::expiring_allowance::PSP22ExpiringAllowanceInternalDefaultImpl for #storage_struct_name {}
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::expiring_allowance::PSP22ExpiringAllowanceInternal for #storage_struct_name {
+            fn _approve_until(
+                &mut self,
+                owner: &AccountId,
+                spender: &AccountId,
+                value: &Balance,
+                expires_at: &Timestamp,
+            ) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::expiring_allowance::PSP22ExpiringAllowanceInternalDefaultImpl::_approve_until_default_impl(self, owner, spender, value, expires_at)
+            }
+
+            fn _allowance_expiry(&self, owner: &AccountId, spender: &AccountId) -> Option<Timestamp> {
+                pendzl::contracts::psp22::expiring_allowance::PSP22ExpiringAllowanceInternalDefaultImpl::_allowance_expiry_default_impl(self, owner, spender)
+            }
+
+            fn _is_allowance_expired(&self, owner: &AccountId, spender: &AccountId) -> bool {
+                pendzl::contracts::psp22::expiring_allowance::PSP22ExpiringAllowanceInternalDefaultImpl::_is_allowance_expired_default_impl(self, owner, spender)
+            }
+
+            fn _clear_allowance_expiry(&mut self, owner: &AccountId, spender: &AccountId) {
+                pendzl::contracts::psp22::expiring_allowance::PSP22ExpiringAllowanceInternalDefaultImpl::_clear_allowance_expiry_default_impl(self, owner, spender)
+            }
+
+            fn _reset_expired_allowance(&mut self, owner: &AccountId, spender: &AccountId) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::expiring_allowance::PSP22ExpiringAllowanceInternalDefaultImpl::_reset_expired_allowance_default_impl(self, owner, spender)
+            }
+
+            fn _check_allowance_expiry(&self, owner: &AccountId, spender: &AccountId) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::expiring_allowance::PSP22ExpiringAllowanceInternalDefaultImpl::_check_allowance_expiry_default_impl(self, owner, spender)
+            }
+        }
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let expiring_allowance_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::expiring_allowance::PSP22ExpiringAllowanceDefaultImpl for #storage_struct_name {}
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let mut expiring_allowance = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::expiring_allowance::PSP22ExpiringAllowance for #storage_struct_name {
+            #[ink(message)]
+            fn approve_until(&mut self, spender: AccountId, value: Balance, expires_at: Timestamp) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::expiring_allowance::PSP22ExpiringAllowanceDefaultImpl::approve_until_default_impl(self, spender, value, expires_at)
+            }
+
+            #[ink(message)]
+            fn allowance_expiry(&self, owner: AccountId, spender: AccountId) -> Option<Timestamp> {
+                pendzl::contracts::psp22::expiring_allowance::PSP22ExpiringAllowanceDefaultImpl::allowance_expiry_default_impl(self, owner, spender)
+            }
+        }
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::psp22::expiring_allowance::*;
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let import_data = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::psp22::expiring_allowance::PSP22ExpiringAllowanceData;
+    ))
+    .expect(DEFAULT_PARSE_IMPORT_ERR_MSG);
+
+    impl_args.imports.insert("PSP22ExpiringAllowance", import);
+    impl_args
+        .imports
+        .insert("PSP22ExpiringAllowanceData", import_data);
+    impl_args.vec_import();
+
+    override_functions(
+        "PSP22ExpiringAllowanceInternal",
+        &mut internal,
+        impl_args.map,
+    );
+    override_functions(
+        "PSP22ExpiringAllowance",
+        &mut expiring_allowance,
+        impl_args.map,
+    );
+
+    // expired allowances read as 0
+    if let Some(allowance) =
+        find_function(impl_args.items, "PSP22Internal", "_allowance")
+    {
+        let block = allowance.block.clone();
+        allowance.block = syn::parse2::<Block>(quote!({
+            let allowance: Balance = #block;
+            if pendzl::contracts::psp22::expiring_allowance::PSP22ExpiringAllowanceInternal::_is_allowance_expired(self, owner, spender) {
+                0
+            } else {
+                allowance
+            }
+        }))
+        .expect(DEFAULT_PARSE_ERR_MSG);
+    }
+
+    extend_function(
+        impl_args.items,
+        "PSP22Internal",
+        "_approve",
+        quote!(),
+        quote!(
+            pendzl::contracts::psp22::expiring_allowance::PSP22ExpiringAllowanceInternal::_clear_allowance_expiry(self, owner, spender);
+        ),
+    );
+
+    // the owner changes an expired allowance starting from 0
+    for message in ["increase_allowance", "decrease_allowance"] {
+        extend_function(
+            impl_args.items,
+            "PSP22",
+            message,
+            quote!(
+                pendzl::contracts::psp22::expiring_allowance::PSP22ExpiringAllowanceInternal::_reset_expired_allowance(self, &Self::env().caller(), &spender)?;
+            ),
+            quote!(),
+        );
+    }
+
+    // expired allowances can't be spent
+    extend_function(
+        impl_args.items,
+        "PSP22Internal",
+        "_decrease_allowance_from_to",
+        quote!(
+            pendzl::contracts::psp22::expiring_allowance::PSP22ExpiringAllowanceInternal::_check_allowance_expiry(self, owner, spender)?;
+        ),
+        quote!(),
+    );
+
+    impl_args.items.push(syn::Item::Impl(internal_default_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args
+        .items
+        .push(syn::Item::Impl(expiring_allowance_default_impl));
+    impl_args.items.push(syn::Item::Impl(expiring_allowance));
+}
+
+pub(crate) fn impl_psp22_vault_fees(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::vault::fees::PSP22VaultFeesInternalDefaultImpl for #storage_struct_name {}
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::vault::fees::PSP22VaultFeesInternal for #storage_struct_name {
+            fn _entry_fee_bps(&self) -> u16 {
+                pendzl::contracts::psp22::vault::fees::PSP22VaultFeesInternalDefaultImpl::_entry_fee_bps_default_impl(self)
+            }
+
+            fn _set_entry_fee_bps(&mut self, fee_bps: &u16) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::vault::fees::PSP22VaultFeesInternalDefaultImpl::_set_entry_fee_bps_default_impl(self, fee_bps)
+            }
+
+            fn _exit_fee_bps(&self) -> u16 {
+                pendzl::contracts::psp22::vault::fees::PSP22VaultFeesInternalDefaultImpl::_exit_fee_bps_default_impl(self)
+            }
+
+            fn _set_exit_fee_bps(&mut self, fee_bps: &u16) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::vault::fees::PSP22VaultFeesInternalDefaultImpl::_set_exit_fee_bps_default_impl(self, fee_bps)
+            }
+
+            fn _management_fee_bps(&self) -> u16 {
+                pendzl::contracts::psp22::vault::fees::PSP22VaultFeesInternalDefaultImpl::_management_fee_bps_default_impl(self)
+            }
+
+            fn _set_management_fee_bps(&mut self, fee_bps: &u16) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::vault::fees::PSP22VaultFeesInternalDefaultImpl::_set_management_fee_bps_default_impl(self, fee_bps)
+            }
+
+            fn _performance_fee_bps(&self) -> u16 {
+                pendzl::contracts::psp22::vault::fees::PSP22VaultFeesInternalDefaultImpl::_performance_fee_bps_default_impl(self)
+            }
+
+            fn _set_performance_fee_bps(&mut self, fee_bps: &u16) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::vault::fees::PSP22VaultFeesInternalDefaultImpl::_set_performance_fee_bps_default_impl(self, fee_bps)
+            }
+
+            fn _fee_recipient(&self) -> Option<AccountId> {
+                pendzl::contracts::psp22::vault::fees::PSP22VaultFeesInternalDefaultImpl::_fee_recipient_default_impl(self)
+            }
+
+            fn _set_fee_recipient(&mut self, fee_recipient: &AccountId) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::vault::fees::PSP22VaultFeesInternalDefaultImpl::_set_fee_recipient_default_impl(self, fee_recipient)
+            }
+
+            fn _high_water_mark(&self) -> Balance {
+                pendzl::contracts::psp22::vault::fees::PSP22VaultFeesInternalDefaultImpl::_high_water_mark_default_impl(self)
+            }
+
+            fn _last_fee_accrual(&self) -> Timestamp {
+                pendzl::contracts::psp22::vault::fees::PSP22VaultFeesInternalDefaultImpl::_last_fee_accrual_default_impl(self)
+            }
+
+            fn _share_price(&self, total_assets: &Balance, total_shares: &Balance) -> Result<Balance, MathError> {
+                pendzl::contracts::psp22::vault::fees::PSP22VaultFeesInternalDefaultImpl::_share_price_default_impl(self, total_assets, total_shares)
+            }
+
+            fn _fee_on_raw(&self, assets: &Balance, fee_bps: u16) -> Result<Balance, MathError> {
+                pendzl::contracts::psp22::vault::fees::PSP22VaultFeesInternalDefaultImpl::_fee_on_raw_default_impl(self, assets, fee_bps)
+            }
+
+            fn _fee_on_total(&self, assets: &Balance, fee_bps: u16) -> Result<Balance, MathError> {
+                pendzl::contracts::psp22::vault::fees::PSP22VaultFeesInternalDefaultImpl::_fee_on_total_default_impl(self, assets, fee_bps)
+            }
+
+            fn _pending_fees(&self) -> Result<(Balance, Balance), MathError> {
+                pendzl::contracts::psp22::vault::fees::PSP22VaultFeesInternalDefaultImpl::_pending_fees_default_impl(self)
+            }
+
+            fn _pending_fee_shares(&self) -> Result<Balance, MathError> {
+                pendzl::contracts::psp22::vault::fees::PSP22VaultFeesInternalDefaultImpl::_pending_fee_shares_default_impl(self)
+            }
+
+            fn _convert_to_shares_with_pending_fees(&self, assets: &Balance, round: Rounding) -> Result<Balance, MathError> {
+                pendzl::contracts::psp22::vault::fees::PSP22VaultFeesInternalDefaultImpl::_convert_to_shares_with_pending_fees_default_impl(self, assets, round)
+            }
+
+            fn _convert_to_assets_with_pending_fees(&self, shares: &Balance, round: Rounding) -> Result<Balance, MathError> {
+                pendzl::contracts::psp22::vault::fees::PSP22VaultFeesInternalDefaultImpl::_convert_to_assets_with_pending_fees_default_impl(self, shares, round)
+            }
+
+            fn _accrue_fees(&mut self) -> Result<Balance, PSP22Error> {
+                pendzl::contracts::psp22::vault::fees::PSP22VaultFeesInternalDefaultImpl::_accrue_fees_default_impl(self)
+            }
+
+            fn _send_fee(&mut self, fee: &Balance) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::vault::fees::PSP22VaultFeesInternalDefaultImpl::_send_fee_default_impl(self, fee)
+            }
+        }
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let vault_fees_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::vault::fees::PSP22VaultFeesDefaultImpl for #storage_struct_name {}
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let mut vault_fees = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::vault::fees::PSP22VaultFees for #storage_struct_name {
+            #[ink(message)]
+            fn entry_fee_bps(&self) -> u16 {
+                pendzl::contracts::psp22::vault::fees::PSP22VaultFeesDefaultImpl::entry_fee_bps_default_impl(self)
+            }
+
+            #[ink(message)]
+            fn exit_fee_bps(&self) -> u16 {
+                pendzl::contracts::psp22::vault::fees::PSP22VaultFeesDefaultImpl::exit_fee_bps_default_impl(self)
+            }
+
+            #[ink(message)]
+            fn management_fee_bps(&self) -> u16 {
+                pendzl::contracts::psp22::vault::fees::PSP22VaultFeesDefaultImpl::management_fee_bps_default_impl(self)
+            }
+
+            #[ink(message)]
+            fn performance_fee_bps(&self) -> u16 {
+                pendzl::contracts::psp22::vault::fees::PSP22VaultFeesDefaultImpl::performance_fee_bps_default_impl(self)
+            }
+
+            #[ink(message)]
+            fn fee_recipient(&self) -> Option<AccountId> {
+                pendzl::contracts::psp22::vault::fees::PSP22VaultFeesDefaultImpl::fee_recipient_default_impl(self)
+            }
+
+            #[ink(message)]
+            fn high_water_mark(&self) -> Balance {
+                pendzl::contracts::psp22::vault::fees::PSP22VaultFeesDefaultImpl::high_water_mark_default_impl(self)
+            }
+
+            #[ink(message)]
+            fn last_fee_accrual(&self) -> Timestamp {
+                pendzl::contracts::psp22::vault::fees::PSP22VaultFeesDefaultImpl::last_fee_accrual_default_impl(self)
+            }
+
+            #[ink(message)]
+            fn pending_fee_shares(&self) -> Result<Balance, MathError> {
+                pendzl::contracts::psp22::vault::fees::PSP22VaultFeesDefaultImpl::pending_fee_shares_default_impl(self)
+            }
+
+            #[ink(message)]
+            fn accrue_fees(&mut self) -> Result<Balance, PSP22Error> {
+                pendzl::contracts::psp22::vault::fees::PSP22VaultFeesDefaultImpl::accrue_fees_default_impl(self)
+            }
+        }
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::psp22::vault::fees::*;
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let import_data = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::psp22::vault::fees::PSP22VaultFeesData;
+    ))
+    .expect(DEFAULT_PARSE_IMPORT_ERR_MSG);
+
+    impl_args.imports.insert("PSP22VaultFees", import);
+    impl_args.imports.insert("PSP22VaultFeesData", import_data);
+    impl_args.vec_import();
+
+    override_functions("PSP22VaultFeesInternal", &mut internal, impl_args.map);
+    override_functions("PSP22VaultFees", &mut vault_fees, impl_args.map);
+
+    // pending fee shares are part of the supply, so accruing them doesn't change conversions.
+    // overridden conversions are kept as they are.
+    if !is_overridden(impl_args.map, "PSP22VaultInternal", "_convert_to_shares")
+    {
+        if let Some(convert_to_shares) = find_function(
+            impl_args.items,
+            "PSP22VaultInternal",
+            "_convert_to_shares",
+        ) {
+            convert_to_shares.block = syn::parse2::<Block>(quote!({
+                pendzl::contracts::psp22::vault::fees::PSP22VaultFeesInternal::_convert_to_shares_with_pending_fees(self, assets, rounding)
+            }))
+            .expect(DEFAULT_PARSE_ERR_MSG);
+        }
+    }
+    if !is_overridden(impl_args.map, "PSP22VaultInternal", "_convert_to_assets")
+    {
+        if let Some(convert_to_assets) = find_function(
+            impl_args.items,
+            "PSP22VaultInternal",
+            "_convert_to_assets",
+        ) {
+            convert_to_assets.block = syn::parse2::<Block>(quote!({
+                pendzl::contracts::psp22::vault::fees::PSP22VaultFeesInternal::_convert_to_assets_with_pending_fees(self, shares, rounding)
+            }))
+            .expect(DEFAULT_PARSE_ERR_MSG);
+        }
+    }
+
+    // entry fee is taken from the deposited assets
+    if let Some(preview_deposit) =
+        find_function(impl_args.items, "PSP22VaultInternal", "_preview_deposit")
+    {
+        let block = preview_deposit.block.clone();
+        preview_deposit.block = syn::parse2::<Block>(quote!({
+            let fee = pendzl::contracts::psp22::vault::fees::PSP22VaultFeesInternal::_fee_on_total(
+                self,
+                assets,
+                pendzl::contracts::psp22::vault::fees::PSP22VaultFeesInternal::_entry_fee_bps(self),
+            )?;
+            let assets: &Balance = &assets.checked_sub(fee).ok_or(MathError::Underflow)?;
+            #block
+        }))
+        .expect(DEFAULT_PARSE_ERR_MSG);
+    }
+    if let Some(preview_mint) =
+        find_function(impl_args.items, "PSP22VaultInternal", "_preview_mint")
+    {
+        let block = preview_mint.block.clone();
+        preview_mint.block = syn::parse2::<Block>(quote!({
+            let assets: Balance = #block?;
+            let fee = pendzl::contracts::psp22::vault::fees::PSP22VaultFeesInternal::_fee_on_raw(
+                self,
+                &assets,
+                pendzl::contracts::psp22::vault::fees::PSP22VaultFeesInternal::_entry_fee_bps(self),
+            )?;
+            assets.checked_add(fee).ok_or(MathError::Overflow)
+        }))
+        .expect(DEFAULT_PARSE_ERR_MSG);
+    }
+
+    // exit fee is charged on top of the withdrawn assets
+    if let Some(preview_withdraw) = find_function(
+        impl_args.items,
+        "PSP22VaultInternal",
+        "_preview_withdraw",
+    ) {
+        let block = preview_withdraw.block.clone();
+        preview_withdraw.block = syn::parse2::<Block>(quote!({
+            let fee = pendzl::contracts::psp22::vault::fees::PSP22VaultFeesInternal::_fee_on_raw(
+                self,
+                assets,
+                pendzl::contracts::psp22::vault::fees::PSP22VaultFeesInternal::_exit_fee_bps(self),
+            )?;
+            let assets: &Balance = &assets.checked_add(fee).ok_or(MathError::Overflow)?;
+            #block
+        }))
+        .expect(DEFAULT_PARSE_ERR_MSG);
+    }
+    if let Some(preview_redeem) =
+        find_function(impl_args.items, "PSP22VaultInternal", "_preview_redeem")
+    {
+        let block = preview_redeem.block.clone();
+        preview_redeem.block = syn::parse2::<Block>(quote!({
+            let assets: Balance = #block?;
+            let fee = pendzl::contracts::psp22::vault::fees::PSP22VaultFeesInternal::_fee_on_total(
+                self,
+                &assets,
+                pendzl::contracts::psp22::vault::fees::PSP22VaultFeesInternal::_exit_fee_bps(self),
+            )?;
+            assets.checked_sub(fee).ok_or(MathError::Underflow)
+        }))
+        .expect(DEFAULT_PARSE_ERR_MSG);
+    }
+    if let Some(max_withdraw) =
+        find_function(impl_args.items, "PSP22VaultInternal", "_max_withdraw")
+    {
+        let block = max_withdraw.block.clone();
+        max_withdraw.block = syn::parse2::<Block>(quote!({
+            let max_withdraw: Balance = #block;
+            let owner_balance = pendzl::contracts::psp22::PSP22Internal::_balance_of(self, owner);
+            let max_redeem_assets = pendzl::contracts::psp22::vault::PSP22VaultInternal::_preview_redeem(self, &owner_balance)
+                .unwrap_or_default();
+            core::cmp::min(max_withdraw, max_redeem_assets)
+        }))
+        .expect(DEFAULT_PARSE_ERR_MSG);
+    }
+
+    extend_function(
+        impl_args.items,
+        "PSP22VaultInternal",
+        "_deposit",
+        quote!(
+            pendzl::contracts::psp22::vault::fees::PSP22VaultFeesInternal::_accrue_fees(self)?;
+        ),
+        quote!(
+            let fee = pendzl::contracts::psp22::vault::fees::PSP22VaultFeesInternal::_fee_on_total(
+                self,
+                assets,
+                pendzl::contracts::psp22::vault::fees::PSP22VaultFeesInternal::_entry_fee_bps(self),
+            )?;
+            pendzl::contracts::psp22::vault::fees::PSP22VaultFeesInternal::_send_fee(self, &fee)?;
+        ),
+    );
+
+    extend_function(
+        impl_args.items,
+        "PSP22VaultInternal",
+        "_withdraw",
+        quote!(
+            pendzl::contracts::psp22::vault::fees::PSP22VaultFeesInternal::_accrue_fees(self)?;
+        ),
+        quote!(
+            let fee = pendzl::contracts::psp22::vault::fees::PSP22VaultFeesInternal::_fee_on_raw(
+                self,
+                assets,
+                pendzl::contracts::psp22::vault::fees::PSP22VaultFeesInternal::_exit_fee_bps(self),
+            )?;
+            pendzl::contracts::psp22::vault::fees::PSP22VaultFeesInternal::_send_fee(self, &fee)?;
+        ),
+    );
+
+    impl_args.items.push(syn::Item::Impl(internal_default_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args
+        .items
+        .push(syn::Item::Impl(vault_fees_default_impl));
+    impl_args.items.push(syn::Item::Impl(vault_fees));
+}
+
+pub(crate) fn impl_psp22_vault_native(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::vault::native::PSP22VaultNativeInternalDefaultImpl for #storage_struct_name {}
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let vault_native_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::vault::native::PSP22VaultNativeDefaultImpl for #storage_struct_name {}
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let mut vault_native = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::vault::native::PSP22VaultNative for #storage_struct_name {
+            #[ink(message, payable)]
+            fn deposit_native(&mut self, receiver: AccountId) -> Result<Balance, PSP22Error> {
+                pendzl::contracts::psp22::vault::native::PSP22VaultNativeDefaultImpl::deposit_native_default_impl(self, receiver)
+            }
+
+            #[ink(message, payable)]
+            fn mint_native(&mut self, shares: Balance, receiver: AccountId) -> Result<Balance, PSP22Error> {
+                pendzl::contracts::psp22::vault::native::PSP22VaultNativeDefaultImpl::mint_native_default_impl(self, shares, receiver)
+            }
+        }
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::psp22::vault::native::*;
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    impl_args.imports.insert("PSP22VaultNative", import);
+    impl_args.vec_import();
+
+    override_functions("PSP22VaultNative", &mut vault_native, impl_args.map);
+
+    // the underlying asset is the native currency instead of a PSP22 token.
+    // overridden functions are kept as they are.
+    if !is_overridden(impl_args.map, "PSP22VaultInternal", "_total_assets") {
+        if let Some(total_assets) = find_function(
+            impl_args.items,
+            "PSP22VaultInternal",
+            "_total_assets",
+        ) {
+            total_assets.block = syn::parse2::<Block>(quote!({
+                pendzl::contracts::psp22::vault::native::PSP22VaultNativeInternalDefaultImpl::_total_assets_native_default_impl(self)
+            }))
+            .expect(DEFAULT_PARSE_ERR_MSG);
+        }
+    }
+    if !is_overridden(impl_args.map, "PSP22VaultInternal", "_receive_assets") {
+        if let Some(receive_assets) = find_function(
+            impl_args.items,
+            "PSP22VaultInternal",
+            "_receive_assets",
+        ) {
+            receive_assets.block = syn::parse2::<Block>(quote!({
+                pendzl::contracts::psp22::vault::native::PSP22VaultNativeInternalDefaultImpl::_receive_assets_native_default_impl(self, from, assets)
+            }))
+            .expect(DEFAULT_PARSE_ERR_MSG);
+        }
+    }
+    if !is_overridden(impl_args.map, "PSP22VaultInternal", "_send_assets") {
+        if let Some(send_assets) =
+            find_function(impl_args.items, "PSP22VaultInternal", "_send_assets")
+        {
+            send_assets.block = syn::parse2::<Block>(quote!({
+                pendzl::contracts::psp22::vault::native::PSP22VaultNativeInternalDefaultImpl::_send_assets_native_default_impl(self, to, assets)
+            }))
+            .expect(DEFAULT_PARSE_ERR_MSG);
+        }
+    }
+
+    impl_args.items.push(syn::Item::Impl(internal_default_impl));
+    impl_args
+        .items
+        .push(syn::Item::Impl(vault_native_default_impl));
+    impl_args.items.push(syn::Item::Impl(vault_native));
+}
+
+pub(crate) fn impl_psp22_vault_async(impl_args: &mut ImplArgs) {
+    // requests are fulfilled without charging fees and deposited by a non payable message
+    for unsupported in ["PSP22VaultFees", "PSP22VaultNative"] {
+        if find_impl(impl_args.items, unsupported).is_some() {
+            panic!(
+                "PSP22VaultAsync can not be used together with {}",
+                unsupported
+            );
+        }
+    }
+
+    let storage_struct_name = impl_args.contract_name();
+    let internal_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::vault::async_requests::PSP22VaultAsyncInternalDefaultImpl for #storage_struct_name {}
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::vault::async_requests::PSP22VaultAsyncInternal for #storage_struct_name {
+            fn _pending_deposit_request(&self, controller: &AccountId) -> Balance {
+                pendzl::contracts::psp22::vault::async_requests::PSP22VaultAsyncInternalDefaultImpl::_pending_deposit_request_default_impl(self, controller)
+            }
+
+            fn _claimable_deposit_request(&self, controller: &AccountId) -> Balance {
+                pendzl::contracts::psp22::vault::async_requests::PSP22VaultAsyncInternalDefaultImpl::_claimable_deposit_request_default_impl(self, controller)
+            }
+
+            fn _pending_redeem_request(&self, controller: &AccountId) -> Balance {
+                pendzl::contracts::psp22::vault::async_requests::PSP22VaultAsyncInternalDefaultImpl::_pending_redeem_request_default_impl(self, controller)
+            }
+
+            fn _claimable_redeem_request(&self, controller: &AccountId) -> Balance {
+                pendzl::contracts::psp22::vault::async_requests::PSP22VaultAsyncInternalDefaultImpl::_claimable_redeem_request_default_impl(self, controller)
+            }
+
+            fn _reserved_assets(&self) -> Balance {
+                pendzl::contracts::psp22::vault::async_requests::PSP22VaultAsyncInternalDefaultImpl::_reserved_assets_default_impl(self)
+            }
+
+            fn _request_deposit(&mut self, owner: &AccountId, controller: &AccountId, assets: &Balance) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::vault::async_requests::PSP22VaultAsyncInternalDefaultImpl::_request_deposit_default_impl(self, owner, controller, assets)
+            }
+
+            fn _request_redeem(&mut self, caller: &AccountId, owner: &AccountId, controller: &AccountId, shares: &Balance) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::vault::async_requests::PSP22VaultAsyncInternalDefaultImpl::_request_redeem_default_impl(self, caller, owner, controller, shares)
+            }
+
+            fn _fulfill_deposit(&mut self, controller: &AccountId, assets: &Balance) -> Result<Balance, PSP22Error> {
+                pendzl::contracts::psp22::vault::async_requests::PSP22VaultAsyncInternalDefaultImpl::_fulfill_deposit_default_impl(self, controller, assets)
+            }
+
+            fn _fulfill_redeem(&mut self, controller: &AccountId, shares: &Balance) -> Result<Balance, PSP22Error> {
+                pendzl::contracts::psp22::vault::async_requests::PSP22VaultAsyncInternalDefaultImpl::_fulfill_redeem_default_impl(self, controller, shares)
+            }
+
+            fn _claim(&mut self, controller: &AccountId, receiver: &AccountId) -> Result<(Balance, Balance), PSP22Error> {
+                pendzl::contracts::psp22::vault::async_requests::PSP22VaultAsyncInternalDefaultImpl::_claim_default_impl(self, controller, receiver)
+            }
+        }
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let vault_async_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::vault::async_requests::PSP22VaultAsyncDefaultImpl for #storage_struct_name {}
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let mut vault_async = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::vault::async_requests::PSP22VaultAsync for #storage_struct_name {
+            #[ink(message)]
+            fn request_deposit(&mut self, assets: Balance, controller: AccountId) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::vault::async_requests::PSP22VaultAsyncDefaultImpl::request_deposit_default_impl(self, assets, controller)
+            }
+
+            #[ink(message)]
+            fn pending_deposit_request(&self, controller: AccountId) -> Balance {
+                pendzl::contracts::psp22::vault::async_requests::PSP22VaultAsyncDefaultImpl::pending_deposit_request_default_impl(self, controller)
+            }
+
+            #[ink(message)]
+            fn claimable_deposit_request(&self, controller: AccountId) -> Balance {
+                pendzl::contracts::psp22::vault::async_requests::PSP22VaultAsyncDefaultImpl::claimable_deposit_request_default_impl(self, controller)
+            }
+
+            #[ink(message)]
+            fn request_redeem(&mut self, shares: Balance, controller: AccountId, owner: AccountId) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::vault::async_requests::PSP22VaultAsyncDefaultImpl::request_redeem_default_impl(self, shares, controller, owner)
+            }
+
+            #[ink(message)]
+            fn pending_redeem_request(&self, controller: AccountId) -> Balance {
+                pendzl::contracts::psp22::vault::async_requests::PSP22VaultAsyncDefaultImpl::pending_redeem_request_default_impl(self, controller)
+            }
+
+            #[ink(message)]
+            fn claimable_redeem_request(&self, controller: AccountId) -> Balance {
+                pendzl::contracts::psp22::vault::async_requests::PSP22VaultAsyncDefaultImpl::claimable_redeem_request_default_impl(self, controller)
+            }
+
+            #[ink(message)]
+            fn claim(&mut self, receiver: AccountId) -> Result<(Balance, Balance), PSP22Error> {
+                pendzl::contracts::psp22::vault::async_requests::PSP22VaultAsyncDefaultImpl::claim_default_impl(self, receiver)
+            }
+        }
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::psp22::vault::async_requests::*;
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let import_data = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::psp22::vault::async_requests::PSP22VaultAsyncData;
+    ))
+    .expect(DEFAULT_PARSE_IMPORT_ERR_MSG);
+
+    impl_args.imports.insert("PSP22VaultAsync", import);
+    impl_args.imports.insert("PSP22VaultAsyncData", import_data);
+    impl_args.vec_import();
+
+    override_functions("PSP22VaultAsyncInternal", &mut internal, impl_args.map);
+    override_functions("PSP22VaultAsync", &mut vault_async, impl_args.map);
+
+    // assets held for pending deposits and claimable redeems don't belong to the share holders
+    if let Some(total_assets) =
+        find_function(impl_args.items, "PSP22VaultInternal", "_total_assets")
+    {
+        let block = total_assets.block.clone();
+        total_assets.block = syn::parse2::<Block>(quote!({
+            let total_assets: Balance = #block;
+            total_assets.saturating_sub(
+                pendzl::contracts::psp22::vault::async_requests::PSP22VaultAsyncInternal::_reserved_assets(self),
+            )
+        }))
+        .expect(DEFAULT_PARSE_ERR_MSG);
+    }
+
+    // withdrawals go through request_redeem and claim.
+    // overridden functions are kept as they are.
+    if !is_overridden(impl_args.map, "PSP22VaultInternal", "_max_withdraw") {
+        if let Some(max_withdraw) = find_function(
+            impl_args.items,
+            "PSP22VaultInternal",
+            "_max_withdraw",
+        ) {
+            max_withdraw.block = syn::parse2::<Block>(quote!({ 0 }))
+                .expect(DEFAULT_PARSE_ERR_MSG);
+        }
+    }
+    if !is_overridden(impl_args.map, "PSP22VaultInternal", "_max_redeem") {
+        if let Some(max_redeem) =
+            find_function(impl_args.items, "PSP22VaultInternal", "_max_redeem")
+        {
+            max_redeem.block = syn::parse2::<Block>(quote!({ 0 }))
+                .expect(DEFAULT_PARSE_ERR_MSG);
+        }
+    }
+
+    impl_args.items.push(syn::Item::Impl(internal_default_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args
+        .items
+        .push(syn::Item::Impl(vault_async_default_impl));
+    impl_args.items.push(syn::Item::Impl(vault_async));
+}
+
+pub(crate) fn impl_psp22_vault_slippage(impl_args: &mut ImplArgs) {
+    // the slippage protected deposits are non payable messages
+    if find_impl(impl_args.items, "PSP22VaultNative").is_some() {
+        panic!(
+            "PSP22VaultSlippage can not be used together with PSP22VaultNative"
+        );
+    }
+    let storage_struct_name = impl_args.contract_name();
+    let internal_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::vault::slippage::PSP22VaultSlippageInternalDefaultImpl for #storage_struct_name {}
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::vault::slippage::PSP22VaultSlippageInternal for #storage_struct_name {
+            fn _check_deadline(&self, deadline: &Option<Timestamp>) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::vault::slippage::PSP22VaultSlippageInternalDefaultImpl::_check_deadline_default_impl(self, deadline)
+            }
+        }
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let vault_slippage_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::vault::slippage::PSP22VaultSlippageDefaultImpl for #storage_struct_name {}
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let mut vault_slippage = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::vault::slippage::PSP22VaultSlippage for #storage_struct_name {
+            #[ink(message)]
+            fn deposit_with_min_shares(
+                &mut self,
+                assets: Balance,
+                receiver: AccountId,
+                min_shares: Balance,
+                deadline: Option<Timestamp>,
+            ) -> Result<Balance, PSP22Error> {
+                pendzl::contracts::psp22::vault::slippage::PSP22VaultSlippageDefaultImpl::deposit_with_min_shares_default_impl(self, assets, receiver, min_shares, deadline)
+            }
+
+            #[ink(message)]
+            fn mint_with_max_assets(
+                &mut self,
+                shares: Balance,
+                receiver: AccountId,
+                max_assets: Balance,
+                deadline: Option<Timestamp>,
+            ) -> Result<Balance, PSP22Error> {
+                pendzl::contracts::psp22::vault::slippage::PSP22VaultSlippageDefaultImpl::mint_with_max_assets_default_impl(self, shares, receiver, max_assets, deadline)
+            }
+
+            #[ink(message)]
+            fn withdraw_with_max_shares(
+                &mut self,
+                assets: Balance,
+                receiver: AccountId,
+                owner: AccountId,
+                max_shares: Balance,
+                deadline: Option<Timestamp>,
+            ) -> Result<Balance, PSP22Error> {
+                pendzl::contracts::psp22::vault::slippage::PSP22VaultSlippageDefaultImpl::withdraw_with_max_shares_default_impl(self, assets, receiver, owner, max_shares, deadline)
+            }
+
+            #[ink(message)]
+            fn redeem_with_min_assets(
+                &mut self,
+                shares: Balance,
+                receiver: AccountId,
+                owner: AccountId,
+                min_assets: Balance,
+                deadline: Option<Timestamp>,
+            ) -> Result<Balance, PSP22Error> {
+                pendzl::contracts::psp22::vault::slippage::PSP22VaultSlippageDefaultImpl::redeem_with_min_assets_default_impl(self, shares, receiver, owner, min_assets, deadline)
+            }
+        }
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::psp22::vault::slippage::*;
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    impl_args.imports.insert("PSP22VaultSlippage", import);
+    impl_args.vec_import();
+
+    override_functions(
+        "PSP22VaultSlippageInternal",
+        &mut internal,
+        impl_args.map,
+    );
+    override_functions(
+        "PSP22VaultSlippage",
+        &mut vault_slippage,
+        impl_args.map,
+    );
+
+    impl_args.items.push(syn::Item::Impl(internal_default_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args
+        .items
+        .push(syn::Item::Impl(vault_slippage_default_impl));
+    impl_args.items.push(syn::Item::Impl(vault_slippage));
+}
+
+pub(crate) fn impl_psp34_enumerable(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp34::enumerable::PSP34EnumerableInternalDefaultImpl for #storage_struct_name {}
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp34::enumerable::PSP34EnumerableInternal for #storage_struct_name {
+            fn _update_enumeration(
+                &mut self,
+                from: &Option<&AccountId>,
+                to: &Option<&AccountId>,
+                id: &Id,
+            ) -> Result<(), PSP34Error> {
+                pendzl::contracts::psp34::enumerable::PSP34EnumerableInternalDefaultImpl::_update_enumeration_default_impl(self, from, to, id)
+            }
+        }
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let enumerable_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp34::enumerable::PSP34EnumerableDefaultImpl for #storage_struct_name {}
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let mut enumerable = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp34::enumerable::PSP34Enumerable for #storage_struct_name {
+            #[ink(message)]
+            fn owners_token_by_index(&self, owner: AccountId, index: u128) -> Option<Id> {
+                pendzl::contracts::psp34::enumerable::PSP34EnumerableDefaultImpl::owners_token_by_index_default_impl(self, owner, index)
+            }
+
+            #[ink(message)]
+            fn token_by_index(&self, index: u128) -> Option<Id> {
+                pendzl::contracts::psp34::enumerable::PSP34EnumerableDefaultImpl::token_by_index_default_impl(self, index)
+            }
+        }
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::psp34::enumerable::*;
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let import_data = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::psp34::enumerable::PSP34EnumerableData;
+    ))
+    .expect(DEFAULT_PARSE_IMPORT_ERR_MSG);
+
+    impl_args.imports.insert("PSP34Enumerable", import);
+    impl_args.imports.insert("PSP34EnumerableData", import_data);
+    impl_args.vec_import();
+
+    override_functions("PSP34EnumerableInternal", &mut internal, impl_args.map);
+    override_functions("PSP34Enumerable", &mut enumerable, impl_args.map);
+
+    // keep the token lists in sync with every balance change
+    extend_function(
+        impl_args.items,
+        "PSP34Internal",
+        "_update",
+        quote!(),
+        quote!(
+            pendzl::contracts::psp34::enumerable::PSP34EnumerableInternal::_update_enumeration(self, from, to, id)?;
+        ),
+    );
+
+    impl_args.items.push(syn::Item::Impl(internal_default_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args
+        .items
+        .push(syn::Item::Impl(enumerable_default_impl));
+    impl_args.items.push(syn::Item::Impl(enumerable));
+}
+
+pub(crate) fn impl_psp37(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp37::PSP37InternalDefaultImpl for #storage_struct_name {}
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp37::PSP37Internal for #storage_struct_name {
+            fn _balance_of(&self, owner: &AccountId, id: &Option<Id>) -> Balance {
+                pendzl::contracts::psp37::PSP37InternalDefaultImpl::_balance_of_default_impl(self, owner, id)
+            }
+
+            fn _total_supply(&self, id: &Option<Id>) -> Balance {
+                pendzl::contracts::psp37::PSP37InternalDefaultImpl::_total_supply_default_impl(self, id)
+            }
+
+            fn _allowance(&self, owner: &AccountId, operator: &AccountId, id: &Option<Id>) -> Balance {
+                pendzl::contracts::psp37::PSP37InternalDefaultImpl::_allowance_default_impl(self, owner, operator, id)
+            }
+
+            fn _approve(&mut self, owner: &AccountId, operator: &AccountId, id: &Option<Id>, value: &Balance) -> Result<(), PSP37Error> {
+                pendzl::contracts::psp37::PSP37InternalDefaultImpl::_approve_default_impl(self, owner, operator, id, value)
+            }
+
+            fn _decrease_allowance(&mut self, owner: &AccountId, operator: &AccountId, id: &Id, value: &Balance) -> Result<(), PSP37Error> {
+                pendzl::contracts::psp37::PSP37InternalDefaultImpl::_decrease_allowance_default_impl(self, owner, operator, id, value)
+            }
+
+            fn _update(
+                &mut self,
+                from: &Option<&AccountId>,
+                to: &Option<&AccountId>,
+                ids_amounts: &Vec<(Id, Balance)>,
+            ) -> Result<(), PSP37Error> {
+                pendzl::contracts::psp37::PSP37InternalDefaultImpl::_update_default_impl(self, from, to, ids_amounts)
+            }
+
+            fn _transfer(&mut self, from: &AccountId, to: &AccountId, ids_amounts: &Vec<(Id, Balance)>, data: &Vec<u8>) -> Result<(), PSP37Error> {
+                pendzl::contracts::psp37::PSP37Internal::_update(self, &Some(from), &Some(to), ids_amounts)?;
+                pendzl::contracts::psp37::PSP37Internal::_do_safe_transfer_check(self, &Some(from), to, ids_amounts, data)
+            }
+
+            fn _mint_to(&mut self, to: &AccountId, ids_amounts: &Vec<(Id, Balance)>) -> Result<(), PSP37Error> {
+                pendzl::contracts::psp37::PSP37Internal::_update(self, &None, &Some(to), ids_amounts)?;
+                pendzl::contracts::psp37::PSP37Internal::_do_safe_transfer_check(self, &None, to, ids_amounts, &Vec::new())
+            }
+
+            fn _burn_from(&mut self, from: &AccountId, ids_amounts: &Vec<(Id, Balance)>) -> Result<(), PSP37Error> {
+                pendzl::contracts::psp37::PSP37Internal::_update(self, &Some(from), &None, ids_amounts)
+            }
+
+            fn _do_safe_transfer_check(&mut self, from: &Option<&AccountId>, to: &AccountId, ids_amounts: &Vec<(Id, Balance)>, data: &Vec<u8>) -> Result<(), PSP37Error> {
+                pendzl::contracts::psp37::PSP37InternalDefaultImpl::_do_safe_transfer_check_default_impl(self, from, to, ids_amounts, data)
+            }
+        }
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let psp37_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp37::PSP37DefaultImpl for #storage_struct_name {}
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let mut psp37 = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp37::PSP37 for #storage_struct_name {
+            #[ink(message)]
+            fn balance_of(&self, owner: AccountId, id: Option<Id>) -> Balance {
+                pendzl::contracts::psp37::PSP37DefaultImpl::balance_of_default_impl(self, owner, id)
+            }
+
+            #[ink(message)]
+            fn total_supply(&self, id: Option<Id>) -> Balance {
+                pendzl::contracts::psp37::PSP37DefaultImpl::total_supply_default_impl(self, id)
+            }
+
+            #[ink(message)]
+            fn allowance(&self, owner: AccountId, operator: AccountId, id: Option<Id>) -> Balance {
+                pendzl::contracts::psp37::PSP37DefaultImpl::allowance_default_impl(self, owner, operator, id)
+            }
+
+            #[ink(message)]
+            fn approve(&mut self, operator: AccountId, id: Option<Id>, value: Balance) -> Result<(), PSP37Error> {
+                pendzl::contracts::psp37::PSP37DefaultImpl::approve_default_impl(self, operator, id, value)
+            }
+
+            #[ink(message)]
+            fn transfer(&mut self, to: AccountId, id: Id, value: Balance, data: Vec<u8>) -> Result<(), PSP37Error> {
+                pendzl::contracts::psp37::PSP37DefaultImpl::transfer_default_impl(self, to, id, value, data)
+            }
+
+            #[ink(message)]
+            fn transfer_from(&mut self, from: AccountId, to: AccountId, id: Id, value: Balance, data: Vec<u8>) -> Result<(), PSP37Error> {
+                pendzl::contracts::psp37::PSP37DefaultImpl::transfer_from_default_impl(self, from, to, id, value, data)
+            }
+        }
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::psp37::*;
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let import_data = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::psp37::PSP37Data;
+    ))
+    .expect(DEFAULT_PARSE_IMPORT_ERR_MSG);
+
+    impl_args.imports.insert("PSP37", import);
+    impl_args.imports.insert("PSP37Data", import_data);
+    impl_args.vec_import();
+
+    override_functions("PSP37Internal", &mut internal, impl_args.map);
+    override_functions("PSP37", &mut psp37, impl_args.map);
+
+    impl_args.items.push(syn::Item::Impl(internal_default_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(psp37_default_impl));
+    impl_args.items.push(syn::Item::Impl(psp37));
+}
+
+pub(crate) fn impl_psp37_batch(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let batch_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp37::batch::PSP37BatchDefaultImpl for #storage_struct_name {}
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let mut batch = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp37::batch::PSP37Batch for #storage_struct_name {
+            #[ink(message)]
+            fn batch_transfer(&mut self, to: AccountId, ids_amounts: Vec<(Id, Balance)>, data: Vec<u8>) -> Result<(), PSP37Error> {
+                pendzl::contracts::psp37::batch::PSP37BatchDefaultImpl::batch_transfer_default_impl(self, to, ids_amounts, data)
+            }
+
+            #[ink(message)]
+            fn batch_transfer_from(&mut self, from: AccountId, to: AccountId, ids_amounts: Vec<(Id, Balance)>, data: Vec<u8>) -> Result<(), PSP37Error> {
+                pendzl::contracts::psp37::batch::PSP37BatchDefaultImpl::batch_transfer_from_default_impl(self, from, to, ids_amounts, data)
+            }
+        }
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::psp37::batch::*;
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    impl_args.imports.insert("PSP37Batch", import);
+    impl_args.vec_import();
+
+    override_functions("PSP37Batch", &mut batch, impl_args.map);
+
+    impl_args.items.push(syn::Item::Impl(batch_default_impl));
+    impl_args.items.push(syn::Item::Impl(batch));
+}
+
+pub(crate) fn impl_psp37_burnable(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let burnable_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp37::burnable::PSP37BurnableDefaultImpl for #storage_struct_name {}
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let mut burnable = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp37::burnable::PSP37Burnable for #storage_struct_name {
+            #[ink(message)]
+            fn burn(&mut self, from: AccountId, ids_amounts: Vec<(Id, Balance)>) -> Result<(), PSP37Error> {
+                pendzl::contracts::psp37::burnable::PSP37BurnableDefaultImpl::burn_default_impl(self, from, ids_amounts)
+            }
+        }
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::psp37::burnable::*;
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    impl_args.imports.insert("PSP37Burnable", import);
+    impl_args.vec_import();
+
+    override_functions("PSP37Burnable", &mut burnable, impl_args.map);
+
+    impl_args.items.push(syn::Item::Impl(burnable_default_impl));
+    impl_args.items.push(syn::Item::Impl(burnable));
+}
+
+pub(crate) fn impl_psp37_mintable(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let mintable_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp37::mintable::PSP37MintableDefaultImpl for #storage_struct_name {}
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let mut mintable = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp37::mintable::PSP37Mintable for #storage_struct_name {
+            #[ink(message)]
+            fn mint(&mut self, to: AccountId, ids_amounts: Vec<(Id, Balance)>) -> Result<(), PSP37Error> {
+                pendzl::contracts::psp37::mintable::PSP37MintableDefaultImpl::mint_default_impl(self, to, ids_amounts)
+            }
+        }
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::psp37::mintable::*;
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    impl_args.imports.insert("PSP37Mintable", import);
+    impl_args.vec_import();
+
+    override_functions("PSP37Mintable", &mut mintable, impl_args.map);
+
+    impl_args.items.push(syn::Item::Impl(mintable_default_impl));
+    impl_args.items.push(syn::Item::Impl(mintable));
+}
+
+pub(crate) fn impl_psp37_metadata(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp37::metadata::PSP37MetadataInternalDefaultImpl for #storage_struct_name {}
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp37::metadata::PSP37MetadataInternal for #storage_struct_name {
+            fn _set_attribute(&mut self, id: &Id, key: &str, value: &str) {
+                pendzl::contracts::psp37::metadata::PSP37MetadataInternalDefaultImpl::_set_attribute_default_impl(self, id, key, value)
+            }
+        }
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let metadata_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp37::metadata::PSP37MetadataDefaultImpl for #storage_struct_name {}
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let mut metadata = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp37::metadata::PSP37Metadata for #storage_struct_name {
+            #[ink(message)]
+            fn get_attribute(&self, id: Id, key: String) -> Option<String> {
+                pendzl::contracts::psp37::metadata::PSP37MetadataDefaultImpl::get_attribute_default_impl(self, id, key)
+            }
+        }
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::psp37::metadata::*;
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let import_data = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::psp37::metadata::PSP37MetadataData;
+    ))
+    .expect(DEFAULT_PARSE_IMPORT_ERR_MSG);
+
+    impl_args.imports.insert("PSP37Metadata", import);
+    impl_args.imports.insert("PSP37MetadataData", import_data);
+    impl_args.vec_import();
+
+    override_functions("PSP37MetadataInternal", &mut internal, impl_args.map);
+    override_functions("PSP37Metadata", &mut metadata, impl_args.map);
+
+    impl_args.items.push(syn::Item::Impl(internal_default_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(metadata_default_impl));
+    impl_args.items.push(syn::Item::Impl(metadata));
+}
+
+pub(crate) fn impl_psp37_enumerable(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp37::enumerable::PSP37EnumerableInternalDefaultImpl for #storage_struct_name {}
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp37::enumerable::PSP37EnumerableInternal for #storage_struct_name {
+            fn _update_enumeration(
+                &mut self,
+                from: &Option<&AccountId>,
+                to: &Option<&AccountId>,
+                ids_amounts: &Vec<(Id, Balance)>,
+            ) -> Result<(), PSP37Error> {
+                pendzl::contracts::psp37::enumerable::PSP37EnumerableInternalDefaultImpl::_update_enumeration_default_impl(self, from, to, ids_amounts)
+            }
+        }
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let enumerable_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp37::enumerable::PSP37EnumerableDefaultImpl for #storage_struct_name {}
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let mut enumerable = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp37::enumerable::PSP37Enumerable for #storage_struct_name {
+            #[ink(message)]
+            fn owners_token_by_index(&self, owner: AccountId, index: u128) -> Option<Id> {
+                pendzl::contracts::psp37::enumerable::PSP37EnumerableDefaultImpl::owners_token_by_index_default_impl(self, owner, index)
+            }
+
+            #[ink(message)]
+            fn token_by_index(&self, index: u128) -> Option<Id> {
+                pendzl::contracts::psp37::enumerable::PSP37EnumerableDefaultImpl::token_by_index_default_impl(self, index)
+            }
+        }
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::psp37::enumerable::*;
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let import_data = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::psp37::enumerable::PSP37EnumerableData;
+    ))
+    .expect(DEFAULT_PARSE_IMPORT_ERR_MSG);
+
+    impl_args.imports.insert("PSP37Enumerable", import);
+    impl_args.imports.insert("PSP37EnumerableData", import_data);
+    impl_args.vec_import();
+
+    override_functions("PSP37EnumerableInternal", &mut internal, impl_args.map);
+    override_functions("PSP37Enumerable", &mut enumerable, impl_args.map);
+
+    // keep the token lists in sync with every balance change
+    extend_function(
+        impl_args.items,
+        "PSP37Internal",
+        "_update",
+        quote!(),
+        quote!(
+            pendzl::contracts::psp37::enumerable::PSP37EnumerableInternal::_update_enumeration(self, from, to, ids_amounts)?;
+        ),
+    );
+
+    impl_args.items.push(syn::Item::Impl(internal_default_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args
+        .items
+        .push(syn::Item::Impl(enumerable_default_impl));
+    impl_args.items.push(syn::Item::Impl(enumerable));
+}
+
+pub(crate) fn impl_ownable(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::ownable::OwnableInternalDefaultImpl for #storage_struct_name {}
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::ownable::OwnableInternal for #storage_struct_name {
+            fn _owner(&self) -> Option<AccountId>{
+                pendzl::contracts::ownable::OwnableInternalDefaultImpl::_owner_default_impl(self)
+            }
+            fn _update_owner(&mut self, owner: &Option<AccountId>){
+                pendzl::contracts::ownable::OwnableInternalDefaultImpl::_update_owner_default_impl(self, owner);
+
+            }
+            fn _only_owner(&self) -> Result<(), OwnableError> {
+                pendzl::contracts::ownable::OwnableInternalDefaultImpl::_only_owner_default_impl(self)
+            }
+        }
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let ownable_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::ownable::OwnableDefaultImpl for #storage_struct_name {}
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let mut ownable = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::ownable::Ownable for #storage_struct_name {
+            #[ink(message)]
+            fn owner(&self) -> Option<AccountId> {
+                pendzl::contracts::ownable::OwnableDefaultImpl::owner_default_impl(self)
+            }
+
+            #[ink(message)]
+            fn renounce_ownership(&mut self) -> Result<(), OwnableError> {
+                pendzl::contracts::ownable::OwnableDefaultImpl::renounce_ownership_default_impl(self)
+            }
+
+            #[ink(message)]
+            fn transfer_ownership(&mut self, new_owner: AccountId) -> Result<(), OwnableError> {
+                pendzl::contracts::ownable::OwnableDefaultImpl::transfer_ownership_default_impl(self, new_owner)
+            }
+        }
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::ownable::*;
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let import_data = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::ownable::OwnableData;
+    ))
+    .expect(DEFAULT_PARSE_IMPORT_ERR_MSG);
+
+    impl_args.imports.insert("Ownable", import);
+    impl_args.imports.insert("OwnableData", import_data);
+
+    override_functions("OwnableInternal", &mut internal, impl_args.map);
+    override_functions("Ownable", &mut ownable, impl_args.map);
+
+    impl_args.items.push(syn::Item::Impl(internal_default_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(ownable_default_impl));
+    impl_args.items.push(syn::Item::Impl(ownable));
+}
+
+pub(crate) fn impl_access_control(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::access_control::AccessControlInternalDefaultImpl for #storage_struct_name {}
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::access_control::AccessControlInternal for #storage_struct_name {
+            fn _default_admin() -> RoleType {
+                <Self as pendzl::contracts::access_control::AccessControlInternalDefaultImpl>::_default_admin_default_impl()
+            }
+
+            fn _has_role(&self, role: RoleType, account: Option<AccountId>) -> bool{
+                pendzl::contracts::access_control::AccessControlInternalDefaultImpl::_has_role_default_impl(self, role, account)
+            }
+
+            fn _grant_role(&mut self, role: RoleType, account: Option<AccountId>) -> Result<(), AccessControlError> {
+                pendzl::contracts::access_control::AccessControlInternalDefaultImpl::_grant_role_default_impl(self, role, account)
+            }
+
+            fn _do_revoke_role(&mut self, role: RoleType, account: Option<AccountId>)  -> Result<(), AccessControlError>  {
+                pendzl::contracts::access_control::AccessControlInternalDefaultImpl::_do_revoke_role_default_impl(self, role, account)
+            }
+
+            fn _get_role_admin(&self, role: RoleType) -> RoleType {
+                pendzl::contracts::access_control::AccessControlInternalDefaultImpl::_get_role_admin_default_impl(self, role)
+            }
+
+            fn _set_role_admin(&mut self, role: RoleType, new_admin: RoleType) {
+                pendzl::contracts::access_control::AccessControlInternalDefaultImpl::_set_role_admin_default_impl(self, role, new_admin);
+            }
+
+            fn _ensure_has_role(&self, role: RoleType, account: Option<AccountId>) -> Result<(), AccessControlError> {
+                pendzl::contracts::access_control::AccessControlInternalDefaultImpl::_ensure_has_role_default_impl(self, role, account)
+            }
+
+        }
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let access_control_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::access_control::AccessControlDefaultImpl for #storage_struct_name {}
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let mut access_control = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::access_control::AccessControl for #storage_struct_name {
+            #[ink(message)]
+            fn has_role(&self, role: RoleType, address: Option<AccountId>) -> bool {
+                pendzl::contracts::access_control::AccessControlDefaultImpl::has_role_default_impl(self, role, address)
+            }
+
+            #[ink(message)]
+            fn get_role_admin(&self, role: RoleType) -> RoleType {
+                pendzl::contracts::access_control::AccessControlDefaultImpl::get_role_admin_default_impl(self, role)
+            }
+
+            #[ink(message)]
+            fn grant_role(&mut self, role: RoleType, account: Option<AccountId>) -> Result<(), AccessControlError> {
+                pendzl::contracts::access_control::AccessControlDefaultImpl::grant_role_default_impl(self, role, account)
+            }
+
+            #[ink(message)]
+            fn revoke_role(&mut self, role: RoleType, account: Option<AccountId>) -> Result<(), AccessControlError> {
+                pendzl::contracts::access_control::AccessControlDefaultImpl::revoke_role_default_impl(self, role, account)
+            }
+
+            #[ink(message)]
+            fn renounce_role(&mut self, role: RoleType, account: Option<AccountId>) -> Result<(), AccessControlError> {
+                pendzl::contracts::access_control::AccessControlDefaultImpl::renounce_role_default_impl(self, role, account)
+            }
+
+            #[ink(message)]
+            fn set_role_admin(&mut self, role: RoleType, new_admin: RoleType) -> Result<(), AccessControlError> {
+                pendzl::contracts::access_control::AccessControlDefaultImpl::set_role_admin_default_impl(self, role, new_admin)
+            }
+        }
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::access_control::*;
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let import_data = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::access_control::AccessControlData;
+    ))
+    .expect(DEFAULT_PARSE_IMPORT_ERR_MSG);
+
+    impl_args.imports.insert("AccessControl", import);
+    impl_args.imports.insert("AccessControlData", import_data);
+
+    override_functions("AccessControlInternal", &mut internal, impl_args.map);
+    override_functions("AccessControl", &mut access_control, impl_args.map);
+
+    impl_args.items.push(syn::Item::Impl(internal_default_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args
+        .items
+        .push(syn::Item::Impl(access_control_default_impl));
+    impl_args.items.push(syn::Item::Impl(access_control));
+}
+
+pub(crate) fn impl_pausable(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::pausable::PausableInternalDefaultImpl for #storage_struct_name {}
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::pausable::PausableInternal for #storage_struct_name {
+            fn _paused(&self) -> bool {
+                pendzl::contracts::pausable::PausableInternalDefaultImpl::_paused_default_impl(self)
+            }
+
+            fn _pause(&mut self) -> Result<(), PausableError> {
+                pendzl::contracts::pausable::PausableInternalDefaultImpl::_pause_default_impl(self)
+            }
+
+            fn _unpause(&mut self) -> Result<(), PausableError> {
+                pendzl::contracts::pausable::PausableInternalDefaultImpl::_unpause_default_impl(self)
+            }
+
+            fn _ensure_paused(&self) -> Result<(), PausableError> {
+                pendzl::contracts::pausable::PausableInternalDefaultImpl::_ensure_paused_default_impl(self)
+            }
+
+            fn _ensure_not_paused(&self) -> Result<(), PausableError> {
+                pendzl::contracts::pausable::PausableInternalDefaultImpl::_ensure_not_paused_default_impl(self)
+            }
+        }
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let pausable_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl  pendzl::contracts::pausable::PausableDefaultImpl for #storage_struct_name {}
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let mut pausable = syn::parse2::<syn::ItemImpl>(quote!(
+        impl  pendzl::contracts::pausable::Pausable for #storage_struct_name {
+            #[ink(message)]
+            fn paused(&self) -> bool {
+                pendzl::contracts::pausable::PausableDefaultImpl::paused_default_impl(self)
+            }
+        }
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::pausable::*;
+    ))
+    .expect(DEFAULT_PARSE_IMPORT_ERR_MSG);
+
+    let import_data = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::pausable::PausableData;
+    ))
+    .expect(DEFAULT_PARSE_IMPORT_ERR_MSG);
+    impl_args.imports.insert("Pausable", import);
+    impl_args.imports.insert("PausableData", import_data);
+
+    override_functions("PausableInternal", &mut internal, impl_args.map);
+    override_functions("Pausable", &mut pausable, impl_args.map);
+
+    impl_args.items.push(syn::Item::Impl(internal_default_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(pausable_default_impl));
+    impl_args.items.push(syn::Item::Impl(pausable));
+}
+pub(crate) fn impl_vesting(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::general_vest::GeneralVestInternalDefaultImpl for #storage_struct_name {}
+    ))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::general_vest::GeneralVestInternal for #storage_struct_name {
+            fn _create_vest(&mut self,
+                receiver: AccountId,
+                asset: Option<AccountId>,
+                amount: Balance,
+                schedule: VestingSchedule,
                 data: &Vec<u8>
             )-> Result<(), VestingError>  {
                 pendzl::contracts::general_vest::GeneralVestInternalDefaultImpl::_create_vest_default_impl(
@@ -1333,3 +4053,113 @@ fn override_functions(
         }
     }
 }
+
+/// Returns true if `fn_name` of `trait_name` is overridden with `#[overrider]`.
+fn is_overridden(
+    map: &OverridenFnMap,
+    trait_name: &str,
+    fn_name: &str,
+) -> bool {
+    map.get(trait_name).is_some_and(|overrides| {
+        overrides.iter().any(|(name, _)| name == fn_name)
+    })
+}
+
+/// Finds the already generated implementation of `trait_name`.
+fn find_impl<'a>(
+    items: &'a mut [syn::Item],
+    trait_name: &str,
+) -> Option<&'a mut syn::ItemImpl> {
+    items.iter_mut().find_map(|item| match item {
+        syn::Item::Impl(implementation)
+            if implementation.trait_.as_ref().is_some_and(
+                |(_, path, _)| {
+                    path.segments
+                        .last()
+                        .is_some_and(|segment| segment.ident == trait_name)
+                },
+            ) =>
+        {
+            Some(implementation)
+        }
+        _ => None,
+    })
+}
+
+/// Finds a function in an already generated trait implementation.
+///
+/// Arguments:
+/// - `items`: The module items containing the generated implementation.
+/// - `trait_name`: The name of the trait whose implementation is searched.
+/// - `fn_name`: The name of the function to find.
+///
+/// Returns `None` if the implementation of `trait_name` or the function `fn_name` is not found.
+fn find_function<'a>(
+    items: &'a mut [syn::Item],
+    trait_name: &str,
+    fn_name: &str,
+) -> Option<&'a mut syn::ImplItemMethod> {
+    items
+        .iter_mut()
+        .filter_map(|item| match item {
+            syn::Item::Impl(implementation) => Some(implementation),
+            _ => None,
+        })
+        .filter(|implementation| {
+            implementation.trait_.as_ref().is_some_and(|(_, path, _)| {
+                path.segments
+                    .last()
+                    .is_some_and(|segment| segment.ident == trait_name)
+            })
+        })
+        .flat_map(|implementation| implementation.items.iter_mut())
+        .find_map(|item| match item {
+            syn::ImplItem::Method(method) if method.sig.ident == fn_name => {
+                Some(method)
+            }
+            _ => None,
+        })
+}
+
+/// Extends the body of a function in an already generated trait implementation.
+///
+/// Used by extensions that need to hook into a function of the trait they extend
+/// (e.g. `PSP37Enumerable` updating its token lists in `PSP37Internal::_update`).
+/// The resulting body executes `before`, then the original body propagating its error,
+/// then `after` and finally returns `Ok(())`.
+///
+/// Arguments:
+/// - `items`: The module items containing the generated implementation.
+/// - `trait_name`: The name of the trait whose implementation is extended.
+/// - `fn_name`: The name of the function to extend.
+/// - `before`: Statements executed before the original body.
+/// - `after`: Statements executed after the original body.
+///
+/// Panics if the implementation of `trait_name` or the function `fn_name` is not found.
+fn extend_function(
+    items: &mut [syn::Item],
+    trait_name: &str,
+    fn_name: &str,
+    before: proc_macro2::TokenStream,
+    after: proc_macro2::TokenStream,
+) {
+    let method =
+        find_function(items, trait_name, fn_name).unwrap_or_else(|| {
+            panic!("Could not find fn {} in trait {}", fn_name, trait_name)
+        });
+
+    let block = method.block.clone();
+    // the type of the wrapped body can not be inferred if it was already extended
+    let output = match &method.sig.output {
+        syn::ReturnType::Type(_, ty) => quote!(#ty),
+        syn::ReturnType::Default => quote!(()),
+    };
+    method.block = syn::parse2::<Block>(quote!({
+        #before
+        let result: #output = #block;
+        result?;
+        #after
+        Ok(())
+    }))
+    .expect(DEFAULT_PARSE_ERR_MSG);
+}
@@ -0,0 +1,62 @@
+// SPDX-License-Identifier: MIT
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+#[ink::contract]
+pub mod t_flash_borrower {
+    use ink::prelude::{string::String, vec::Vec};
+    use pendzl::contracts::psp22::{
+        flash_lender::{PSP22FlashBorrower, PSP22FlashBorrowerError},
+        PSP22Ref, PSP22,
+    };
+
+    #[ink(storage)]
+    #[derive(Default)]
+    pub struct Contract {
+        reject: bool,
+        repay: bool,
+        received_loans: u32,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new(reject: bool, repay: bool) -> Self {
+            Self {
+                reject,
+                repay,
+                received_loans: 0,
+            }
+        }
+
+        #[ink(message)]
+        pub fn received_loans(&self) -> u32 {
+            self.received_loans
+        }
+    }
+
+    impl PSP22FlashBorrower for Contract {
+        #[ink(message)]
+        fn on_flash_loan(
+            &mut self,
+            _initiator: AccountId,
+            amount: Balance,
+            fee: Balance,
+            _data: Vec<u8>,
+        ) -> Result<(), PSP22FlashBorrowerError> {
+            if self.reject {
+                return Err(PSP22FlashBorrowerError::FlashLoanRejected(
+                    String::from("Rejected by borrower"),
+                ));
+            }
+            self.received_loans += 1;
+            if self.repay {
+                let lender = Self::env().caller();
+                let mut token: PSP22Ref = lender.into();
+                token.approve(lender, amount + fee).map_err(|_| {
+                    PSP22FlashBorrowerError::FlashLoanRejected(String::from(
+                        "Approve failed",
+                    ))
+                })?;
+            }
+            Ok(())
+        }
+    }
+}
@@ -79,7 +79,11 @@ pub mod t_psp22 {
             amount: Balance,
         ) -> Result<(), PSP22Error> {
             PSP22InternalDefaultImpl::_transfer_default_impl(
-                self, &from, &to, &amount,
+                self,
+                &from,
+                &to,
+                &amount,
+                &Vec::new(),
             )
         }
         #[ink(message)]
@@ -0,0 +1,27 @@
+// SPDX-License-Identifier: MIT
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+#[pendzl::implementation(PSP22, PSP22ExpiringAllowance)]
+#[ink::contract]
+pub mod t_psp22_expiring_allowance {
+    use pendzl::contracts::psp22::*;
+
+    #[ink(storage)]
+    #[derive(Default, StorageFieldGetter)]
+    pub struct Contract {
+        #[storage_field]
+        psp22: PSP22Data,
+        #[storage_field]
+        expiring_allowance: PSP22ExpiringAllowanceData,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new(total_supply: Balance) -> Self {
+            let mut instance = Self::default();
+            instance
+                ._mint_to(&Self::env().caller(), &total_supply)
+                .expect("Should mint");
+            instance
+        }
+    }
+}
@@ -0,0 +1,44 @@
+// SPDX-License-Identifier: MIT
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+#[pendzl::implementation(PSP22, PSP22FlashLender)]
+#[ink::contract]
+pub mod t_psp22_flash_lender {
+    use pendzl::contracts::psp22::*;
+
+    #[ink(storage)]
+    #[derive(Default, StorageFieldGetter)]
+    pub struct Contract {
+        #[storage_field]
+        psp22: PSP22Data,
+        fee_percentage: u8,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new(total_supply: Balance, fee_percentage: u8) -> Self {
+            let mut instance = Self::default();
+            let caller = instance.env().caller();
+            instance.fee_percentage = fee_percentage;
+
+            instance
+                ._mint_to(&caller, &total_supply)
+                .expect("Should mint total_supply");
+
+            instance
+        }
+
+        #[ink(message)]
+        pub fn t_mint(
+            &mut self,
+            to: AccountId,
+            amount: Balance,
+        ) -> Result<(), PSP22Error> {
+            self._mint_to(&to, &amount)
+        }
+    }
+
+    #[overrider(PSP22FlashLenderInternal)]
+    fn _flash_fee(&self, amount: &Balance) -> Result<Balance, PSP22Error> {
+        Ok(amount * self.fee_percentage as u128 / 100)
+    }
+}
@@ -0,0 +1,27 @@
+// SPDX-License-Identifier: MIT
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+#[pendzl::implementation(PSP22)]
+#[ink::contract]
+pub mod t_psp22_infinite_allowance {
+    use pendzl::contracts::psp22::*;
+
+    #[ink(storage)]
+    #[derive(Default, StorageFieldGetter)]
+    pub struct Contract {
+        #[storage_field]
+        psp22: PSP22Data,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new(total_supply: Balance) -> Self {
+            let mut instance = Self {
+                psp22: PSP22Data::with_infinite_allowance(),
+            };
+            instance
+                ._mint_to(&Self::env().caller(), &total_supply)
+                .expect("Should mint");
+            instance
+        }
+    }
+}
@@ -0,0 +1,21 @@
+// SPDX-License-Identifier: MIT
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+#[pendzl::implementation(PSP22, PSP22NativeWrapper)]
+#[ink::contract]
+pub mod t_psp22_native_wrapper {
+    use pendzl::contracts::psp22::*;
+
+    #[ink(storage)]
+    #[derive(Default, StorageFieldGetter)]
+    pub struct Contract {
+        #[storage_field]
+        psp22: PSP22Data,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+}
@@ -0,0 +1,43 @@
+// SPDX-License-Identifier: MIT
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+#[pendzl::implementation(PSP22, PSP22TransferFee)]
+#[ink::contract]
+pub mod t_psp22_transfer_fee {
+    use pendzl::contracts::psp22::*;
+
+    #[ink(storage)]
+    #[derive(Default, StorageFieldGetter)]
+    pub struct Contract {
+        #[storage_field]
+        psp22: PSP22Data,
+        #[storage_field]
+        transfer_fee: PSP22TransferFeeData,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new(
+            total_supply: Balance,
+            transfer_fee_bps: u16,
+            fee_recipient: AccountId,
+        ) -> Self {
+            let mut instance = Self::default();
+            instance.transfer_fee =
+                PSP22TransferFeeData::new(transfer_fee_bps, fee_recipient)
+                    .expect("Should set transfer fee");
+            instance
+                ._mint_to(&Self::env().caller(), &total_supply)
+                .expect("Should mint");
+            instance
+        }
+
+        #[ink(constructor)]
+        pub fn new_without_fee_recipient(total_supply: Balance) -> Self {
+            let mut instance = Self::default();
+            instance
+                ._mint_to(&Self::env().caller(), &total_supply)
+                .expect("Should mint");
+            instance
+        }
+    }
+}
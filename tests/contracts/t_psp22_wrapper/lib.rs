@@ -0,0 +1,33 @@
+// SPDX-License-Identifier: MIT
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+#[pendzl::implementation(PSP22, PSP22Wrapper)]
+#[ink::contract]
+pub mod t_psp22_wrapper {
+    use pendzl::contracts::psp22::*;
+
+    #[ink(storage)]
+    #[derive(Default, StorageFieldGetter)]
+    pub struct Contract {
+        #[storage_field]
+        psp22: PSP22Data,
+        #[storage_field]
+        wrapper: PSP22WrapperData,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new(underlying: AccountId) -> Self {
+            let mut instance = Self::default();
+            instance.wrapper = PSP22WrapperData::new(underlying);
+            instance
+        }
+
+        #[ink(message)]
+        pub fn t_recover(
+            &mut self,
+            account: AccountId,
+        ) -> Result<Balance, PSP22Error> {
+            self._recover(&account)
+        }
+    }
+}
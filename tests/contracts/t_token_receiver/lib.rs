@@ -0,0 +1,81 @@
+// SPDX-License-Identifier: MIT
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+#[ink::contract]
+pub mod t_token_receiver {
+    use ink::prelude::{string::String, vec::Vec};
+    use pendzl::contracts::psp22::{PSP22Receiver, PSP22ReceiverError};
+    use pendzl::contracts::psp34::{Id, PSP34Receiver, PSP34ReceiverError};
+
+    #[ink(storage)]
+    #[derive(Default)]
+    pub struct Contract {
+        reject: bool,
+        received_calls: u32,
+        last_received_value: Balance,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new(reject: bool) -> Self {
+            Self {
+                reject,
+                received_calls: 0,
+                last_received_value: 0,
+            }
+        }
+
+        #[ink(message)]
+        pub fn set_reject(&mut self, reject: bool) {
+            self.reject = reject;
+        }
+
+        #[ink(message)]
+        pub fn received_calls(&self) -> u32 {
+            self.received_calls
+        }
+
+        #[ink(message)]
+        pub fn last_received_value(&self) -> Balance {
+            self.last_received_value
+        }
+    }
+
+    impl PSP22Receiver for Contract {
+        #[ink(message)]
+        fn before_received(
+            &mut self,
+            _operator: AccountId,
+            _from: AccountId,
+            value: Balance,
+            _data: Vec<u8>,
+        ) -> Result<(), PSP22ReceiverError> {
+            if self.reject {
+                return Err(PSP22ReceiverError::TransferRejected(
+                    String::from("Rejected by receiver"),
+                ));
+            }
+            self.received_calls += 1;
+            self.last_received_value = value;
+            Ok(())
+        }
+    }
+
+    impl PSP34Receiver for Contract {
+        #[ink(message)]
+        fn before_received(
+            &mut self,
+            _operator: AccountId,
+            _from: Option<AccountId>,
+            _id: Id,
+            _data: Vec<u8>,
+        ) -> Result<(), PSP34ReceiverError> {
+            if self.reject {
+                return Err(PSP34ReceiverError::TransferRejected(
+                    String::from("Rejected by receiver"),
+                ));
+            }
+            self.received_calls += 1;
+            Ok(())
+        }
+    }
+}
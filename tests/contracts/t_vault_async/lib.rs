@@ -0,0 +1,46 @@
+// SPDX-License-Identifier: MIT
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+#[pendzl::implementation(PSP22, PSP22Vault, PSP22VaultAsync)]
+#[ink::contract]
+pub mod t_vault_async {
+    #[ink(storage)]
+    #[derive(Default, StorageFieldGetter)]
+    pub struct Contract {
+        #[storage_field]
+        psp22: PSP22Data,
+        #[storage_field]
+        vault: PSP22VaultData,
+        #[storage_field]
+        vault_async: PSP22VaultAsyncData,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new(asset: AccountId) -> Self {
+            Self {
+                psp22: PSP22Data::default(),
+                vault: PSP22VaultData::new(asset, None),
+                vault_async: PSP22VaultAsyncData::default(),
+            }
+        }
+
+        #[ink(message)]
+        pub fn t_fulfill_deposit(
+            &mut self,
+            controller: AccountId,
+            assets: Balance,
+        ) -> Result<Balance, PSP22Error> {
+            self._fulfill_deposit(&controller, &assets)
+        }
+
+        #[ink(message)]
+        pub fn t_fulfill_redeem(
+            &mut self,
+            controller: AccountId,
+            shares: Balance,
+        ) -> Result<Balance, PSP22Error> {
+            self._fulfill_redeem(&controller, &shares)
+        }
+    }
+}
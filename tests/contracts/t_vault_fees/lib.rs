@@ -0,0 +1,58 @@
+// SPDX-License-Identifier: MIT
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+#[pendzl::implementation(PSP22, PSP22Vault, PSP22VaultFees)]
+#[ink::contract]
+pub mod t_vault_fees {
+    #[ink(storage)]
+    #[derive(Default, StorageFieldGetter)]
+    pub struct Contract {
+        #[storage_field]
+        psp22: PSP22Data,
+        #[storage_field]
+        vault: PSP22VaultData,
+        #[storage_field]
+        fees: PSP22VaultFeesData,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new(
+            asset: AccountId,
+            entry_fee_bps: u16,
+            exit_fee_bps: u16,
+            management_fee_bps: u16,
+            performance_fee_bps: u16,
+            fee_recipient: AccountId,
+        ) -> Result<Self, PSP22Error> {
+            Ok(Self {
+                psp22: PSP22Data::default(),
+                vault: PSP22VaultData::new(asset, None),
+                fees: PSP22VaultFeesData::new(
+                    entry_fee_bps,
+                    exit_fee_bps,
+                    management_fee_bps,
+                    performance_fee_bps,
+                    fee_recipient,
+                )?,
+            })
+        }
+
+        #[ink(constructor)]
+        pub fn new_without_fee_recipient(asset: AccountId) -> Self {
+            Self {
+                psp22: PSP22Data::default(),
+                vault: PSP22VaultData::new(asset, None),
+                fees: PSP22VaultFeesData::default(),
+            }
+        }
+
+        #[ink(message)]
+        pub fn t_set_entry_fee_bps(
+            &mut self,
+            fee_bps: u16,
+        ) -> Result<(), PSP22Error> {
+            self._set_entry_fee_bps(&fee_bps)
+        }
+    }
+}
@@ -0,0 +1,28 @@
+// SPDX-License-Identifier: MIT
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+#[pendzl::implementation(PSP22, PSP22Vault, PSP22VaultNative)]
+#[ink::contract]
+pub mod t_vault_native {
+    #[ink(storage)]
+    #[derive(Default, StorageFieldGetter)]
+    pub struct Contract {
+        #[storage_field]
+        psp22: PSP22Data,
+        #[storage_field]
+        vault: PSP22VaultData,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new(underlying_decimals: u8) -> Self {
+            Self {
+                psp22: PSP22Data::default(),
+                vault: PSP22VaultData::new(
+                    NATIVE_ASSET_ID.into(),
+                    Some(underlying_decimals),
+                ),
+            }
+        }
+    }
+}
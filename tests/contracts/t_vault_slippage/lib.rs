@@ -0,0 +1,25 @@
+// SPDX-License-Identifier: MIT
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+#[pendzl::implementation(PSP22, PSP22Vault, PSP22VaultSlippage)]
+#[ink::contract]
+pub mod t_vault_slippage {
+    #[ink(storage)]
+    #[derive(Default, StorageFieldGetter)]
+    pub struct Contract {
+        #[storage_field]
+        psp22: PSP22Data,
+        #[storage_field]
+        vault: PSP22VaultData,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new(asset: AccountId) -> Self {
+            Self {
+                psp22: PSP22Data::default(),
+                vault: PSP22VaultData::new(asset, None),
+            }
+        }
+    }
+}
@@ -4,6 +4,7 @@ import { shouldBehaveLikePSP22, shouldBehaveLikePSP22Approve, shouldBehaveLikePS
 import { getSigners, localApi } from '@c-forge/polkahat-network-helpers';
 import TPsp22Deployer from 'typechain/deployers/t_psp22';
 import TPsp22Contract from 'typechain/contracts/t_psp22';
+import TTokenReceiverDeployer from 'typechain/deployers/t_token_receiver';
 import type { KeyringPair } from '@polkadot/keyring/types';
 import { MAX_U128 } from '@c-forge/polkahat-chai-matchers';
 import '@c-forge/polkahat-chai-matchers';
@@ -175,4 +176,38 @@ describe('PSP 22', () => {
 
     shouldBehaveLikePSP22Approve(() => ctx);
   });
+
+  describe('safe transfer check', function () {
+    const value = new BN(100);
+
+    it('calls before_received on a receiver contract', async function () {
+      const api = await localApi.get();
+      const receiver = (await new TTokenReceiverDeployer(api, owner).new(false)).contract;
+
+      const tx = await ctx.token.withSigner(ctx.holder).tx.transfer(receiver.address, value, []);
+      await expect(tx).to.changePSP22Balances(ctx.token, [ctx.holder.address, receiver.address], [value.neg(), value]);
+      await expect(receiver.query.receivedCalls()).to.haveOkResult(1);
+    });
+
+    it('reverts when the receiver contract rejects the transfer', async function () {
+      const api = await localApi.get();
+      const receiver = (await new TTokenReceiverDeployer(api, owner).new(true)).contract;
+
+      await expect(ctx.token.withSigner(ctx.holder).query.transfer(receiver.address, value, [])).to.be.revertedWithError({
+        safeTransferCheckFailed: 'Rejected by receiver',
+      });
+    });
+
+    it('reverts transfer_from when the receiver contract rejects the transfer', async function () {
+      const api = await localApi.get();
+      const receiver = (await new TTokenReceiverDeployer(api, owner).new(true)).contract;
+      await ctx.token.withSigner(ctx.holder).tx.approve(ctx.other.address, value);
+
+      await expect(
+        ctx.token.withSigner(ctx.other).query.transferFrom(ctx.holder.address, receiver.address, value, []),
+      ).to.be.revertedWithError({
+        safeTransferCheckFailed: 'Rejected by receiver',
+      });
+    });
+  });
 });
@@ -0,0 +1,66 @@
+import { ApiPromise } from '@polkadot/api';
+import BN from 'bn.js';
+import TPsp22ExpiringAllowanceDeployer from 'typechain/deployers/t_psp22_expiring_allowance';
+import TPsp22ExpiringAllowanceContract from 'typechain/contracts/t_psp22_expiring_allowance';
+import '@c-forge/polkahat-chai-matchers';
+import { expect } from 'chai';
+import { getSigners, localApi, time } from '@c-forge/polkahat-network-helpers';
+
+const [owner, spender, recipient] = getSigners();
+
+const EXPIRES_AT = 2_000;
+
+describe('PSP22 expiring allowance', function () {
+  let api: ApiPromise;
+  let token: TPsp22ExpiringAllowanceContract;
+
+  beforeEach(async function () {
+    api = await localApi.get();
+    await time.setTo(1_000);
+    token = (await new TPsp22ExpiringAllowanceDeployer(api, owner).new(1_000)).contract;
+    await token.withSigner(owner).tx.approveUntil(spender.address, 100, EXPIRES_AT);
+  });
+
+  it('decrease_allowance keeps the expiry before it expires', async function () {
+    await token.withSigner(owner).tx.decreaseAllowance(spender.address, 40);
+
+    await expect(token.query.allowance(owner.address, spender.address)).to.haveOkResult(60);
+    await expect(token.query.allowanceExpiry(owner.address, spender.address)).to.haveOkResult(EXPIRES_AT);
+  });
+
+  describe('after expiry', function () {
+    beforeEach(async function () {
+      await time.setTo(EXPIRES_AT + 1_000);
+    });
+
+    it('expired allowance can not be spent', async function () {
+      await expect(token.query.allowance(owner.address, spender.address)).to.haveOkResult(0);
+      await expect(token.withSigner(spender).query.transferFrom(owner.address, recipient.address, 1, [])).to.be.revertedWithError(
+        'AllowanceExpired',
+      );
+    });
+
+    it('increase_allowance starts from 0 without expiry', async function () {
+      await token.withSigner(owner).tx.increaseAllowance(spender.address, 50);
+
+      await expect(token.query.allowance(owner.address, spender.address)).to.haveOkResult(50);
+      await expect(token.query.allowanceExpiry(owner.address, spender.address)).to.haveOkResult(null);
+
+      const tx = token.withSigner(spender).tx.transferFrom(owner.address, recipient.address, 50, []);
+
+      await expect(tx).to.changePSP22Balances(token, [owner.address, recipient.address], [new BN(-50), new BN(50)]);
+      await expect(token.withSigner(spender).query.transferFrom(owner.address, recipient.address, 1, [])).to.be.revertedWithError(
+        'InsufficientAllowance',
+      );
+    });
+
+    it('decrease_allowance resets the allowance and its expiry', async function () {
+      await expect(token.withSigner(owner).query.decreaseAllowance(spender.address, 1)).to.be.revertedWithError('InsufficientAllowance');
+
+      await token.withSigner(owner).tx.decreaseAllowance(spender.address, 0);
+
+      await expect(token.query.allowance(owner.address, spender.address)).to.haveOkResult(0);
+      await expect(token.query.allowanceExpiry(owner.address, spender.address)).to.haveOkResult(null);
+    });
+  });
+});
@@ -0,0 +1,75 @@
+import BN from 'bn.js';
+import { getSigners, localApi } from '@c-forge/polkahat-network-helpers';
+import TPsp22FlashLenderDeployer from 'typechain/deployers/t_psp22_flash_lender';
+import TPsp22FlashLenderContract from 'typechain/contracts/t_psp22_flash_lender';
+import TFlashBorrowerDeployer from 'typechain/deployers/t_flash_borrower';
+import TPsp22Deployer from 'typechain/deployers/t_psp22';
+import { MAX_U128 } from '@c-forge/polkahat-chai-matchers';
+import '@c-forge/polkahat-chai-matchers';
+import { expect } from 'chai';
+
+const [owner] = getSigners();
+const initialSupply = new BN(1000);
+const FEE_PERCENTAGE = 1;
+
+describe('PSP22 Flash Lender', () => {
+  let token: TPsp22FlashLenderContract;
+
+  beforeEach(async () => {
+    const api = await localApi.get();
+    token = (await new TPsp22FlashLenderDeployer(api, owner).new(initialSupply, FEE_PERCENTAGE)).contract;
+  });
+
+  it('max_flash_loan is the remaining supply', async function () {
+    await expect(token.query.maxFlashLoan()).to.haveOkResult(MAX_U128.sub(initialSupply));
+  });
+
+  it('flash_fee uses the overridden fee', async function () {
+    await expect(token.query.flashFee(1000)).to.haveOkResult(10);
+  });
+
+  it('lends, calls the borrower and burns amount with fee', async function () {
+    const api = await localApi.get();
+    const borrower = (await new TFlashBorrowerDeployer(api, owner).new(false, true)).contract;
+    await token.withSigner(owner).tx.tMint(borrower.address, 10);
+
+    const tx = await token.withSigner(owner).tx.flashLoan(borrower.address, 1000, []);
+    await expect(tx).to.changePSP22Balances(token, [borrower.address], [new BN(-10)]);
+    await expect(borrower.query.receivedLoans()).to.haveOkResult(1);
+    await expect(token.query.totalSupply()).to.haveOkResult(initialSupply);
+  });
+
+  it('reverts if amount exceeds max_flash_loan', async function () {
+    const api = await localApi.get();
+    const borrower = (await new TFlashBorrowerDeployer(api, owner).new(false, true)).contract;
+
+    await expect(token.withSigner(owner).query.flashLoan(borrower.address, MAX_U128, [])).to.be.revertedWithError(
+      'FlashLoanAmountExceeded',
+    );
+  });
+
+  it('reverts if the borrower rejects the loan', async function () {
+    const api = await localApi.get();
+    const borrower = (await new TFlashBorrowerDeployer(api, owner).new(true, true)).contract;
+
+    await expect(token.withSigner(owner).query.flashLoan(borrower.address, 1000, [])).to.be.revertedWithError({
+      flashLoanRejected: 'Rejected by borrower',
+    });
+  });
+
+  it('reverts if the borrower does not approve the repayment', async function () {
+    const api = await localApi.get();
+    const borrower = (await new TFlashBorrowerDeployer(api, owner).new(false, false)).contract;
+
+    await expect(token.withSigner(owner).query.flashLoan(borrower.address, 1000, [])).to.be.revertedWithError('InsufficientAllowance');
+  });
+
+  it('reverts if the receiver does not implement PSP22FlashBorrower', async function () {
+    const api = await localApi.get();
+    const receiver = (await new TPsp22Deployer(api, owner).new(0, null, null, 0)).contract;
+
+    await expect(token.withSigner(owner).query.flashLoan(receiver.address, 1000, [])).to.be.revertedWithError({
+      flashLoanRejected: "Receiver doesn't implement PSP22FlashBorrower",
+    });
+  });
+});
@@ -0,0 +1,44 @@
+import BN from 'bn.js';
+import { getSigners, localApi } from '@c-forge/polkahat-network-helpers';
+import TPsp22InfiniteAllowanceDeployer from 'typechain/deployers/t_psp22_infinite_allowance';
+import TPsp22InfiniteAllowanceContract from 'typechain/contracts/t_psp22_infinite_allowance';
+import { MAX_U128 } from '@c-forge/polkahat-chai-matchers';
+import '@c-forge/polkahat-chai-matchers';
+import { expect } from 'chai';
+
+const [owner, spender, recipient] = getSigners();
+const initialSupply = new BN(1000);
+
+describe('PSP22 infinite allowance', () => {
+  let token: TPsp22InfiniteAllowanceContract;
+
+  beforeEach(async () => {
+    const api = await localApi.get();
+    token = (await new TPsp22InfiniteAllowanceDeployer(api, owner).new(initialSupply)).contract;
+  });
+
+  it('transfer_from does not decrease an infinite allowance', async function () {
+    await token.withSigner(owner).tx.approve(spender.address, MAX_U128);
+
+    const tx = token.withSigner(spender).tx.transferFrom(owner.address, recipient.address, 400, []);
+
+    await expect(tx).to.changePSP22Balances(token, [owner.address, recipient.address], [new BN(-400), new BN(400)]);
+    await expect(token.query.allowance(owner.address, spender.address)).to.haveOkResult(MAX_U128);
+  });
+
+  it('transfer_from decreases a finite allowance', async function () {
+    await token.withSigner(owner).tx.approve(spender.address, 500);
+
+    await token.withSigner(spender).tx.transferFrom(owner.address, recipient.address, 400, []);
+
+    await expect(token.query.allowance(owner.address, spender.address)).to.haveOkResult(100);
+  });
+
+  it('owner can decrease an infinite allowance', async function () {
+    await token.withSigner(owner).tx.approve(spender.address, MAX_U128);
+
+    await token.withSigner(owner).tx.decreaseAllowance(spender.address, 1);
+
+    await expect(token.query.allowance(owner.address, spender.address)).to.haveOkResult(new BN(MAX_U128).subn(1));
+  });
+});
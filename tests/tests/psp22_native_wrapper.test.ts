@@ -0,0 +1,45 @@
+import BN from 'bn.js';
+import { getSigners, localApi } from '@c-forge/polkahat-network-helpers';
+import TPsp22NativeWrapperDeployer from 'typechain/deployers/t_psp22_native_wrapper';
+import TPsp22NativeWrapperContract from 'typechain/contracts/t_psp22_native_wrapper';
+import '@c-forge/polkahat-chai-matchers';
+import { expect } from 'chai';
+
+const [owner, alice] = getSigners();
+const amount = new BN(1000);
+
+describe('PSP22 Native Wrapper', () => {
+  let token: TPsp22NativeWrapperContract;
+
+  beforeEach(async () => {
+    const api = await localApi.get();
+    token = (await new TPsp22NativeWrapperDeployer(api, owner).new()).contract;
+  });
+
+  it('deposit mints the transferred value to the caller', async function () {
+    const tx = token.withSigner(alice).tx.deposit({ value: amount });
+
+    await expect(tx).to.emitEvent(token, 'Deposit', { account: alice.address, amount });
+    await expect(tx).to.changeBalances([token.address], [amount]);
+    await expect(tx).to.changePSP22Balances(token, [alice.address], [amount]);
+  });
+
+  describe('withdraw', () => {
+    beforeEach(async () => {
+      await token.withSigner(alice).tx.deposit({ value: amount });
+    });
+
+    it('burns tokens and sends native currency to the caller', async function () {
+      const tx = token.withSigner(alice).tx.withdraw(400);
+
+      await expect(tx).to.emitEvent(token, 'Withdrawal', { account: alice.address, amount: 400 });
+      await expect(tx).to.changeBalances([token.address], [new BN(-400)]);
+      await expect(tx).to.changePSP22Balances(token, [alice.address], [new BN(-400)]);
+      await expect(token.query.totalSupply()).to.haveOkResult(600);
+    });
+
+    it('reverts if the caller has insufficient balance', async function () {
+      await expect(token.withSigner(owner).query.withdraw(1)).to.be.revertedWithError('InsufficientBalance');
+    });
+  });
+});
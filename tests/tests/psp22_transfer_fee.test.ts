@@ -0,0 +1,45 @@
+import { ApiPromise } from '@polkadot/api';
+import BN from 'bn.js';
+import TPsp22TransferFeeDeployer from 'typechain/deployers/t_psp22_transfer_fee';
+import TTokenReceiverDeployer from 'typechain/deployers/t_token_receiver';
+import '@c-forge/polkahat-chai-matchers';
+import { expect } from 'chai';
+import { getSigners, localApi } from '@c-forge/polkahat-network-helpers';
+
+const [deployer, feeRecipient] = getSigners();
+
+describe('PSP22 transfer fee', function () {
+  let api: ApiPromise;
+
+  beforeEach(async function () {
+    api = await localApi.get();
+  });
+
+  it('notifies the receiver contract about the amount after the fee', async function () {
+    // 1% fee
+    const token = (await new TPsp22TransferFeeDeployer(api, deployer).new(10_000, 100, feeRecipient.address)).contract;
+    const receiver = (await new TTokenReceiverDeployer(api, deployer).new(false)).contract;
+
+    const tx = token.withSigner(deployer).tx.transfer(receiver.address, 1_000, []);
+
+    await expect(tx).to.changePSP22Balances(
+      token,
+      [deployer.address, receiver.address, feeRecipient.address],
+      [new BN(-1_000), new BN(990), new BN(10)],
+    );
+    await expect(receiver.query.receivedCalls()).to.haveOkResult(1);
+    await expect(receiver.query.lastReceivedValue()).to.haveOkResult(990);
+  });
+
+  it('charges no fee when the fee recipient is not set', async function () {
+    const token = (await new TPsp22TransferFeeDeployer(api, deployer).newWithoutFeeRecipient(10_000)).contract;
+    const receiver = (await new TTokenReceiverDeployer(api, deployer).new(false)).contract;
+
+    await expect(token.query.feeRecipient()).to.haveOkResult(null);
+
+    const tx = token.withSigner(deployer).tx.transfer(receiver.address, 1_000, []);
+
+    await expect(tx).to.changePSP22Balances(token, [deployer.address, receiver.address], [new BN(-1_000), new BN(1_000)]);
+    await expect(receiver.query.lastReceivedValue()).to.haveOkResult(1_000);
+  });
+});
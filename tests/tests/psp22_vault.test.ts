@@ -38,6 +38,18 @@ describe.only('ERC4626', function () {
     await expect(vault.query.tokenDecimals()).to.haveOkResult(decimals);
   });
 
+  it('deposit pulls a pendzl PSP22 asset into the vault without calling the vault back', async function () {
+    token = (await new TPsp22Deployer(api, deployer).new(0, '', '', decimals)).contract;
+    vault = (await new TVault22Deployer(api, deployer).new(token.address, 0, '', '', null)).contract;
+    await token.tx.tMint(holder.address, 1000);
+    await token.withSigner(holder).tx.approve(vault.address, 1000);
+
+    const tx = vault.withSigner(holder).tx.deposit(1000, recipient.address);
+
+    await expect(tx).to.changePSP22Balances(token, [holder.address, vault.address], [new BN(-1000), new BN(1000)]);
+    await expect(tx).to.changePSP22Balances(vault, [recipient.address], [new BN(1000)]);
+  });
+
   // removed excess underlying decimals
 
   it('decimals overflow', async function () {
@@ -63,28 +75,22 @@ describe.only('ERC4626', function () {
 
     it('reverts on deposit() above max deposit', async function () {
       const maxDeposit = (await vault.query.maxDeposit(holder.address)).value.ok;
-      await expect(vault.withSigner(holder).query.deposit(maxDeposit!.addn(1), recipient.address)).to.be.revertedWithError({
-        custom: 'V:MaxDeposit',
-      });
+      await expect(vault.withSigner(holder).query.deposit(maxDeposit!.addn(1), recipient.address)).to.be.revertedWithError('MaxDepositExceeded');
     });
 
     it('reverts on mint() above max mint', async function () {
       const maxMint = (await vault.query.maxMint(holder.address)).value.ok;
-      await expect(vault.withSigner(holder).query.mint(maxMint!.addn(1), recipient.address)).to.be.revertedWithError({ custom: 'V:MaxMint' });
+      await expect(vault.withSigner(holder).query.mint(maxMint!.addn(1), recipient.address)).to.be.revertedWithError('MaxMintExceeded');
     });
 
     it('reverts on withdraw() above max withdraw', async function () {
       const maxWithdraw = (await vault.query.maxWithdraw(holder.address)).value.ok;
-      await expect(vault.withSigner(holder).query.withdraw(maxWithdraw!.addn(1), recipient.address, holder.address)).to.be.revertedWithError({
-        custom: 'V:MaxWithdraw',
-      });
+      await expect(vault.withSigner(holder).query.withdraw(maxWithdraw!.addn(1), recipient.address, holder.address)).to.be.revertedWithError('MaxWithdrawExceeded');
     });
 
     it('reverts on redeem() above max redeem', async function () {
       const maxRedeem = (await vault.query.maxRedeem(holder.address)).value.ok;
-      await expect(vault.withSigner(holder).query.redeem(maxRedeem!.addn(1), recipient.address, holder.address)).to.be.revertedWithError({
-        custom: 'V:MaxRedeem',
-      });
+      await expect(vault.withSigner(holder).query.redeem(maxRedeem!.addn(1), recipient.address, holder.address)).to.be.revertedWithError('MaxRedeemExceeded');
     });
   });
 
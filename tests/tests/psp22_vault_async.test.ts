@@ -0,0 +1,99 @@
+import { ApiPromise } from '@polkadot/api';
+import BN from 'bn.js';
+import TPsp22Deployer from 'typechain/deployers/t_psp22';
+import TPsp22Contract from 'typechain/contracts/t_psp22';
+import TVaultAsyncDeployer from 'typechain/deployers/t_vault_async';
+import TVaultAsyncContract from 'typechain/contracts/t_vault_async';
+import '@c-forge/polkahat-chai-matchers';
+import { expect } from 'chai';
+import { getSigners, localApi } from '@c-forge/polkahat-network-helpers';
+
+const MAX_U128 = new BN('340282366920938463463374607431768211455');
+
+const [deployer, holder, receiver] = getSigners();
+
+describe('PSP22 vault async requests', function () {
+  let api: ApiPromise;
+  let token: TPsp22Contract;
+  let vault: TVaultAsyncContract;
+
+  beforeEach(async function () {
+    api = await localApi.get();
+    token = (await new TPsp22Deployer(api, deployer).new(0, '', '', 12)).contract;
+    await token.tx.tMint(holder.address, 100_000);
+    vault = (await new TVaultAsyncDeployer(api, deployer).new(token.address)).contract;
+    await token.withSigner(holder).tx.approve(vault.address, MAX_U128);
+  });
+
+  it('request_redeem escrows shares and disables synchronous redeem', async function () {
+    await vault.withSigner(holder).tx.deposit(1_000, holder.address);
+
+    await expect(vault.query.maxRedeem(holder.address)).to.haveOkResult(0);
+    await expect(vault.query.maxWithdraw(holder.address)).to.haveOkResult(0);
+
+    const tx = vault.withSigner(holder).tx.requestRedeem(400, holder.address, holder.address);
+
+    await expect(tx).to.emitEvent(vault, 'RedeemRequest', {
+      controller: holder.address,
+      owner: holder.address,
+      sender: holder.address,
+      shares: new BN(400),
+    });
+    await expect(tx).to.changePSP22Balances(vault, [holder.address, vault.address], [new BN(-400), new BN(400)]);
+    await expect(vault.query.pendingRedeemRequest(holder.address)).to.haveOkResult(400);
+    await expect(vault.query.claimableRedeemRequest(holder.address)).to.haveOkResult(0);
+  });
+
+  it('fulfilled redeem is claimable and excluded from total assets', async function () {
+    await vault.withSigner(holder).tx.deposit(1_000, holder.address);
+    await vault.withSigner(holder).tx.requestRedeem(400, holder.address, holder.address);
+
+    const fulfillTx = vault.tx.tFulfillRedeem(holder.address, 400);
+
+    await expect(fulfillTx).to.emitEvent(vault, 'RedeemFulfilled', { controller: holder.address, assets: new BN(400), shares: new BN(400) });
+    await expect(fulfillTx).to.changePSP22Balances(vault, [vault.address], [new BN(-400)]);
+    await expect(vault.query.pendingRedeemRequest(holder.address)).to.haveOkResult(0);
+    await expect(vault.query.claimableRedeemRequest(holder.address)).to.haveOkResult(400);
+    await expect(vault.query.totalAssets()).to.haveOkResult(600);
+
+    const claimTx = vault.withSigner(holder).tx.claim(receiver.address);
+
+    await expect(claimTx).to.emitEvent(vault, 'Withdraw', {
+      sender: holder.address,
+      receiver: receiver.address,
+      owner: holder.address,
+      assets: new BN(400),
+      shares: new BN(400),
+    });
+    await expect(claimTx).to.changePSP22Balances(token, [vault.address, receiver.address], [new BN(-400), new BN(400)]);
+    await expect(vault.query.claimableRedeemRequest(holder.address)).to.haveOkResult(0);
+    await expect(vault.query.totalAssets()).to.haveOkResult(600);
+  });
+
+  it('deposit request is priced on fulfillment and claimed as shares', async function () {
+    const requestTx = vault.withSigner(holder).tx.requestDeposit(1_000, holder.address);
+
+    await expect(requestTx).to.emitEvent(vault, 'DepositRequest', { controller: holder.address, owner: holder.address, assets: new BN(1_000) });
+    await expect(requestTx).to.changePSP22Balances(token, [holder.address, vault.address], [new BN(-1_000), new BN(1_000)]);
+    await expect(vault.query.pendingDepositRequest(holder.address)).to.haveOkResult(1_000);
+    await expect(vault.query.totalAssets()).to.haveOkResult(0);
+
+    await vault.tx.tFulfillDeposit(holder.address, 1_000);
+
+    await expect(vault.query.pendingDepositRequest(holder.address)).to.haveOkResult(0);
+    await expect(vault.query.claimableDepositRequest(holder.address)).to.haveOkResult(1_000);
+    await expect(vault.query.totalAssets()).to.haveOkResult(1_000);
+
+    const claimTx = vault.withSigner(holder).tx.claim(holder.address);
+
+    await expect(claimTx).to.emitEvent(vault, 'Deposit', { sender: holder.address, owner: holder.address, assets: new BN(1_000), shares: new BN(1_000) });
+    await expect(claimTx).to.changePSP22Balances(vault, [holder.address, vault.address], [new BN(1_000), new BN(-1_000)]);
+  });
+
+  it('fulfilling more than is pending fails', async function () {
+    await vault.withSigner(holder).tx.requestDeposit(1_000, holder.address);
+
+    await expect(vault.query.tFulfillDeposit(holder.address, 1_001)).to.be.revertedWithError('InsufficientPendingRequest');
+    await expect(vault.query.tFulfillRedeem(holder.address, 1)).to.be.revertedWithError('InsufficientPendingRequest');
+  });
+});
@@ -0,0 +1,126 @@
+import { ApiPromise } from '@polkadot/api';
+import BN from 'bn.js';
+import TPsp22Deployer from 'typechain/deployers/t_psp22';
+import TPsp22Contract from 'typechain/contracts/t_psp22';
+import TVaultFeesDeployer from 'typechain/deployers/t_vault_fees';
+import TVaultFeesContract from 'typechain/contracts/t_vault_fees';
+import '@c-forge/polkahat-chai-matchers';
+import { expect } from 'chai';
+import { duration, getSigners, localApi, time } from '@c-forge/polkahat-network-helpers';
+
+const MAX_U128 = new BN('340282366920938463463374607431768211455');
+
+const [deployer, holder, feeRecipient] = getSigners();
+
+describe('PSP22 vault fees', function () {
+  let api: ApiPromise;
+  let token: TPsp22Contract;
+  let vault: TVaultFeesContract;
+
+  async function deployVault(entryFeeBps: number, exitFeeBps: number, managementFeeBps: number, performanceFeeBps: number) {
+    vault = (
+      await new TVaultFeesDeployer(api, deployer).new(
+        token.address,
+        entryFeeBps,
+        exitFeeBps,
+        managementFeeBps,
+        performanceFeeBps,
+        feeRecipient.address,
+      )
+    ).contract;
+    await token.withSigner(holder).tx.approve(vault.address, MAX_U128);
+  }
+
+  beforeEach(async function () {
+    api = await localApi.get();
+    token = (await new TPsp22Deployer(api, deployer).new(0, '', '', 12)).contract;
+    await token.tx.tMint(holder.address, 100_000);
+  });
+
+  it('rejects fees above 100%', async function () {
+    await expect(new TVaultFeesDeployer(api, deployer).new(token.address, 10_001, 0, 0, 0, feeRecipient.address)).to.be.eventually.rejected;
+  });
+
+  it('entry fee stays in the vault without a fee recipient', async function () {
+    vault = (await new TVaultFeesDeployer(api, deployer).newWithoutFeeRecipient(token.address)).contract;
+    await token.withSigner(holder).tx.approve(vault.address, MAX_U128);
+    await vault.tx.tSetEntryFeeBps(100);
+
+    await expect(vault.query.feeRecipient()).to.haveOkResult(null);
+
+    const tx = vault.withSigner(holder).tx.deposit(10_100, holder.address);
+
+    await expect(tx).to.changePSP22Balances(token, [holder.address, vault.address], [new BN(-10_100), new BN(10_100)]);
+    await expect(tx).to.changePSP22Balances(vault, [holder.address], [new BN(10_000)]);
+  });
+
+  it('deposit charges the entry fee', async function () {
+    await deployVault(100, 0, 0, 0);
+
+    await expect(vault.query.previewDeposit(10_100)).to.haveOkResult(10_000);
+
+    const tx = vault.withSigner(holder).tx.deposit(10_100, holder.address);
+
+    await expect(tx).to.changePSP22Balances(
+      token,
+      [holder.address, vault.address, feeRecipient.address],
+      [new BN(-10_100), new BN(10_000), new BN(100)],
+    );
+    await expect(tx).to.changePSP22Balances(vault, [holder.address], [new BN(10_000)]);
+  });
+
+  it('redeem charges the exit fee', async function () {
+    await deployVault(0, 200, 0, 0);
+    await vault.withSigner(holder).tx.deposit(10_000, holder.address);
+
+    await expect(vault.query.maxWithdraw(holder.address)).to.haveOkResult(9_803);
+    await expect(vault.query.previewRedeem(10_000)).to.haveOkResult(9_803);
+
+    const tx = vault.withSigner(holder).tx.redeem(10_000, holder.address, holder.address);
+
+    await expect(tx).to.changePSP22Balances(
+      token,
+      [holder.address, vault.address, feeRecipient.address],
+      [new BN(9_803), new BN(-10_000), new BN(197)],
+    );
+    await expect(tx).to.changePSP22Balances(vault, [holder.address], [new BN(-10_000)]);
+  });
+
+  it('performance fee is minted as shares above the high-water mark', async function () {
+    await deployVault(0, 0, 0, 1_000);
+    await vault.withSigner(holder).tx.deposit(10_000, holder.address);
+    await expect(vault.query.pendingFeeShares()).to.haveOkResult(0);
+
+    // yield doubles the share price
+    await token.tx.tMint(vault.address, 10_000);
+    await expect(vault.query.pendingFeeShares()).to.haveOkResult(525);
+
+    const tx = vault.tx.accrueFees();
+
+    await expect(tx).to.changePSP22Balances(vault, [feeRecipient.address], [new BN(525)]);
+    await expect(vault.query.pendingFeeShares()).to.haveOkResult(0);
+  });
+
+  it('previews include pending fees', async function () {
+    await deployVault(0, 0, 0, 1_000);
+    await vault.withSigner(holder).tx.deposit(10_000, holder.address);
+    await token.tx.tMint(vault.address, 10_000);
+
+    await expect(vault.query.previewDeposit(1_000)).to.haveOkResult(526);
+
+    const tx = vault.withSigner(holder).tx.deposit(1_000, holder.address);
+
+    await expect(tx).to.changePSP22Balances(vault, [holder.address, feeRecipient.address], [new BN(526), new BN(525)]);
+  });
+
+  it('management fee accrues over time', async function () {
+    await deployVault(0, 0, 100, 0);
+    await vault.withSigner(holder).tx.deposit(10_000, holder.address);
+
+    await time.increase(duration.days(365));
+
+    const tx = vault.tx.accrueFees();
+
+    await expect(tx).to.changePSP22Balances(vault, [feeRecipient.address], [new BN(101)]);
+  });
+});
@@ -0,0 +1,45 @@
+import BN from 'bn.js';
+import { getSigners, localApi } from '@c-forge/polkahat-network-helpers';
+import TVaultNativeDeployer from 'typechain/deployers/t_vault_native';
+import TVaultNativeContract from 'typechain/contracts/t_vault_native';
+import '@c-forge/polkahat-chai-matchers';
+import { expect } from 'chai';
+
+const [deployer, holder, recipient] = getSigners();
+const amount = new BN(1000);
+
+describe('PSP22 vault over the native currency', () => {
+  let vault: TVaultNativeContract;
+
+  beforeEach(async () => {
+    const api = await localApi.get();
+    vault = (await new TVaultNativeDeployer(api, deployer).new(12)).contract;
+  });
+
+  it('deposit_native mints shares for the transferred value', async function () {
+    await expect(vault.query.previewDeposit(amount)).to.haveOkResult(amount);
+
+    const tx = vault.withSigner(holder).tx.depositNative(holder.address, { value: amount });
+
+    await expect(tx).to.emitEvent(vault, 'Deposit', { sender: holder.address, owner: holder.address, assets: amount, shares: amount });
+    await expect(tx).to.changeBalances([vault.address], [amount]);
+    await expect(tx).to.changePSP22Balances(vault, [holder.address], [amount]);
+    await expect(vault.query.totalAssets()).to.haveOkResult(amount);
+  });
+
+  it('mint_native reverts if the transferred value does not match the assets', async function () {
+    await expect(vault.withSigner(holder).query.mintNative(amount, holder.address, { value: amount.subn(1) })).to.be.revertedWithError(
+      'InvalidTransferredValue',
+    );
+  });
+
+  it('redeem sends native currency to the receiver', async function () {
+    await vault.withSigner(holder).tx.depositNative(holder.address, { value: amount });
+
+    const tx = vault.withSigner(holder).tx.redeem(400, recipient.address, holder.address);
+
+    await expect(tx).to.changeBalances([vault.address, recipient.address], [new BN(-400), new BN(400)]);
+    await expect(tx).to.changePSP22Balances(vault, [holder.address], [new BN(-400)]);
+    await expect(vault.query.totalAssets()).to.haveOkResult(600);
+  });
+});
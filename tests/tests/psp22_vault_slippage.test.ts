@@ -0,0 +1,77 @@
+import { ApiPromise } from '@polkadot/api';
+import BN from 'bn.js';
+import TPsp22Deployer from 'typechain/deployers/t_psp22';
+import TPsp22Contract from 'typechain/contracts/t_psp22';
+import TVaultSlippageDeployer from 'typechain/deployers/t_vault_slippage';
+import TVaultSlippageContract from 'typechain/contracts/t_vault_slippage';
+import '@c-forge/polkahat-chai-matchers';
+import { expect } from 'chai';
+import { getSigners, localApi } from '@c-forge/polkahat-network-helpers';
+
+const MAX_U128 = new BN('340282366920938463463374607431768211455');
+
+const [deployer, holder] = getSigners();
+
+describe('PSP22 vault slippage protection', function () {
+  let api: ApiPromise;
+  let token: TPsp22Contract;
+  let vault: TVaultSlippageContract;
+
+  beforeEach(async function () {
+    api = await localApi.get();
+    token = (await new TPsp22Deployer(api, deployer).new(0, '', '', 12)).contract;
+    await token.tx.tMint(holder.address, 100_000);
+    vault = (await new TVaultSlippageDeployer(api, deployer).new(token.address)).contract;
+    await token.withSigner(holder).tx.approve(vault.address, MAX_U128);
+
+    await vault.withSigner(holder).tx.deposit(1_000, holder.address);
+    // donation doubles the share price
+    await token.tx.tMint(vault.address, 1_000);
+  });
+
+  it('deposit_with_min_shares', async function () {
+    await expect(vault.withSigner(holder).query.depositWithMinShares(1_000, holder.address, 501, null)).to.be.revertedWithError('SharesBelowMin');
+
+    const tx = vault.withSigner(holder).tx.depositWithMinShares(1_000, holder.address, 500, null);
+
+    await expect(tx).to.changePSP22Balances(vault, [holder.address], [new BN(500)]);
+  });
+
+  it('mint_with_max_assets', async function () {
+    await expect(vault.withSigner(holder).query.mintWithMaxAssets(500, holder.address, 999, null)).to.be.revertedWithError('AssetsAboveMax');
+
+    const tx = vault.withSigner(holder).tx.mintWithMaxAssets(500, holder.address, 1_000, null);
+
+    await expect(tx).to.changePSP22Balances(token, [holder.address], [new BN(-1_000)]);
+  });
+
+  it('withdraw_with_max_shares', async function () {
+    await expect(vault.withSigner(holder).query.withdrawWithMaxShares(1_000, holder.address, holder.address, 500, null)).to.be.revertedWithError(
+      'SharesAboveMax',
+    );
+
+    const tx = vault.withSigner(holder).tx.withdrawWithMaxShares(1_000, holder.address, holder.address, 501, null);
+
+    await expect(tx).to.changePSP22Balances(vault, [holder.address], [new BN(-501)]);
+  });
+
+  it('redeem_with_min_assets', async function () {
+    await expect(vault.withSigner(holder).query.redeemWithMinAssets(500, holder.address, holder.address, 1_000, null)).to.be.revertedWithError(
+      'AssetsBelowMin',
+    );
+    await expect(vault.withSigner(holder).query.redeemWithMinAssets(1_001, holder.address, holder.address, 0, null)).to.be.revertedWithError(
+      'MaxRedeemExceeded',
+    );
+
+    const tx = vault.withSigner(holder).tx.redeemWithMinAssets(500, holder.address, holder.address, 999, null);
+
+    await expect(tx).to.changePSP22Balances(token, [holder.address], [new BN(999)]);
+  });
+
+  it('fails past the deadline', async function () {
+    await expect(vault.withSigner(holder).query.depositWithMinShares(1_000, holder.address, 0, 1)).to.be.revertedWithError('VaultDeadlineExpired');
+    await expect(vault.withSigner(holder).query.redeemWithMinAssets(500, holder.address, holder.address, 0, 1)).to.be.revertedWithError(
+      'VaultDeadlineExpired',
+    );
+  });
+});
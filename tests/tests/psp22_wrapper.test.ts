@@ -0,0 +1,65 @@
+import BN from 'bn.js';
+import { getSigners, localApi } from '@c-forge/polkahat-network-helpers';
+import TPsp22WrapperDeployer from 'typechain/deployers/t_psp22_wrapper';
+import TPsp22WrapperContract from 'typechain/contracts/t_psp22_wrapper';
+import TPsp22Deployer from 'typechain/deployers/t_psp22';
+import TPsp22Contract from 'typechain/contracts/t_psp22';
+import '@c-forge/polkahat-chai-matchers';
+import { expect } from 'chai';
+
+const [owner, alice, bob] = getSigners();
+const initialSupply = new BN(1000);
+
+describe('PSP22 Wrapper', () => {
+  let underlying: TPsp22Contract;
+  let token: TPsp22WrapperContract;
+
+  beforeEach(async () => {
+    const api = await localApi.get();
+    underlying = (await new TPsp22Deployer(api, owner).new(initialSupply, null, null, 0)).contract;
+    token = (await new TPsp22WrapperDeployer(api, owner).new(underlying.address)).contract;
+  });
+
+  it('underlying returns the wrapped token', async function () {
+    await expect(token.query.underlying()).to.haveOkResult(underlying.address);
+  });
+
+  it('deposit_for takes underlying from the caller and mints to account', async function () {
+    await underlying.withSigner(owner).tx.approve(token.address, 100);
+
+    const tx = await token.withSigner(owner).tx.depositFor(alice.address, 100);
+    await expect(tx).to.changePSP22Balances(underlying, [owner.address, token.address], [new BN(-100), new BN(100)]);
+    await expect(token.query.balanceOf(alice.address)).to.haveOkResult(100);
+    await expect(token.query.totalSupply()).to.haveOkResult(100);
+  });
+
+  it('deposit_for reverts without allowance on the underlying', async function () {
+    await expect(token.withSigner(owner).query.depositFor(alice.address, 100)).to.be.revertedWithError('InsufficientAllowance');
+  });
+
+  it('withdraw_to burns from the caller and sends underlying to account', async function () {
+    await underlying.withSigner(owner).tx.approve(token.address, 100);
+    await token.withSigner(owner).tx.depositFor(alice.address, 100);
+
+    const tx = await token.withSigner(alice).tx.withdrawTo(bob.address, 40);
+    await expect(tx).to.changePSP22Balances(token, [alice.address], [new BN(-40)]);
+    await expect(underlying.query.balanceOf(bob.address)).to.haveOkResult(40);
+    await expect(underlying.query.balanceOf(token.address)).to.haveOkResult(60);
+    await expect(token.query.totalSupply()).to.haveOkResult(60);
+  });
+
+  it('withdraw_to reverts if the caller has insufficient balance', async function () {
+    await expect(token.withSigner(alice).query.withdrawTo(alice.address, 1)).to.be.revertedWithError('InsufficientBalance');
+  });
+
+  it('recover mints for underlying sent by mistake', async function () {
+    await underlying.withSigner(owner).tx.approve(token.address, 100);
+    await token.withSigner(owner).tx.depositFor(owner.address, 100);
+    await underlying.withSigner(owner).tx.transfer(token.address, 50, []);
+
+    await expect(token.withSigner(owner).query.tRecover(bob.address)).to.haveOkResult(50);
+    await token.withSigner(owner).tx.tRecover(bob.address);
+    await expect(token.query.balanceOf(bob.address)).to.haveOkResult(50);
+    await expect(token.query.totalSupply()).to.haveOkResult(150);
+  });
+});
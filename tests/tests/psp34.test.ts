@@ -3,6 +3,8 @@ import { BN } from 'bn.js';
 import { expect } from 'chai';
 import TPSP34MetadataContract from 'typechain/contracts/t_psp34_metadata';
 import TPSP34MetadataDeployer from 'typechain/deployers/t_psp34_metadata';
+import TTokenReceiverDeployer from 'typechain/deployers/t_token_receiver';
+import TFlipperV0Deployer from 'typechain/deployers/t_flipper_v0';
 import { nonExistentTokenId } from '@c-forge/pendzl-tests';
 import {
   firstTokenId,
@@ -228,4 +230,46 @@ describe('PSP34Metadata', function () {
       });
     });
   });
+
+  describe('safe transfer check', function () {
+    it('calls before_received on mint to a receiver contract', async function () {
+      const receiver = (await new TTokenReceiverDeployer(api, deployer).new(false)).contract;
+
+      await tPSP34.tx.tMint(receiver.address, firstTokenId);
+      await expect(tPSP34.query.ownerOf(firstTokenId)).to.haveOkResult(receiver.address);
+      await expect(receiver.query.receivedCalls()).to.haveOkResult(1);
+    });
+
+    it('calls before_received on transfer to a receiver contract', async function () {
+      const receiver = (await new TTokenReceiverDeployer(api, deployer).new(false)).contract;
+      await tPSP34.tx.tMint(owner.address, firstTokenId);
+
+      await tPSP34.withSigner(owner).tx.transfer(receiver.address, firstTokenId, []);
+      await expect(tPSP34.query.ownerOf(firstTokenId)).to.haveOkResult(receiver.address);
+      await expect(receiver.query.receivedCalls()).to.haveOkResult(1);
+    });
+
+    it('reverts when the receiver contract rejects the token', async function () {
+      const receiver = (await new TTokenReceiverDeployer(api, deployer).new(true)).contract;
+      await tPSP34.tx.tMint(owner.address, firstTokenId);
+
+      await expect(tPSP34.withSigner(owner).query.transfer(receiver.address, firstTokenId, [])).to.be.revertedWithError({
+        safeTransferCheckFailed: 'Rejected by receiver',
+      });
+      await expect(tPSP34.query.tMint(receiver.address, secondTokenId)).to.be.revertedWithError({
+        safeTransferCheckFailed: 'Rejected by receiver',
+      });
+    });
+
+    it('accepts a recipient contract that does not implement PSP34Receiver', async function () {
+      const flipper = (await new TFlipperV0Deployer(api, deployer).new(false)).contract;
+
+      await tPSP34.tx.tMint(flipper.address, firstTokenId);
+      await expect(tPSP34.query.ownerOf(firstTokenId)).to.haveOkResult(flipper.address);
+
+      await tPSP34.tx.tMint(owner.address, secondTokenId);
+      await tPSP34.withSigner(owner).tx.transfer(flipper.address, secondTokenId, []);
+      await expect(tPSP34.query.ownerOf(secondTokenId)).to.haveOkResult(flipper.address);
+    });
+  });
 });